@@ -0,0 +1,245 @@
+//! Rendering components with ANSI escape codes, e.g. for server consoles.
+
+use std::fmt::{Display, Formatter};
+use std::io::IsTerminal;
+
+use crate::component::{default_key_name, format_translation, ChatComponent, ComponentType};
+use crate::style::{ChatColor, ChatFormatting, ComponentStyle};
+
+const RESET: &str = "\x1b[0m";
+
+/// The colors a terminal can display, colors are changed to the nearest one it supports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorDepth {
+    /// 24-bit colors, the exact colors of the client.
+    TrueColor,
+    /// The 256 colors of xterm.
+    Ansi256,
+    /// The 16 standard colors, the terminal's theme decides how they look.
+    Ansi16,
+}
+
+/// The levels of every channel in xterm's 6x6x6 color cube.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> i32 {
+    let channel = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+    channel(r1, r2) + channel(g1, g2) + channel(b1, b2)
+}
+
+/// The xterm color closest to `rgb`, out of the color cube and the gray ramp.
+fn ansi256((red, green, blue): (u8, u8, u8)) -> u8 {
+    let level = |value: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|i| (CUBE_LEVELS[*i] as i32 - value as i32).abs())
+            .unwrap_or(0)
+    };
+    let (r, g, b) = (level(red), level(green), level(blue));
+    let cube = (CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+    let gray_index = ((red as u32 + green as u32 + blue as u32) / 3).saturating_sub(3) / 10;
+    let gray_index = gray_index.min(23) as u8;
+    let gray_level = 8 + gray_index * 10;
+    if distance((gray_level, gray_level, gray_level), (red, green, blue))
+        < distance(cube, (red, green, blue))
+    {
+        232 + gray_index
+    } else {
+        16 + 36 * r as u8 + 6 * g as u8 + b as u8
+    }
+}
+
+/// The SGR color parameter of one of the 16 colors.
+fn ansi16(formatting: ChatFormatting) -> &'static str {
+    match formatting {
+        ChatFormatting::Black => "30",
+        ChatFormatting::DarkBlue => "34",
+        ChatFormatting::DarkGreen => "32",
+        ChatFormatting::DarkCyan => "36",
+        ChatFormatting::DarkRed => "31",
+        ChatFormatting::Purple => "35",
+        ChatFormatting::Gold => "33",
+        ChatFormatting::Gray => "37",
+        ChatFormatting::DarkGray => "90",
+        ChatFormatting::Blue => "94",
+        ChatFormatting::Green => "92",
+        ChatFormatting::Cyan => "96",
+        ChatFormatting::Red => "91",
+        ChatFormatting::Pink => "95",
+        ChatFormatting::Yellow => "93",
+        _ => "97",
+    }
+}
+
+fn color_param(color: &ChatColor, depth: ColorDepth) -> Option<String> {
+    match depth {
+        ColorDepth::TrueColor => {
+            let (red, green, blue) = color.to_rgb()?;
+            Some(format!("38;2;{};{};{}", red, green, blue))
+        }
+        ColorDepth::Ansi256 => Some(format!("38;5;{}", ansi256(color.to_rgb()?))),
+        ColorDepth::Ansi16 => {
+            let named = color.to_nearest_legacy()?;
+            ChatFormatting::from_color(&named)
+                .filter(|formatting| *formatting != ChatFormatting::Reset)
+                .map(|formatting| ansi16(formatting).to_string())
+        }
+    }
+}
+
+/// The SGR parameters (like `1;38;2;255;85;85`) displaying `style`, empty for no formatting.
+fn sgr(style: &ComponentStyle, depth: ColorDepth) -> String {
+    let mut params = vec![];
+    let decorations = [
+        (style.get_bold(), "1"),
+        (style.get_italic(), "3"),
+        (style.get_underlined(), "4"),
+        (style.get_strikethrough(), "9"),
+    ];
+    for (value, param) in decorations.iter() {
+        if *value == Some(true) {
+            params.push(param.to_string());
+        }
+    }
+    if let Some(color) = style
+        .get_color()
+        .and_then(|color| color_param(color, depth))
+    {
+        params.push(color);
+    }
+    params.join(";")
+}
+
+struct AnsiWriter {
+    depth: ColorDepth,
+    out: String,
+    /// The parameters of the formatting currently in effect
+    current: String,
+}
+
+impl AnsiWriter {
+    /// Writes the codes changing the formatting to `target`, resetting the previous one
+    /// so nothing of it carries over.
+    fn switch_to(&mut self, target: String) {
+        if target == self.current {
+            return;
+        }
+        if !self.current.is_empty() {
+            self.out.push_str(RESET);
+        }
+        if !target.is_empty() {
+            self.out.push_str(&format!("\x1b[{}m", target));
+        }
+        self.current = target;
+    }
+
+    fn write(&mut self, component: &ChatComponent, parent: Option<&ComponentStyle>) {
+        let mut style = component.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+
+        let text = match component.get_kind() {
+            ComponentType::Text(text) => text.get_text().to_string(),
+            ComponentType::Translation(translation) => {
+                // every argument starts and ends in the translation's formatting
+                let args: Vec<String> = translation
+                    .get_args()
+                    .iter()
+                    .map(|arg| {
+                        let mut writer = AnsiWriter {
+                            depth: self.depth,
+                            out: String::new(),
+                            current: sgr(&style, self.depth),
+                        };
+                        writer.write(arg, Some(&style));
+                        writer.switch_to(sgr(&style, self.depth));
+                        writer.out
+                    })
+                    .collect();
+                format_translation(translation.get_key(), &args)
+            }
+            ComponentType::Score(score) => score.get_value().unwrap_or_default().to_string(),
+            ComponentType::Selector(selector) => selector.get_selector().to_string(),
+            ComponentType::Keybind(keybind) => default_key_name(keybind.get_keybind())
+                .unwrap_or(keybind.get_keybind())
+                .to_string(),
+            ComponentType::Nbt(_) => String::new(),
+            ComponentType::Custom(custom) => custom.plain_text(),
+        };
+        if !text.is_empty() {
+            self.switch_to(sgr(&style, self.depth));
+            self.out.push_str(&text);
+        }
+        for sibling in component.get_siblings() {
+            self.write(sibling, Some(&style));
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Renders this component with ANSI escape codes in 24-bit color, so terminals
+    /// show it like the client does. The output ends unformatted.
+    ///
+    /// Translations are rendered with their key as pattern, obfuscated text is shown as it is.
+    pub fn to_ansi(&self) -> String {
+        self.to_ansi_with(ColorDepth::TrueColor)
+    }
+
+    /// Renders this component with ANSI escape codes for a terminal supporting `depth`,
+    /// see [`ChatComponent::to_ansi()`].
+    pub fn to_ansi_with(&self, depth: ColorDepth) -> String {
+        let mut writer = AnsiWriter {
+            depth,
+            out: String::new(),
+            current: String::new(),
+        };
+        writer.write(self, None);
+        writer.switch_to(String::new());
+        writer.out
+    }
+}
+
+/// Returns whether colors should be written, following the `NO_COLOR` convention:
+/// set to anything but an empty string it turns them off, like a `stdout` that isn't a terminal.
+pub(crate) fn colors_enabled(no_color: Option<&str>, is_terminal: bool) -> bool {
+    no_color.is_none_or(str::is_empty) && is_terminal
+}
+
+/// Displays a component with ANSI escape codes if `stdout` is a terminal and `NO_COLOR`
+/// isn't set, as plain text otherwise, see [`ChatComponent::display_ansi()`].
+#[derive(Clone, Copy, Debug)]
+pub struct AnsiDisplay<'a> {
+    component: &'a ChatComponent,
+    depth: ColorDepth,
+}
+
+impl AnsiDisplay<'_> {
+    /// Sets the colors the terminal supports, 24-bit colors by default.
+    pub fn depth(mut self, depth: ColorDepth) -> Self {
+        self.depth = depth;
+        self
+    }
+}
+
+impl Display for AnsiDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let no_color = std::env::var("NO_COLOR").ok();
+        if colors_enabled(no_color.as_deref(), std::io::stdout().is_terminal()) {
+            f.write_str(&self.component.to_ansi_with(self.depth))
+        } else {
+            f.write_str(&self.component.to_plain_text())
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Displays this component with ANSI escape codes in terminals, falling back to plain
+    /// text when `NO_COLOR` is set or `stdout` isn't a terminal (like in CI logs or when
+    /// piped to a file), so `println!("{}", component.display_ansi())` just works.
+    pub fn display_ansi(&self) -> AnsiDisplay<'_> {
+        AnsiDisplay {
+            component: self,
+            depth: ColorDepth::TrueColor,
+        }
+    }
+}
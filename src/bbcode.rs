@@ -0,0 +1,155 @@
+//! Rendering components as BBCode, e.g. for forums.
+
+use crate::component::{default_key_name, format_translation, ChatComponent, ComponentType};
+use crate::style::{ChatColor, ClickEvent, ComponentStyle};
+
+/// A BBCode tag, in the order they are opened.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Tag {
+    Url(String),
+    Color(String),
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+}
+
+impl Tag {
+    fn open(&self) -> String {
+        match self {
+            Tag::Url(url) => format!("[url={}]", url),
+            Tag::Color(color) => format!("[color={}]", color),
+            Tag::Bold => "[b]".to_string(),
+            Tag::Italic => "[i]".to_string(),
+            Tag::Underline => "[u]".to_string(),
+            Tag::Strikethrough => "[s]".to_string(),
+        }
+    }
+
+    fn close(&self) -> &'static str {
+        match self {
+            Tag::Url(_) => "[/url]",
+            Tag::Color(_) => "[/color]",
+            Tag::Bold => "[/b]",
+            Tag::Italic => "[/i]",
+            Tag::Underline => "[/u]",
+            Tag::Strikethrough => "[/s]",
+        }
+    }
+}
+
+/// The tags displaying `style`, [`ChatColor::Reset`] and unset values get none.
+fn tags(style: &ComponentStyle) -> Vec<Tag> {
+    let mut tags = vec![];
+    if let Some(ClickEvent::OpenUrl(url)) = style.get_click_event() {
+        if url.starts_with("http://") || url.starts_with("https://") {
+            tags.push(Tag::Url(url.replace(']', "%5D")));
+        }
+    }
+    if let Some((red, green, blue)) = style
+        .get_color()
+        .filter(|color| **color != ChatColor::Reset)
+        .and_then(ChatColor::to_rgb)
+    {
+        tags.push(Tag::Color(format!("#{:02x}{:02x}{:02x}", red, green, blue)));
+    }
+    let decorations = [
+        (style.get_bold(), Tag::Bold),
+        (style.get_italic(), Tag::Italic),
+        (style.get_underlined(), Tag::Underline),
+        (style.get_strikethrough(), Tag::Strikethrough),
+    ];
+    for (value, tag) in decorations.iter() {
+        if *value == Some(true) {
+            tags.push(tag.clone());
+        }
+    }
+    tags
+}
+
+struct BbcodeWriter {
+    out: String,
+    /// The tags currently open, in the order they were opened
+    open: Vec<Tag>,
+}
+
+impl BbcodeWriter {
+    /// Closes and opens tags until exactly `target` is open, keeping the outer
+    /// tags both have in common so they stay nested.
+    fn switch_to(&mut self, target: &[Tag]) {
+        let kept = self
+            .open
+            .iter()
+            .zip(target)
+            .take_while(|(open, target)| open == target)
+            .count();
+        while self.open.len() > kept {
+            let tag = self.open.pop().expect("only open tags are closed");
+            self.out.push_str(tag.close());
+        }
+        for tag in &target[kept..] {
+            self.out.push_str(&tag.open());
+            self.open.push(tag.clone());
+        }
+    }
+
+    fn write(&mut self, component: &ChatComponent, parent: Option<&ComponentStyle>) {
+        let mut style = component.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+        let target = tags(&style);
+
+        let text = match component.get_kind() {
+            ComponentType::Text(text) => text.get_text().to_string(),
+            ComponentType::Translation(translation) => {
+                // every argument starts and ends with the translation's tags open
+                let args: Vec<String> = translation
+                    .get_args()
+                    .iter()
+                    .map(|arg| {
+                        let mut writer = BbcodeWriter {
+                            out: String::new(),
+                            open: target.clone(),
+                        };
+                        writer.write(arg, Some(&style));
+                        writer.switch_to(&target);
+                        writer.out
+                    })
+                    .collect();
+                format_translation(translation.get_key(), &args)
+            }
+            ComponentType::Score(score) => score.get_value().unwrap_or_default().to_string(),
+            ComponentType::Selector(selector) => selector.get_selector().to_string(),
+            ComponentType::Keybind(keybind) => default_key_name(keybind.get_keybind())
+                .unwrap_or(keybind.get_keybind())
+                .to_string(),
+            ComponentType::Nbt(_) => String::new(),
+            ComponentType::Custom(custom) => custom.plain_text(),
+        };
+        if !text.is_empty() {
+            self.switch_to(&target);
+            self.out.push_str(&text);
+        }
+        for sibling in component.get_siblings() {
+            self.write(sibling, Some(&style));
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Renders this component as BBCode with `[color]`, `[b]`, `[i]`, `[u]` and `[s]` tags,
+    /// `open_url` click events become `[url]` tags. Tags are only opened where the style
+    /// changes and are always closed in the right order.
+    ///
+    /// BBCode has no escapes, so brackets in the text are written as they are.
+    pub fn to_bbcode(&self) -> String {
+        let mut writer = BbcodeWriter {
+            out: String::new(),
+            open: vec![],
+        };
+        writer.write(self, None);
+        writer.switch_to(&[]);
+        writer.out
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+/// The result of mapping a Java Edition translation key to Bedrock Edition.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BedrockKey {
+    /// The key Bedrock clients know this text by.
+    Mapped(String),
+    /// Bedrock has no translation for this key (e.g. advancements or key binds),
+    /// the text should be sent in another way.
+    Untranslatable,
+}
+
+/// Java keys whose Bedrock key doesn't follow one of the naming patterns.
+/// [`None`] marks keys without a Bedrock translation.
+const EXPLICIT: &[(&str, Option<&str>)] = &[
+    ("chat.type.admin", None),
+    (
+        "multiplayer.player.joined.renamed",
+        Some("multiplayer.player.joined"),
+    ),
+    ("death.fell.accident.generic", Some("death.attack.fall")),
+    ("block.minecraft.grass_block", Some("tile.grass.name")),
+    ("block.minecraft.oak_planks", Some("tile.planks.oak.name")),
+    (
+        "item.minecraft.carrot_on_a_stick",
+        Some("item.carrotOnAStick.name"),
+    ),
+];
+
+/// Prefixes of Java keys that have no Bedrock translation.
+const UNTRANSLATABLE: &[&str] = &[
+    "advancements.",
+    "chat.type.advancement.",
+    "key.",
+    "options.",
+];
+
+/// Maps Java Edition translation keys to their Bedrock Edition equivalents.
+///
+/// Blocks, items and entities are mapped by their naming patterns
+/// (`block.minecraft.stone` to `tile.stone.name`), a few known differences
+/// by name and every other key is assumed to be shared by both editions.
+/// Use [`BedrockKeyMap::insert()`] to add mappings for your own keys.
+#[derive(Clone, Debug, Default)]
+pub struct BedrockKeyMap {
+    overrides: HashMap<String, Option<String>>,
+}
+
+impl BedrockKeyMap {
+    pub fn new() -> Self {
+        BedrockKeyMap::default()
+    }
+
+    /// Maps `java` to `bedrock`, [`None`] marks it as untranslatable.
+    pub fn insert<T: Into<String>, U: Into<String>>(&mut self, java: T, bedrock: Option<U>) {
+        self.overrides
+            .insert(java.into(), bedrock.map(|bedrock| bedrock.into()));
+    }
+
+    /// Returns the Bedrock key of the Java translation key `java`.
+    pub fn get(&self, java: &str) -> BedrockKey {
+        let mapped = match self.overrides.get(java) {
+            Some(bedrock) => bedrock.clone(),
+            None => default_mapping(java),
+        };
+        match mapped {
+            Some(bedrock) => BedrockKey::Mapped(bedrock),
+            None => BedrockKey::Untranslatable,
+        }
+    }
+
+    /// Returns the Java key of the Bedrock translation key `bedrock`, the opposite
+    /// of [`BedrockKeyMap::get()`]. Keys that don't follow a known pattern are assumed
+    /// to be shared by both editions.
+    pub fn get_java(&self, bedrock: &str) -> String {
+        let overridden = self
+            .overrides
+            .iter()
+            .find(|(_, mapped)| mapped.as_deref() == Some(bedrock));
+        if let Some((java, _)) = overridden {
+            return java.clone();
+        }
+        let explicit = EXPLICIT.iter().find(|(_, mapped)| *mapped == Some(bedrock));
+        if let Some((java, _)) = explicit {
+            return java.to_string();
+        }
+        let patterns = [
+            ("tile.", "block.minecraft."),
+            ("item.", "item.minecraft."),
+            ("entity.", "entity.minecraft."),
+        ];
+        for (bedrock_prefix, java_prefix) in patterns.iter() {
+            let name = bedrock
+                .strip_prefix(bedrock_prefix)
+                .and_then(|name| name.strip_suffix(".name"))
+                .filter(|name| !name.contains('.'));
+            if let Some(name) = name {
+                return format!("{}{}", java_prefix, name);
+            }
+        }
+        bedrock.to_string()
+    }
+}
+
+fn default_mapping(java: &str) -> Option<String> {
+    if let Some((_, bedrock)) = EXPLICIT.iter().find(|(key, _)| *key == java) {
+        return bedrock.map(String::from);
+    }
+    if UNTRANSLATABLE.iter().any(|prefix| java.starts_with(prefix)) {
+        return None;
+    }
+    let patterns = [
+        ("block.minecraft.", "tile."),
+        ("item.minecraft.", "item."),
+        ("entity.minecraft.", "entity."),
+    ];
+    for (java_prefix, bedrock_prefix) in patterns.iter() {
+        if let Some(name) = java.strip_prefix(java_prefix) {
+            // variants (`item.minecraft.potion.effect.water`) have no counterpart
+            if name.contains('.') {
+                return None;
+            }
+            return Some(format!("{}{}.name", bedrock_prefix, name));
+        }
+    }
+    Some(java.to_string())
+}
@@ -0,0 +1,8 @@
+//! Support for Bedrock Edition, e.g. for crossplay bridges forwarding Java messages.
+
+mod keys;
+#[cfg(feature = "json")]
+mod rawtext;
+mod translate;
+
+pub use keys::{BedrockKey, BedrockKeyMap};
@@ -0,0 +1,224 @@
+use serde::de::Error;
+use serde_json::{json, Value};
+
+use crate::component::{default_key_name, ChatComponent, ComponentType, TranslationComponent};
+use crate::legacy::{LegacyOptions, LegacyParser, LegacyWriter};
+use crate::style::ComponentStyle;
+
+/// The style Bedrock can display, `§n` and `§m` are material colors there
+/// so underlined and strikethrough text is left out.
+fn bedrock_style(style: &ComponentStyle) -> ComponentStyle {
+    let mut style = style.clone();
+    style.set_underlined(false);
+    style.set_strikethrough(false);
+    style
+}
+
+struct RawtextWriter<'a> {
+    legacy: LegacyWriter<'a>,
+    entries: Vec<Value>,
+    /// Text not yet added to `entries`, so adjacent texts become one entry
+    text: String,
+}
+
+impl<'a> RawtextWriter<'a> {
+    fn new(options: &'a LegacyOptions, style: Option<&ComponentStyle>) -> Self {
+        RawtextWriter {
+            legacy: LegacyWriter::new(options, style),
+            entries: vec![],
+            text: String::new(),
+        }
+    }
+
+    fn push(&mut self, entry: Value) {
+        if !self.text.is_empty() {
+            let text = std::mem::take(&mut self.text);
+            self.entries.push(json!({ "text": text }));
+        }
+        self.entries.push(entry);
+    }
+
+    fn finish(mut self) -> Vec<Value> {
+        if !self.text.is_empty() {
+            self.entries.push(json!({ "text": self.text }));
+        }
+        self.entries
+    }
+
+    fn write(&mut self, component: &ChatComponent, parent: Option<&ComponentStyle>) {
+        let mut style = component.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+        let style = bedrock_style(&style);
+
+        let entry = match component.get_kind() {
+            ComponentType::Text(text) => Err(text.get_text().to_string()),
+            ComponentType::Translation(translation) => {
+                // every argument is one entry starting and ending in the translation's formatting
+                let args: Vec<Value> = translation
+                    .get_args()
+                    .iter()
+                    .map(|arg| {
+                        let mut writer = RawtextWriter::new(self.legacy.options(), Some(&style));
+                        writer.write(arg, Some(&style));
+                        writer.text.push_str(&writer.legacy.codes_to(&style));
+                        let mut entries = writer.finish();
+                        match entries.len() {
+                            1 => entries.remove(0),
+                            _ => json!({ "rawtext": entries }),
+                        }
+                    })
+                    .collect();
+                let mut entry = json!({ "translate": translation.get_key() });
+                if !args.is_empty() {
+                    entry["with"] = json!({ "rawtext": args });
+                }
+                Ok(entry)
+            }
+            ComponentType::Score(score) => Ok(json!({
+                "score": { "name": score.get_name(), "objective": score.get_objective() }
+            })),
+            ComponentType::Selector(selector) => Ok(json!({ "selector": selector.get_selector() })),
+            ComponentType::Keybind(keybind) => Err(default_key_name(keybind.get_keybind())
+                .unwrap_or(keybind.get_keybind())
+                .to_string()),
+            // Bedrock has no NBT components, an unresolved one displays nothing
+            ComponentType::Nbt(_) => Err(String::new()),
+            ComponentType::Custom(custom) => Err(custom.plain_text()),
+        };
+        match entry {
+            Ok(entry) => {
+                let codes = self.legacy.codes_to(&style);
+                self.text.push_str(&codes);
+                self.push(entry);
+            }
+            Err(text) if !text.is_empty() => {
+                let codes = self.legacy.codes_to(&style);
+                self.text.push_str(&codes);
+                self.text.push_str(&text);
+            }
+            Err(_) => {}
+        }
+        for sibling in component.get_siblings() {
+            self.write(sibling, Some(&style));
+        }
+    }
+}
+
+/// Reads the entries of a `rawtext` array, the formatting of each text carries
+/// over to the entries after it.
+fn read_entries(
+    entries: &Value,
+    parser: &mut LegacyParser,
+    version: u32,
+    out: &mut Vec<ChatComponent>,
+) -> Result<(), serde_json::Error> {
+    let entries = entries
+        .as_array()
+        .ok_or_else(|| serde_json::Error::custom("rawtext must be an array"))?;
+    for entry in entries {
+        let style = parser.get_style().clone();
+        if let Some(text) = entry.get("text").and_then(Value::as_str) {
+            out.extend(parser.feed(text));
+        } else if let Some(key) = entry.get("translate").and_then(Value::as_str) {
+            let mut translation = TranslationComponent::from_key(key);
+            let args = match entry.get("with") {
+                // the arguments are either plain strings or a rawtext object
+                Some(Value::Object(with)) => with
+                    .get("rawtext")
+                    .and_then(Value::as_array)
+                    .cloned()
+                    .ok_or_else(|| serde_json::Error::custom("with must contain rawtext"))?,
+                Some(Value::Array(args)) => args
+                    .iter()
+                    .map(|arg| match arg {
+                        Value::String(text) => Ok(json!({ "text": text })),
+                        _ => Err(serde_json::Error::custom("with must contain strings")),
+                    })
+                    .collect::<Result<_, _>>()?,
+                Some(_) => return Err(serde_json::Error::custom("invalid with")),
+                None => vec![],
+            };
+            for arg in args {
+                let mut arg_parser = LegacyParser::new(version);
+                arg_parser.set_style(style.clone());
+                let mut components = vec![];
+                read_entries(&json!([arg]), &mut arg_parser, version, &mut components)?;
+                components.extend(arg_parser.finish());
+                translation.add_arg(join(components, version));
+            }
+            out.push(ChatComponent::from_component(
+                ComponentType::Translation(translation),
+                style,
+            ));
+        } else if let Some(score) = entry.get("score") {
+            let field = |name: &str| {
+                score
+                    .get(name)
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| serde_json::Error::custom(format!("score needs a {}", name)))
+            };
+            out.push(ChatComponent::from_score(
+                field("name")?,
+                field("objective")?,
+                style,
+            ));
+        } else if let Some(selector) = entry.get("selector").and_then(Value::as_str) {
+            out.push(ChatComponent::from_selector(selector, style));
+        } else if let Some(entries) = entry.get("rawtext") {
+            read_entries(entries, parser, version, out)?;
+        } else {
+            return Err(serde_json::Error::custom(format!(
+                "unknown rawtext entry {}",
+                entry
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Makes one component out of `components`, an empty root with them as siblings
+/// unless there is only one.
+fn join(mut components: Vec<ChatComponent>, version: u32) -> ChatComponent {
+    if components.len() == 1 {
+        return components.remove(0);
+    }
+    let mut component = ChatComponent::from_text("", ComponentStyle::with_version(version));
+    *component.get_siblings_mut() = components;
+    component
+}
+
+impl ChatComponent {
+    /// Parses the `rawtext` JSON of Bedrock Edition into components of the given version,
+    /// see [`ChatComponent::to_rawtext()`].
+    ///
+    /// The `§` codes in texts are read into styles that carry over to later entries,
+    /// like Bedrock displays them. `with` may be a list of strings or a `rawtext` object,
+    /// every one of its entries becomes an argument of the translation.
+    pub fn from_rawtext(json: &str, version: u32) -> Result<ChatComponent, serde_json::Error> {
+        let value: Value = serde_json::from_str(json)?;
+        let entries = value
+            .get("rawtext")
+            .ok_or_else(|| serde_json::Error::custom("missing rawtext"))?;
+        let mut parser = LegacyParser::new(version);
+        let mut components = vec![];
+        read_entries(entries, &mut parser, version, &mut components)?;
+        components.extend(parser.finish());
+        Ok(join(components, version))
+    }
+
+    /// Converts this component into the `rawtext` JSON of Bedrock Edition, as used by
+    /// `/tellraw` and `/titleraw`.
+    ///
+    /// Bedrock has no JSON styles, so the formatting is written as `§` codes inside the
+    /// texts (with custom colors as the nearest of the 16 colors). Events, underlined and
+    /// strikethrough text are left out and keybinds become their default key.
+    pub fn to_rawtext(&self) -> String {
+        let options = LegacyOptions::default();
+        let mut writer = RawtextWriter::new(&options, None);
+        writer.write(self, None);
+        let entries = writer.finish();
+        json!({ "rawtext": entries }).to_string()
+    }
+}
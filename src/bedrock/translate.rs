@@ -0,0 +1,98 @@
+use crate::bedrock::{BedrockKey, BedrockKeyMap};
+use crate::component::{
+    default_key_name, translation_pieces, ChatComponent, ComponentType, TextComponent,
+    TranslationComponent, TranslationPiece,
+};
+use crate::style::{ChatColor, ComponentStyle};
+
+/// The parts of `style` Bedrock displays: the 16 colors, bold, italic and obfuscated.
+fn supported_style(style: &ComponentStyle) -> ComponentStyle {
+    let mut supported = ComponentStyle::with_version(style.get_version());
+    supported.set_color(style.get_color().and_then(ChatColor::to_nearest_legacy));
+    if let Some(bold) = style.get_bold() {
+        supported.set_bold(bold);
+    }
+    if let Some(italic) = style.get_italic() {
+        supported.set_italic(italic);
+    }
+    if let Some(obfuscated) = style.get_obfuscated() {
+        supported.set_obfuscated(obfuscated);
+    }
+    supported
+}
+
+impl ChatComponent {
+    /// Converts this Java Edition component into one Bedrock Edition displays the same way,
+    /// e.g. before sending it with [`ChatComponent::to_rawtext()`].
+    ///
+    /// Translation keys are mapped with `keys`. Translations Bedrock doesn't have become
+    /// text, with their arguments placed into the key like into a pattern, so map their keys
+    /// with [`BedrockKeyMap::insert()`] where possible. Keybinds become their default key,
+    /// custom colors the nearest of the 16 colors and everything else Bedrock can't show
+    /// (underlined, strikethrough, fonts, insertions and events) is left out.
+    pub fn to_bedrock(&self, keys: &BedrockKeyMap) -> ChatComponent {
+        let version = self.get_version();
+        let mut siblings = vec![];
+        let kind = match self.get_kind() {
+            ComponentType::Translation(translation) => {
+                let args: Vec<ChatComponent> = translation
+                    .get_args()
+                    .iter()
+                    .map(|arg| arg.to_bedrock(keys))
+                    .collect();
+                match keys.get(translation.get_key()) {
+                    BedrockKey::Mapped(key) => {
+                        let mut translation = TranslationComponent::from_key(key);
+                        args.into_iter().for_each(|arg| translation.add_arg(arg));
+                        ComponentType::Translation(translation)
+                    }
+                    BedrockKey::Untranslatable => {
+                        for piece in translation_pieces(translation.get_key()) {
+                            match piece {
+                                TranslationPiece::Text(text) => {
+                                    siblings.push(ChatComponent::from_text(
+                                        text,
+                                        ComponentStyle::with_version(version),
+                                    ))
+                                }
+                                TranslationPiece::Arg(index) => {
+                                    siblings.extend(args.get(index).cloned())
+                                }
+                            }
+                        }
+                        ComponentType::Text(TextComponent::from_text(""))
+                    }
+                }
+            }
+            ComponentType::Keybind(keybind) => ComponentType::Text(TextComponent::from_text(
+                default_key_name(keybind.get_keybind()).unwrap_or(keybind.get_keybind()),
+            )),
+            ComponentType::Custom(custom) => {
+                ComponentType::Text(TextComponent::from_text(custom.plain_text()))
+            }
+            kind => kind.clone(),
+        };
+        let mut component = ChatComponent::from_component(kind, supported_style(self.get_style()));
+        siblings.extend(
+            self.get_siblings()
+                .iter()
+                .map(|sibling| sibling.to_bedrock(keys)),
+        );
+        *component.get_siblings_mut() = siblings;
+        component
+    }
+
+    /// Converts this Bedrock Edition component (e.g. read with [`ChatComponent::from_rawtext()`])
+    /// into a Java Edition one by mapping its translation keys back,
+    /// see [`BedrockKeyMap::get_java()`].
+    pub fn to_java(&self, keys: &BedrockKeyMap) -> ChatComponent {
+        let mut component = self.clone();
+        component.walk_mut(&mut |component| {
+            if let ComponentType::Translation(translation) = component.get_kind_mut() {
+                let key = keys.get_java(translation.get_key());
+                translation.set_key(key);
+            }
+        });
+        component
+    }
+}
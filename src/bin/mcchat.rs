@@ -0,0 +1,91 @@
+//! `mcchat` converts chat messages between formats.
+//!
+//! Every line read from stdin is treated as one message and written
+//! to stdout in the requested output format.
+
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use mc_chat::{formats, VERSION_1_16};
+
+const USAGE: &str = "Usage: mcchat [--from <format>] [--to <format>] [--version <protocol>]
+
+Converts every line of stdin from one chat format into another.
+
+Formats: json, legacy, discord, rawtext, minimessage (with the `minimessage` feature),
+snbt (with the `nbt` feature), ansi, bbcode, irc and html (output only)";
+
+struct Options {
+    from: String,
+    to: String,
+    version: u32,
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        from: "json".to_string(),
+        to: "json".to_string(),
+        version: VERSION_1_16,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+        match arg.as_str() {
+            "--from" | "-f" => options.from = value()?,
+            "--to" | "-t" => options.to = value()?,
+            "--version" | "-v" => {
+                let version = value()?;
+                options.version = version
+                    .parse()
+                    .map_err(|_| format!("{} is not a valid protocol version!", version))?;
+            }
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            _ => return Err(format!("Unknown argument {}", arg)),
+        }
+    }
+    Ok(options)
+}
+
+fn convert(line: &str, options: &Options) -> Result<String, String> {
+    let mut component = formats::read(line, &options.from)?;
+    component.downgrade(options.version);
+    formats::write(&component, &options.to)
+}
+
+fn main() {
+    let options = parse_args().unwrap_or_else(|err| {
+        eprintln!("{}\n\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut failed = false;
+    for (number, line) in stdin.lock().lines().enumerate() {
+        let line = line.unwrap_or_else(|err| {
+            eprintln!("Failed to read stdin: {}", err);
+            process::exit(1);
+        });
+        if line.trim().is_empty() {
+            continue;
+        }
+        match convert(&line, &options) {
+            Ok(output) => {
+                if writeln!(out, "{}", output).is_err() {
+                    process::exit(1);
+                }
+            }
+            Err(err) => {
+                eprintln!("line {}: {}", number + 1, err);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,197 @@
+//! Assembling components into written books, with the limits of the book screen.
+
+use std::fmt::{Display, Formatter};
+
+use crate::component::ChatComponent;
+use crate::layout::wrap_lines;
+#[cfg(feature = "nbt")]
+use crate::nbt::{write_snbt, Tag};
+use crate::style::ComponentStyle;
+#[cfg(feature = "nbt")]
+use crate::style::VERSION_1_8;
+
+/// The width (in pixels) of a line on a page.
+pub const PAGE_WIDTH: u32 = 114;
+/// The number of lines fitting on a page.
+pub const PAGE_LINES: usize = 14;
+/// The number of characters a page may have.
+pub const PAGE_LENGTH: usize = 1023;
+/// The number of characters the title of a book may have.
+pub const TITLE_LENGTH: usize = 32;
+/// The number of characters the author of a book may have, the length of player names.
+pub const AUTHOR_LENGTH: usize = 16;
+
+/// The first version (1.21.5) storing pages as components instead of JSON strings.
+#[cfg(feature = "nbt")]
+const VERSION_1_21_5: u32 = 770;
+
+/// The error returned when the title or author of a book is too long.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BookErr {
+    /// A title longer than [`TITLE_LENGTH`], with its length
+    TitleTooLong(usize),
+    /// An author longer than [`AUTHOR_LENGTH`], with its length
+    AuthorTooLong(usize),
+}
+
+impl Display for BookErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BookErr::TitleTooLong(length) => write!(
+                f,
+                "The title has {} characters, books allow {}",
+                length, TITLE_LENGTH
+            ),
+            BookErr::AuthorTooLong(length) => write!(
+                f,
+                "The author has {} characters, books allow {}",
+                length, AUTHOR_LENGTH
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BookErr {}
+
+/// Splits `component` into pages: lines are wrapped at [`PAGE_WIDTH`] like the book
+/// screen does and every page gets at most [`PAGE_LINES`] lines and [`PAGE_LENGTH`] characters.
+///
+/// Every page keeps the styles and events resolved from the original tree.
+pub fn split_pages(component: &ChatComponent) -> Vec<ChatComponent> {
+    let version = component.get_version();
+    let mut pages = vec![];
+    let mut page: Vec<ChatComponent> = vec![];
+    let mut length = 0;
+    for line in wrap_lines(component, PAGE_WIDTH) {
+        let line_length = line.to_plain_text().chars().count();
+        // the line break before the line counts too
+        if !page.is_empty() && (page.len() == PAGE_LINES || length + 1 + line_length > PAGE_LENGTH)
+        {
+            pages.push(join_lines(std::mem::take(&mut page), version));
+            length = 0;
+        }
+        if !page.is_empty() {
+            length += 1;
+        }
+        length += line_length;
+        page.push(line);
+    }
+    if !page.is_empty() {
+        pages.push(join_lines(page, version));
+    }
+    pages
+}
+
+fn join_lines(lines: Vec<ChatComponent>, version: u32) -> ChatComponent {
+    let mut page = ChatComponent::from_text("", ComponentStyle::with_version(version));
+    for (i, line) in lines.into_iter().enumerate() {
+        if i > 0 {
+            page.get_siblings_mut().push(ChatComponent::from_text(
+                "\n",
+                ComponentStyle::with_version(version),
+            ));
+        }
+        page.get_siblings_mut().push(line);
+    }
+    page
+}
+
+/// A signed book, its pages are shown as they are so they should fit on a page
+/// (see [`split_pages()`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrittenBook {
+    title: String,
+    author: String,
+    pages: Vec<ChatComponent>,
+}
+
+impl WrittenBook {
+    /// Creates a book without pages, the title and author are checked against
+    /// [`TITLE_LENGTH`] and [`AUTHOR_LENGTH`].
+    pub fn new<T: Into<String>, U: Into<String>>(title: T, author: U) -> Result<Self, BookErr> {
+        let title = title.into();
+        let author = author.into();
+        let title_length = title.chars().count();
+        if title_length > TITLE_LENGTH {
+            return Err(BookErr::TitleTooLong(title_length));
+        }
+        let author_length = author.chars().count();
+        if author_length > AUTHOR_LENGTH {
+            return Err(BookErr::AuthorTooLong(author_length));
+        }
+        Ok(WrittenBook {
+            title,
+            author,
+            pages: vec![],
+        })
+    }
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn get_author(&self) -> &str {
+        &self.author
+    }
+
+    pub fn get_pages(&self) -> &Vec<ChatComponent> {
+        &self.pages
+    }
+
+    pub fn get_pages_mut(&mut self) -> &mut Vec<ChatComponent> {
+        &mut self.pages
+    }
+
+    pub fn add_page(&mut self, page: ChatComponent) {
+        self.pages.push(page);
+    }
+
+    pub fn page(mut self, page: ChatComponent) -> Self {
+        self.add_page(page);
+        self
+    }
+
+    /// Adds `text` on as many new pages as it needs, see [`split_pages()`].
+    pub fn add_text(&mut self, text: &ChatComponent) {
+        self.pages.extend(split_pages(text));
+    }
+
+    pub fn text(mut self, text: &ChatComponent) -> Self {
+        self.add_text(text);
+        self
+    }
+
+    /// The pages as NBT: legacy strings before 1.8, JSON strings before 1.21.5.
+    #[cfg(feature = "nbt")]
+    fn page_tags(&self, version: u32) -> Vec<Tag> {
+        self.pages
+            .iter()
+            .map(|page| {
+                let mut page = page.clone();
+                page.downgrade(version);
+                if version >= VERSION_1_21_5 {
+                    page.to_modern_nbt_tag()
+                } else if version < VERSION_1_8 {
+                    Tag::String(page.to_legacy_string())
+                } else {
+                    Tag::String(
+                        serde_json::to_string(&page).expect("components always serialize to JSON"),
+                    )
+                }
+            })
+            .collect()
+    }
+
+    /// The book as SNBT for the given version: the `written_book_content` item component
+    /// since 1.20.5, the item's `tag` before. Both have the same fields.
+    #[cfg(feature = "nbt")]
+    pub fn to_snbt(&self, version: u32) -> String {
+        let tag = Tag::Compound(vec![
+            ("title".to_string(), Tag::String(self.title.clone())),
+            ("author".to_string(), Tag::String(self.author.clone())),
+            ("pages".to_string(), Tag::List(self.page_tags(version))),
+            ("resolved".to_string(), Tag::Byte(1)),
+        ]);
+        write_snbt(&tag)
+    }
+}
@@ -0,0 +1,218 @@
+use crate::component::{ChatComponent, ComponentType, TranslationComponent};
+use crate::style::{ChatColor, ComponentStyle};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A value filled into a [`ChatDecoration`] when a message is decorated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ChatTypeParameter {
+    Sender,
+    Target,
+    Content,
+}
+
+/// How a message of a [`ChatType`] is displayed (or narrated): a translation
+/// whose arguments are the [`ChatTypeParameter`]s, in order.
+///
+/// Matches the `chat` and `narration` elements of the 1.19+ chat type registry.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChatDecoration {
+    translation_key: String,
+    parameters: Vec<ChatTypeParameter>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Option::is_none", default)
+    )]
+    style: Option<ComponentStyle>,
+}
+
+impl ChatDecoration {
+    pub fn new<T: Into<String>>(translation_key: T, parameters: Vec<ChatTypeParameter>) -> Self {
+        ChatDecoration {
+            translation_key: translation_key.into(),
+            parameters,
+            style: None,
+        }
+    }
+
+    pub fn get_translation_key(&self) -> &str {
+        &self.translation_key
+    }
+
+    pub fn get_parameters(&self) -> &[ChatTypeParameter] {
+        &self.parameters
+    }
+
+    pub fn get_style(&self) -> Option<&ComponentStyle> {
+        self.style.as_ref()
+    }
+
+    pub fn set_style(&mut self, style: Option<ComponentStyle>) {
+        self.style = style;
+    }
+
+    pub fn style(mut self, style: Option<ComponentStyle>) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    /// Builds the displayed message, a missing `target` is displayed as empty text.
+    pub fn decorate(
+        &self,
+        content: ChatComponent,
+        sender: ChatComponent,
+        target: Option<ChatComponent>,
+    ) -> ChatComponent {
+        let version = content.get_version();
+        let mut translation = TranslationComponent::from_key(self.translation_key.as_str());
+        for parameter in &self.parameters {
+            translation.add_arg(match parameter {
+                ChatTypeParameter::Sender => sender.clone(),
+                ChatTypeParameter::Content => content.clone(),
+                ChatTypeParameter::Target => target.clone().unwrap_or_else(|| {
+                    ChatComponent::from_text("", ComponentStyle::with_version(version))
+                }),
+            });
+        }
+        let mut style = self
+            .style
+            .clone()
+            .unwrap_or_else(|| ComponentStyle::with_version(version));
+        style.change_version(version);
+        ChatComponent::from_component(ComponentType::Translation(translation), style)
+    }
+}
+
+/// An entry of the 1.19+ chat type registry, deciding how player messages are displayed.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChatType {
+    chat: ChatDecoration,
+    narration: ChatDecoration,
+}
+
+impl ChatType {
+    pub fn new(chat: ChatDecoration, narration: ChatDecoration) -> Self {
+        ChatType { chat, narration }
+    }
+
+    /// `minecraft:chat`, regular player messages.
+    pub fn chat() -> Self {
+        use ChatTypeParameter::*;
+        ChatType::new(
+            ChatDecoration::new("chat.type.text", vec![Sender, Content]),
+            ChatDecoration::new("chat.type.text.narrate", vec![Sender, Content]),
+        )
+    }
+
+    /// `minecraft:say_command`
+    pub fn say_command() -> Self {
+        use ChatTypeParameter::*;
+        ChatType::new(
+            ChatDecoration::new("chat.type.announcement", vec![Sender, Content]),
+            ChatDecoration::new("chat.type.text.narrate", vec![Sender, Content]),
+        )
+    }
+
+    /// `minecraft:msg_command_incoming`, a private message received from the sender.
+    pub fn msg_command_incoming() -> Self {
+        use ChatTypeParameter::*;
+        ChatType::new(
+            ChatDecoration::new("commands.message.display.incoming", vec![Sender, Content])
+                .style(Some(private_message_style())),
+            ChatDecoration::new("chat.type.text.narrate", vec![Sender, Content]),
+        )
+    }
+
+    /// `minecraft:msg_command_outgoing`, a private message sent to the target.
+    pub fn msg_command_outgoing() -> Self {
+        use ChatTypeParameter::*;
+        ChatType::new(
+            ChatDecoration::new("commands.message.display.outgoing", vec![Target, Content])
+                .style(Some(private_message_style())),
+            ChatDecoration::new("chat.type.text.narrate", vec![Sender, Content]),
+        )
+    }
+
+    /// `minecraft:team_msg_command_incoming`, the target being the team.
+    pub fn team_msg_command_incoming() -> Self {
+        use ChatTypeParameter::*;
+        ChatType::new(
+            ChatDecoration::new("chat.type.team.text", vec![Target, Sender, Content]),
+            ChatDecoration::new("chat.type.text.narrate", vec![Sender, Content]),
+        )
+    }
+
+    /// `minecraft:team_msg_command_outgoing`, the target being the team.
+    pub fn team_msg_command_outgoing() -> Self {
+        use ChatTypeParameter::*;
+        ChatType::new(
+            ChatDecoration::new("chat.type.team.sent", vec![Target, Sender, Content]),
+            ChatDecoration::new("chat.type.text.narrate", vec![Sender, Content]),
+        )
+    }
+
+    /// `minecraft:emote_command`
+    pub fn emote_command() -> Self {
+        use ChatTypeParameter::*;
+        ChatType::new(
+            ChatDecoration::new("chat.type.emote", vec![Sender, Content]),
+            ChatDecoration::new("chat.type.emote", vec![Sender, Content]),
+        )
+    }
+
+    pub fn get_chat(&self) -> &ChatDecoration {
+        &self.chat
+    }
+
+    pub fn get_narration(&self) -> &ChatDecoration {
+        &self.narration
+    }
+
+    /// Builds the message displayed in chat, see [`ChatDecoration::decorate()`].
+    pub fn decorate(
+        &self,
+        content: ChatComponent,
+        sender: ChatComponent,
+        target: Option<ChatComponent>,
+    ) -> ChatComponent {
+        self.chat.decorate(content, sender, target)
+    }
+
+    /// Builds the message a vanilla client displays for a 1.19+ player chat packet.
+    ///
+    /// The `unsigned_content` (decorated by the server) replaces the signed plain
+    /// `content` when present, the result is then decorated with this chat type.
+    pub fn render_player_chat(
+        &self,
+        content: &str,
+        unsigned_content: Option<ChatComponent>,
+        sender: ChatComponent,
+        target: Option<ChatComponent>,
+    ) -> ChatComponent {
+        let content = unsigned_content.unwrap_or_else(|| {
+            ChatComponent::from_text(content, ComponentStyle::with_version(sender.get_version()))
+        });
+        self.decorate(content, sender, target)
+    }
+
+    /// Builds the message read by the narrator, see [`ChatDecoration::decorate()`].
+    pub fn decorate_narration(
+        &self,
+        content: ChatComponent,
+        sender: ChatComponent,
+        target: Option<ChatComponent>,
+    ) -> ChatComponent {
+        self.narration.decorate(content, sender, target)
+    }
+}
+
+fn private_message_style() -> ComponentStyle {
+    ComponentStyle::v1_16()
+        .color(Some(ChatColor::Gray))
+        .italic(true)
+}
@@ -0,0 +1,87 @@
+//! A tokio-util codec framing components the way packets send them.
+
+use std::fmt::{Display, Formatter};
+use std::io;
+
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::component::ChatComponent;
+use crate::protocol::PacketErr;
+
+/// The error of a [`ChatCodec`], either of the stream or of the data read from it.
+#[derive(Debug)]
+pub enum CodecErr {
+    /// Reading from or writing to the stream failed
+    Io(io::Error),
+    /// The data isn't a valid component
+    Packet(PacketErr),
+}
+
+impl Display for CodecErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CodecErr::Io(err) => err.fmt(f),
+            CodecErr::Packet(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for CodecErr {}
+
+impl From<io::Error> for CodecErr {
+    fn from(err: io::Error) -> Self {
+        CodecErr::Io(err)
+    }
+}
+
+/// Encodes and decodes components sent by packets of a protocol version, see
+/// [`ChatComponent::write_packet()`] and [`ChatComponent::read_packet()`].
+///
+/// Decoding waits for more data until a whole component arrived, so it can be
+/// used with `Framed` on streams sending nothing but components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChatCodec {
+    version: u32,
+}
+
+impl ChatCodec {
+    pub fn new(version: u32) -> Self {
+        ChatCodec { version }
+    }
+
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn set_version(&mut self, version: u32) {
+        self.version = version;
+    }
+}
+
+impl Encoder<&ChatComponent> for ChatCodec {
+    type Error = CodecErr;
+
+    fn encode(&mut self, component: &ChatComponent, dst: &mut BytesMut) -> Result<(), CodecErr> {
+        component.write_to_buf(self.version, dst);
+        Ok(())
+    }
+}
+
+impl Decoder for ChatCodec {
+    type Item = ChatComponent;
+    type Error = CodecErr;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<ChatComponent>, CodecErr> {
+        let mut input = &src[..];
+        match ChatComponent::read_packet(self.version, &mut input) {
+            Ok(component) => {
+                let read = src.len() - input.len();
+                src.advance(read);
+                Ok(Some(component))
+            }
+            Err(PacketErr::Incomplete) => Ok(None),
+            Err(err) => Err(CodecErr::Packet(err)),
+        }
+    }
+}
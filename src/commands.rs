@@ -0,0 +1,282 @@
+//! Working with commands embedding components, like the ones found in
+//! command blocks, functions and data packs.
+
+use std::fmt::{Display, Formatter};
+
+use crate::component::ChatComponent;
+use crate::style::VERSION_1_16;
+
+/// Which part of the title screen a `/title` command changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TitleAction {
+    Title,
+    Subtitle,
+    Actionbar,
+}
+
+impl TitleAction {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            TitleAction::Title => "title",
+            TitleAction::Subtitle => "subtitle",
+            TitleAction::Actionbar => "actionbar",
+        }
+    }
+}
+
+/// A command containing a component, without the component itself.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComponentCommand {
+    /// `/tellraw <targets> <message>`
+    Tellraw { targets: String },
+    /// `/title <targets> (title|subtitle|actionbar) <title>`
+    Title {
+        targets: String,
+        action: TitleAction,
+    },
+    /// `/bossbar add <id> <name>` or `/bossbar set <id> name <name>`
+    Bossbar { id: String },
+    /// `/titleraw <targets> (title|subtitle|actionbar) <rawtext>` of Bedrock Edition
+    Titleraw {
+        targets: String,
+        action: TitleAction,
+    },
+}
+
+/// The first version (1.21.5) reading components in commands as SNBT instead of JSON.
+const SNBT_VERSION: u32 = 770;
+
+impl ComponentCommand {
+    /// The selector or player name of the players this command is sent to,
+    /// `None` for `/bossbar`.
+    pub fn get_targets(&self) -> Option<&str> {
+        match self {
+            ComponentCommand::Tellraw { targets }
+            | ComponentCommand::Title { targets, .. }
+            | ComponentCommand::Titleraw { targets, .. } => Some(targets),
+            ComponentCommand::Bossbar { .. } => None,
+        }
+    }
+
+    /// Builds this command with `component` as its argument, serialized for the given version:
+    /// as JSON, or as SNBT since 1.21.5. `/titleraw` always
+    /// takes Bedrock's rawtext and ignores the version.
+    ///
+    /// The command has no leading `/` so it can be used in functions as well,
+    /// add one to paste it into chat.
+    pub fn build(&self, component: &ChatComponent, version: u32) -> String {
+        if let ComponentCommand::Titleraw { targets, action } = self {
+            return format!(
+                "titleraw {} {} {}",
+                targets,
+                action.get_name(),
+                component.to_rawtext()
+            );
+        }
+        let mut component = component.clone();
+        component.downgrade(version);
+        let component = if version >= SNBT_VERSION {
+            component.to_snbt()
+        } else {
+            serde_json::to_string(&component).expect("components always serialize to JSON")
+        };
+        match self {
+            ComponentCommand::Tellraw { targets } => format!("tellraw {} {}", targets, component),
+            ComponentCommand::Title { targets, action } => {
+                format!("title {} {} {}", targets, action.get_name(), component)
+            }
+            ComponentCommand::Bossbar { id } => format!("bossbar set {} name {}", id, component),
+            ComponentCommand::Titleraw { .. } => unreachable!("titleraw is built above"),
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Builds a `/tellraw` command sending this component to `targets`, see [`ComponentCommand::build()`].
+    pub fn to_tellraw_command<T: Into<String>>(&self, targets: T, version: u32) -> String {
+        ComponentCommand::Tellraw {
+            targets: targets.into(),
+        }
+        .build(self, version)
+    }
+
+    /// Builds a `/title` command showing this component to `targets`, see [`ComponentCommand::build()`].
+    ///
+    /// `/title` exists since 1.8 and [`TitleAction::Actionbar`] since 1.11.
+    pub fn to_title_command<T: Into<String>>(
+        &self,
+        targets: T,
+        action: TitleAction,
+        version: u32,
+    ) -> String {
+        ComponentCommand::Title {
+            targets: targets.into(),
+            action,
+        }
+        .build(self, version)
+    }
+
+    /// Builds a Bedrock Edition `/titleraw` command showing this component to `targets`,
+    /// see [`ChatComponent::to_rawtext()`].
+    pub fn to_titleraw_command<T: Into<String>>(&self, targets: T, action: TitleAction) -> String {
+        ComponentCommand::Titleraw {
+            targets: targets.into(),
+            action,
+        }
+        .build(self, 0)
+    }
+}
+
+#[derive(Debug)]
+pub enum CommandParseErr {
+    /// The command doesn't take a component
+    UnknownCommand(String),
+    /// An argument before the component is missing
+    MissingArgument(&'static str),
+    /// The component couldn't be parsed
+    InvalidComponent(String),
+}
+
+impl Display for CommandParseErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandParseErr::UnknownCommand(command) => {
+                write!(f, "{} doesn't take a component!", command)
+            }
+            CommandParseErr::MissingArgument(argument) => write!(f, "Missing {}", argument),
+            CommandParseErr::InvalidComponent(err) => write!(f, "Invalid component: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for CommandParseErr {}
+
+/// Splits off the next argument, keeping selector arguments (`@a[name="A B"]`) together.
+fn next_argument<'a>(
+    input: &'a str,
+    name: &'static str,
+) -> Result<(&'a str, &'a str), CommandParseErr> {
+    let input = input.trim_start();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in input.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None => match c {
+                '"' | '\'' if depth > 0 => quote = Some(c),
+                '[' | '{' => depth += 1,
+                ']' | '}' => depth -= 1,
+                c if c.is_whitespace() && depth <= 0 => return Ok((&input[..i], &input[i..])),
+                _ => {}
+            },
+        }
+    }
+    if input.is_empty() {
+        Err(CommandParseErr::MissingArgument(name))
+    } else {
+        Ok((input, ""))
+    }
+}
+
+/// Parses a `/tellraw`, `/title`, `/titleraw` or `/bossbar` command (with or without leading `/`,
+/// optionally behind `execute ... run`), returning the command and its parsed component.
+///
+/// Components of Java Edition commands are read as JSON, or as SNBT with the `nbt` feature,
+/// the rawtext of `/titleraw` is read with [`ChatComponent::from_rawtext()`].
+pub fn parse_command(line: &str) -> Result<(ComponentCommand, ChatComponent), CommandParseErr> {
+    let mut line = line.trim().trim_start_matches('/');
+    // the command follows the first `run` of the subcommands, which can be nested
+    while let Some(mut rest) = line.strip_prefix("execute ") {
+        loop {
+            match next_argument(rest, "command") {
+                Ok(("run", command)) => {
+                    line = command.trim_start();
+                    break;
+                }
+                Ok((_, next)) => rest = next,
+                // not a command taking a component
+                Err(_) => return Err(CommandParseErr::UnknownCommand("execute".to_string())),
+            }
+        }
+    }
+
+    let (name, rest) = next_argument(line, "command")?;
+    let (command, component) = match name {
+        "tellraw" => {
+            let (targets, rest) = next_argument(rest, "targets")?;
+            let targets = targets.to_string();
+            (ComponentCommand::Tellraw { targets }, rest)
+        }
+        "title" | "titleraw" => {
+            let (targets, rest) = next_argument(rest, "targets")?;
+            let (action, rest) = next_argument(rest, "title action")?;
+            let action = match action {
+                "title" => TitleAction::Title,
+                "subtitle" => TitleAction::Subtitle,
+                "actionbar" => TitleAction::Actionbar,
+                _ => {
+                    return Err(CommandParseErr::UnknownCommand(format!(
+                        "{} {}",
+                        name, action
+                    )))
+                }
+            };
+            let targets = targets.to_string();
+            if name == "titleraw" {
+                (ComponentCommand::Titleraw { targets, action }, rest)
+            } else {
+                (ComponentCommand::Title { targets, action }, rest)
+            }
+        }
+        "bossbar" => {
+            let (operation, rest) = next_argument(rest, "bossbar operation")?;
+            let (id, rest) = next_argument(rest, "bossbar id")?;
+            let rest = match operation {
+                "add" => rest,
+                "set" => match next_argument(rest, "bossbar property")? {
+                    ("name", rest) => rest,
+                    (property, _) => {
+                        return Err(CommandParseErr::UnknownCommand(format!(
+                            "bossbar set {}",
+                            property
+                        )))
+                    }
+                },
+                _ => {
+                    return Err(CommandParseErr::UnknownCommand(format!(
+                        "bossbar {}",
+                        operation
+                    )))
+                }
+            };
+            let id = id.to_string();
+            (ComponentCommand::Bossbar { id }, rest)
+        }
+        _ => return Err(CommandParseErr::UnknownCommand(name.to_string())),
+    };
+
+    let component = component.trim();
+    if component.is_empty() {
+        return Err(CommandParseErr::MissingArgument("component"));
+    }
+    let component = match command {
+        ComponentCommand::Titleraw { .. } => {
+            ChatComponent::from_rawtext(component, VERSION_1_16)
+                .map_err(|err| CommandParseErr::InvalidComponent(err.to_string()))?
+        }
+        _ => parse_component(component)?,
+    };
+    Ok((command, component))
+}
+
+/// Parses the component of a Java Edition command, either JSON or SNBT (since 1.21.5).
+fn parse_component(component: &str) -> Result<ChatComponent, CommandParseErr> {
+    let json = ChatComponent::from_json(component);
+    #[cfg(feature = "nbt")]
+    let json = json.or_else(|err| ChatComponent::from_snbt(component).map_err(|_| err));
+    json.map_err(|err| CommandParseErr::InvalidComponent(err.to_string()))
+}
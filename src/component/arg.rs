@@ -0,0 +1,131 @@
+#[cfg(feature = "json")]
+use serde::ser::{SerializeSeq, Serializer};
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+use crate::component::{ChatComponent, ComponentType, TextComponent};
+use crate::style::ComponentStyle;
+
+/// The JSON primitive a text was given as, arguments of translations are written as it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Primitive {
+    String,
+    Integer,
+    Float,
+    Bool,
+}
+
+impl ChatComponent {
+    /// Whether both components are equal and also written the same way, equality
+    /// doesn't look at the primitives arguments were given as.
+    pub(crate) fn same_form(&self, other: &ChatComponent) -> bool {
+        fn primitives(component: &ChatComponent) -> Vec<Option<Primitive>> {
+            let mut primitives = vec![];
+            component.walk(&mut |component| {
+                if let ComponentType::Text(text) = component.get_kind() {
+                    primitives.push(text.primitive);
+                }
+            });
+            primitives
+        }
+        self == other && primitives(self) == primitives(other)
+    }
+}
+
+/// A value that can be an argument of a translation, see [`TranslationComponent::add_arg()`].
+///
+/// Strings, integers, floats and booleans become plain texts, which are written as the
+/// JSON primitive itself in `with` like vanilla does (e.g. `"with":["Steve",3]`).
+///
+/// [`TranslationComponent::add_arg()`]: crate::component::TranslationComponent::add_arg
+pub trait IntoComponentArg {
+    fn into_component_arg(self) -> ChatComponent;
+}
+
+impl IntoComponentArg for ChatComponent {
+    fn into_component_arg(self) -> ChatComponent {
+        self
+    }
+}
+
+fn primitive_arg(text: String, primitive: Primitive) -> ChatComponent {
+    let mut text = TextComponent::from_text(text);
+    text.primitive = Some(primitive);
+    ChatComponent::from_component(ComponentType::Text(text), ComponentStyle::v1_16())
+}
+
+impl IntoComponentArg for &str {
+    fn into_component_arg(self) -> ChatComponent {
+        primitive_arg(self.to_string(), Primitive::String)
+    }
+}
+
+impl IntoComponentArg for String {
+    fn into_component_arg(self) -> ChatComponent {
+        primitive_arg(self, Primitive::String)
+    }
+}
+
+impl IntoComponentArg for bool {
+    fn into_component_arg(self) -> ChatComponent {
+        primitive_arg(self.to_string(), Primitive::Bool)
+    }
+}
+
+macro_rules! impl_into_component_arg {
+    ($primitive:ident: $($ty:ty),*) => {
+        $(
+            impl IntoComponentArg for $ty {
+                fn into_component_arg(self) -> ChatComponent {
+                    primitive_arg(self.to_string(), Primitive::$primitive)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_component_arg!(Integer: i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_into_component_arg!(Float: f32, f64);
+
+/// The primitive `arg` is written as, if it is a plain text that was given as one
+/// and its text (which may have been changed since) still is a valid value.
+#[cfg(feature = "json")]
+fn primitive_value(arg: &ChatComponent) -> Option<Value> {
+    let text = match arg.get_kind() {
+        ComponentType::Text(text) => text,
+        _ => return None,
+    };
+    let primitive = text.primitive?;
+    let json = serde_json::to_value(arg).ok()?;
+    if json.as_object().is_none_or(|map| map.len() != 1) {
+        // styled or with siblings
+        return None;
+    }
+    let text = text.get_text();
+    match primitive {
+        Primitive::String => Some(Value::from(text)),
+        Primitive::Integer => text.parse::<i64>().ok().map(Value::from),
+        Primitive::Float => text
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(Value::from),
+        Primitive::Bool => text.parse::<bool>().ok().map(Value::from),
+    }
+}
+
+/// Writes the arguments of a translation, plain texts given as primitives are written as them.
+#[cfg(feature = "json")]
+pub(crate) fn serialize_args<S>(args: &[ChatComponent], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(args.len()))?;
+    for arg in args {
+        match primitive_value(arg) {
+            Some(value) => seq.serialize_element(&value)?,
+            None => seq.serialize_element(arg)?,
+        }
+    }
+    seq.end()
+}
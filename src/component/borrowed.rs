@@ -0,0 +1,272 @@
+use std::borrow::Cow;
+
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Deserializer};
+
+use crate::component::{ChatComponent, ComponentType, ScoreComponent, TranslationComponent};
+use crate::style::{ChatColor, ChatFormatting, ComponentStyle};
+
+/// The content of a [`BorrowedComponent`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BorrowedKind<'a> {
+    Text(Cow<'a, str>),
+    Translation {
+        key: Cow<'a, str>,
+        args: Vec<BorrowedComponent<'a>>,
+    },
+    Score {
+        name: Cow<'a, str>,
+        objective: Cow<'a, str>,
+        value: Option<Cow<'a, str>>,
+    },
+    Selector(Cow<'a, str>),
+    Keybind(Cow<'a, str>),
+}
+
+/// A component borrowing its strings from the JSON it was read from, for reading
+/// many components without keeping them (e.g. in proxies filtering chat).
+/// Strings are only copied if they contain escapes.
+///
+/// Only the content, color and decorations are read, events, insertions, fonts and
+/// unknown fields are skipped. Use [`ChatComponent`] to keep everything.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BorrowedComponent<'a> {
+    kind: BorrowedKind<'a>,
+    color: Option<Cow<'a, str>>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    underlined: Option<bool>,
+    strikethrough: Option<bool>,
+    obfuscated: Option<bool>,
+    siblings: Vec<BorrowedComponent<'a>>,
+}
+
+/// A string borrowed unless it contains escapes, serde only borrows a `Cow` that
+/// isn't wrapped in another type like `Option`.
+#[derive(Deserialize)]
+struct BorrowedStr<'a>(#[serde(borrow)] Cow<'a, str>);
+
+#[derive(Deserialize)]
+struct BorrowedScore<'a> {
+    #[serde(borrow)]
+    name: BorrowedStr<'a>,
+    #[serde(borrow)]
+    objective: BorrowedStr<'a>,
+    #[serde(borrow, default)]
+    value: Option<BorrowedStr<'a>>,
+}
+
+#[derive(Deserialize)]
+struct BorrowedData<'a> {
+    #[serde(borrow, default)]
+    text: Option<BorrowedStr<'a>>,
+    #[serde(borrow, default)]
+    translate: Option<BorrowedStr<'a>>,
+    #[serde(borrow, default)]
+    with: Vec<BorrowedComponent<'a>>,
+    #[serde(borrow, default)]
+    score: Option<BorrowedScore<'a>>,
+    #[serde(borrow, default)]
+    selector: Option<BorrowedStr<'a>>,
+    #[serde(borrow, default)]
+    keybind: Option<BorrowedStr<'a>>,
+    #[serde(borrow, default)]
+    color: Option<BorrowedStr<'a>>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    underlined: Option<bool>,
+    strikethrough: Option<bool>,
+    obfuscated: Option<bool>,
+    #[serde(borrow, default)]
+    extra: Vec<BorrowedComponent<'a>>,
+    // read so they don't fail the untagged forms, but never kept
+    #[serde(rename = "clickEvent", default)]
+    _click_event: Option<IgnoredAny>,
+    #[serde(rename = "hoverEvent", default)]
+    _hover_event: Option<IgnoredAny>,
+}
+
+/// The forms a component can be written in, see [`ChatComponent`]'s deserialization.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BorrowedForm<'a> {
+    #[serde(borrow)]
+    Text(BorrowedStr<'a>),
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    #[serde(borrow)]
+    Component(Box<BorrowedData<'a>>),
+    #[serde(borrow)]
+    List(Vec<BorrowedComponent<'a>>),
+}
+
+impl<'a> BorrowedComponent<'a> {
+    fn from_text(text: Cow<'a, str>) -> Self {
+        BorrowedComponent {
+            kind: BorrowedKind::Text(text),
+            color: None,
+            bold: None,
+            italic: None,
+            underlined: None,
+            strikethrough: None,
+            obfuscated: None,
+            siblings: vec![],
+        }
+    }
+
+    /// Reads a component from JSON, borrowing its strings from `json`.
+    #[cfg(feature = "json")]
+    pub fn from_slice(json: &'a [u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(json)
+    }
+
+    pub fn get_kind(&self) -> &BorrowedKind<'a> {
+        &self.kind
+    }
+
+    /// The color as written in the JSON, a name like `red` or `#RRGGBB`.
+    pub fn get_color(&self) -> Option<&str> {
+        self.color.as_deref()
+    }
+
+    pub fn get_bold(&self) -> Option<bool> {
+        self.bold
+    }
+
+    pub fn get_italic(&self) -> Option<bool> {
+        self.italic
+    }
+
+    pub fn get_underlined(&self) -> Option<bool> {
+        self.underlined
+    }
+
+    pub fn get_strikethrough(&self) -> Option<bool> {
+        self.strikethrough
+    }
+
+    pub fn get_obfuscated(&self) -> Option<bool> {
+        self.obfuscated
+    }
+
+    pub fn get_siblings(&self) -> &Vec<BorrowedComponent<'a>> {
+        &self.siblings
+    }
+
+    /// Copies this component into a [`ChatComponent`] of the given version.
+    pub fn to_component(&self, version: u32) -> ChatComponent {
+        let kind = match &self.kind {
+            BorrowedKind::Text(text) => {
+                ComponentType::Text(super::TextComponent::from_text(&**text))
+            }
+            BorrowedKind::Translation { key, args } => {
+                let mut translation = TranslationComponent::from_key(&**key);
+                for arg in args {
+                    translation.add_arg(arg.to_component(version));
+                }
+                ComponentType::Translation(translation)
+            }
+            BorrowedKind::Score {
+                name,
+                objective,
+                value,
+            } => ComponentType::Score(
+                ScoreComponent::from_score(&**name, &**objective).value(value.as_deref()),
+            ),
+            BorrowedKind::Selector(selector) => {
+                ComponentType::Selector(super::SelectorComponent::from_selector(&**selector))
+            }
+            BorrowedKind::Keybind(keybind) => {
+                ComponentType::Keybind(super::KeybindComponent::from_keybind(&**keybind))
+            }
+        };
+        let mut style = ComponentStyle::with_version(version);
+        style.set_color(self.color.as_deref().map(|color| {
+            ChatFormatting::from_name(color)
+                .and_then(|formatting| formatting.to_color())
+                .unwrap_or_else(|| ChatColor::custom(color))
+        }));
+        if let Some(bold) = self.bold {
+            style.set_bold(bold);
+        }
+        if let Some(italic) = self.italic {
+            style.set_italic(italic);
+        }
+        if let Some(underlined) = self.underlined {
+            style.set_underlined(underlined);
+        }
+        if let Some(strikethrough) = self.strikethrough {
+            style.set_strikethrough(strikethrough);
+        }
+        if let Some(obfuscated) = self.obfuscated {
+            style.set_obfuscated(obfuscated);
+        }
+        let mut component = ChatComponent::from_component(kind, style);
+        *component.get_siblings_mut() = self
+            .siblings
+            .iter()
+            .map(|sibling| sibling.to_component(version))
+            .collect();
+        component
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for BorrowedComponent<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = match BorrowedForm::deserialize(deserializer)? {
+            BorrowedForm::Text(text) => return Ok(BorrowedComponent::from_text(text.0)),
+            BorrowedForm::Bool(value) => {
+                return Ok(BorrowedComponent::from_text(Cow::Owned(value.to_string())))
+            }
+            BorrowedForm::Integer(value) => {
+                return Ok(BorrowedComponent::from_text(Cow::Owned(value.to_string())))
+            }
+            BorrowedForm::Float(value) => {
+                return Ok(BorrowedComponent::from_text(Cow::Owned(value.to_string())))
+            }
+            BorrowedForm::List(components) => {
+                let mut components = components.into_iter();
+                let mut parent = components
+                    .next()
+                    .ok_or_else(|| serde::de::Error::custom("a component list can't be empty"))?;
+                parent.siblings.extend(components);
+                return Ok(parent);
+            }
+            BorrowedForm::Component(data) => *data,
+        };
+        let kind = if let Some(text) = data.text {
+            BorrowedKind::Text(text.0)
+        } else if let Some(key) = data.translate {
+            BorrowedKind::Translation {
+                key: key.0,
+                args: data.with,
+            }
+        } else if let Some(score) = data.score {
+            BorrowedKind::Score {
+                name: score.name.0,
+                objective: score.objective.0,
+                value: score.value.map(|value| value.0),
+            }
+        } else if let Some(selector) = data.selector {
+            BorrowedKind::Selector(selector.0)
+        } else if let Some(keybind) = data.keybind {
+            BorrowedKind::Keybind(keybind.0)
+        } else {
+            return Err(serde::de::Error::custom("the component has no content"));
+        };
+        Ok(BorrowedComponent {
+            kind,
+            color: data.color.map(|color| color.0),
+            bold: data.bold,
+            italic: data.italic,
+            underlined: data.underlined,
+            strikethrough: data.strikethrough,
+            obfuscated: data.obfuscated,
+            siblings: data.extra,
+        })
+    }
+}
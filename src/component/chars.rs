@@ -0,0 +1,64 @@
+use crate::component::{ChatComponent, ComponentType, TextComponent};
+use crate::style::ComponentStyle;
+
+impl ChatComponent {
+    /// Styles the text of this component and its siblings character by character,
+    /// the primitive behind effects like gradients and highlights.
+    ///
+    /// `style` is called with the index of every character (counted over the whole text,
+    /// in reading order) and the character itself. Characters it returns a style for
+    /// are split into their own component with that style (inheriting the original
+    /// style), consecutive characters without one are kept together.
+    ///
+    /// With the `unicode-segmentation` feature, every grapheme cluster counts as one character.
+    ///
+    /// Only text components are styled, translation arguments and hover texts are left alone.
+    pub fn style_chars<F>(&mut self, mut style: F)
+    where
+        F: FnMut(usize, &str) -> Option<ComponentStyle>,
+    {
+        let mut index = 0;
+        self.style_chars_from(&mut index, &mut style);
+    }
+
+    fn style_chars_from(
+        &mut self,
+        index: &mut usize,
+        style: &mut dyn FnMut(usize, &str) -> Option<ComponentStyle>,
+    ) {
+        let mut runs = vec![];
+        if let ComponentType::Text(text) = &self.kind {
+            let version = self.style.get_version();
+            let mut unstyled = String::new();
+            for c in crate::text::units(text.get_text()) {
+                match style(*index, c) {
+                    Some(char_style) => {
+                        if !unstyled.is_empty() {
+                            runs.push(ChatComponent::from_text(
+                                std::mem::take(&mut unstyled),
+                                ComponentStyle::with_version(version),
+                            ));
+                        }
+                        runs.push(ChatComponent::from_text(c, char_style));
+                    }
+                    None => unstyled.push_str(c),
+                }
+                *index += 1;
+            }
+            // nothing to split if no character got a style
+            if !runs.is_empty() && !unstyled.is_empty() {
+                runs.push(ChatComponent::from_text(
+                    unstyled,
+                    ComponentStyle::with_version(version),
+                ));
+            }
+        }
+        for sibling in &mut self.siblings {
+            sibling.style_chars_from(index, style);
+        }
+        if !runs.is_empty() {
+            self.kind = ComponentType::Text(TextComponent::from_text(""));
+            self.siblings.splice(0..0, runs);
+        }
+    }
+}
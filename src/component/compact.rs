@@ -0,0 +1,40 @@
+use serde_json::Value;
+
+use crate::component::ChatComponent;
+
+/// Shortens serialized component JSON like vanilla does: unstyled texts without
+/// siblings become plain strings and empty lists are left out.
+fn compact(value: Value) -> Value {
+    match value {
+        Value::Object(mut map) => {
+            map.retain(|key, value| {
+                !(matches!(key.as_str(), "extra" | "with")
+                    && value.as_array().is_some_and(Vec::is_empty))
+            });
+            if map.len() == 1 && map.get("text").is_some_and(Value::is_string) {
+                return map.remove("text").unwrap_or_default();
+            }
+            Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| (key, compact(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(compact).collect()),
+        value => value,
+    }
+}
+
+impl ChatComponent {
+    /// Serializes this component as short as possible, the way vanilla writes it:
+    /// text components without any style or siblings become plain strings
+    /// (also as siblings, arguments and hover texts) and empty `extra` and `with` lists
+    /// are left out.
+    ///
+    /// Minecraft reads both forms the same, this one is just smaller,
+    /// e.g. for MOTDs or other size-sensitive packets.
+    pub fn to_compact_json(&self) -> String {
+        let value = serde_json::to_value(self).expect("components always serialize to JSON");
+        compact(value).to_string()
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "json")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "json")]
+use serde_json::{Map, Value};
+#[cfg(feature = "json")]
+use std::sync::RwLock;
+
+#[cfg(feature = "json")]
+use crate::component::serde_support::{CONTENT_FIELDS, KNOWN_FIELDS};
+
+/// A component kind defined outside of this crate, e.g. by a modded server.
+///
+/// Custom components are stored in [`ComponentType::Custom`](crate::ComponentType::Custom)
+/// and take part in siblings, styling and traversal like every other kind.
+/// To deserialize them, register a parser with [`register_custom_component()`].
+pub trait CustomComponent: Debug + Send + Sync {
+    fn clone_box(&self) -> Box<dyn CustomComponent>;
+
+    /// The text shown by renderers that don't know about this kind.
+    fn plain_text(&self) -> String;
+
+    /// Estimates the heap memory held by this component, see
+    /// [`ChatComponent::deep_size_of()`](crate::ChatComponent::deep_size_of).
+    fn deep_size_of(&self) -> usize {
+        0
+    }
+
+    /// The JSON fields identifying this kind, they are merged with the style and siblings.
+    #[cfg(feature = "json")]
+    fn to_json(&self) -> Map<String, Value>;
+}
+
+impl Clone for Box<dyn CustomComponent> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Custom components are equal if they show the same text and serialize the same fields.
+impl PartialEq for Box<dyn CustomComponent> {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "json")]
+        if self.to_json() != other.to_json() {
+            return false;
+        }
+        self.plain_text() == other.plain_text()
+    }
+}
+
+impl Eq for Box<dyn CustomComponent> {}
+
+impl Hash for Box<dyn CustomComponent> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        #[cfg(feature = "json")]
+        self.to_json().hash(state);
+        self.plain_text().hash(state);
+    }
+}
+
+/// Parses the JSON fields of a component into a custom kind, if they belong to it.
+#[cfg(feature = "json")]
+pub type CustomComponentParser = fn(&Map<String, Value>) -> Option<Box<dyn CustomComponent>>;
+
+#[cfg(feature = "json")]
+static PARSERS: RwLock<Vec<CustomComponentParser>> = RwLock::new(Vec::new());
+
+/// Registers a parser for a custom component kind, it is tried (in order of registration)
+/// for every component that isn't one of the built-in kinds.
+#[cfg(feature = "json")]
+pub fn register_custom_component(parser: CustomComponentParser) {
+    PARSERS
+        .write()
+        .unwrap_or_else(|err| err.into_inner())
+        .push(parser);
+}
+
+#[cfg(feature = "json")]
+impl Serialize for Box<dyn CustomComponent> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_json().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'de> Deserialize<'de> for Box<dyn CustomComponent> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let fields = Map::deserialize(deserializer)?;
+        let parsers = PARSERS.read().unwrap_or_else(|err| err.into_inner());
+        if let Some(custom) = parsers.iter().find_map(|parser| parser(&fields)) {
+            return Ok(custom);
+        }
+        // a built-in kind that failed to parse is an error, otherwise the fields
+        // nothing else reads are some kind this crate doesn't know
+        if !CONTENT_FIELDS.iter().any(|key| fields.contains_key(*key)) {
+            let unknown: Map<String, Value> = fields
+                .into_iter()
+                .filter(|(key, _)| !KNOWN_FIELDS.contains(&key.as_str()))
+                .collect();
+            if !unknown.is_empty() {
+                return Ok(Box::new(UnknownComponent::new(unknown)));
+            }
+        }
+        Err(D::Error::custom("no component kind matches these fields"))
+    }
+}
+
+/// A component of a kind neither this crate nor a registered parser knows, like one
+/// of a newer version or a mod. It keeps its JSON fields, so it is written back unchanged.
+///
+/// Unknown components show no text.
+#[cfg(feature = "json")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnknownComponent {
+    fields: Map<String, Value>,
+}
+
+#[cfg(feature = "json")]
+impl UnknownComponent {
+    pub fn new(fields: Map<String, Value>) -> Self {
+        UnknownComponent { fields }
+    }
+
+    /// The fields of this kind, without the style and siblings.
+    pub fn get_fields(&self) -> &Map<String, Value> {
+        &self.fields
+    }
+
+    pub fn get_fields_mut(&mut self) -> &mut Map<String, Value> {
+        &mut self.fields
+    }
+}
+
+#[cfg(feature = "json")]
+impl CustomComponent for UnknownComponent {
+    fn clone_box(&self) -> Box<dyn CustomComponent> {
+        Box::new(self.clone())
+    }
+
+    fn plain_text(&self) -> String {
+        String::new()
+    }
+
+    fn deep_size_of(&self) -> usize {
+        Value::Object(self.fields.clone()).to_string().len()
+    }
+
+    fn to_json(&self) -> Map<String, Value> {
+        self.fields.clone()
+    }
+}
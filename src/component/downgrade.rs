@@ -0,0 +1,72 @@
+use crate::component::{keybind, ChatComponent, ComponentType, NbtSource, TextComponent};
+use crate::style::{ChatColor, VERSION_1_12, VERSION_1_15, VERSION_1_16, VERSION_1_8};
+
+/// The version number of the Minecraft protocol for 1.14
+const VERSION_1_14: u32 = 477;
+/// The version number of the Minecraft protocol for 1.17
+const VERSION_1_17: u32 = 755;
+/// The version number of the Minecraft protocol for 1.19.4
+const VERSION_1_19_4: u32 = 762;
+
+impl ChatComponent {
+    /// Changes the version of this component tree (see [`ChatComponent::change_version`])
+    /// and replaces every component the given version doesn't know about.
+    ///
+    /// - Before **1.19.4** the fallbacks of translations are removed.
+    /// - Before **1.17** the separators of selectors and NBT components are removed.
+    /// - Before **1.16** RGB colors become the nearest named color
+    ///   (see [`ChatColor::to_nearest_legacy()`]), unreadable custom ones are removed.
+    /// - Before **1.14** [`NbtComponent`](crate::NbtComponent)s become empty texts,
+    ///   before **1.15** the ones reading a storage.
+    /// - Before **1.12** [`KeybindComponent`](crate::KeybindComponent)s become the name of
+    ///   their default key (see [`default_key_name`](crate::default_key_name)).
+    /// - Before **1.8** [`ScoreComponent`](crate::ScoreComponent)s become their value
+    ///   (if any) and [`SelectorComponent`](crate::SelectorComponent)s their selector.
+    pub fn downgrade(&mut self, to: u32) {
+        self.walk_mut(&mut |component| {
+            if to < VERSION_1_17 {
+                match &mut component.kind {
+                    ComponentType::Selector(selector) => selector.set_separator(None),
+                    ComponentType::Nbt(nbt) => nbt.set_separator(None),
+                    _ => {}
+                }
+            }
+            if to < VERSION_1_16 {
+                if let Some(color @ (ChatColor::Rgb(..) | ChatColor::Custom(_))) =
+                    component.style.get_color()
+                {
+                    let color = color.to_nearest_legacy();
+                    component.style.set_color(color);
+                }
+            }
+            if let ComponentType::Translation(translation) = &mut component.kind {
+                if to < VERSION_1_19_4 {
+                    translation.set_fallback(None::<String>);
+                }
+            }
+            let text = match &component.kind {
+                ComponentType::Nbt(nbt)
+                    if to < VERSION_1_14
+                        || (to < VERSION_1_15
+                            && matches!(nbt.get_source(), NbtSource::Storage(_))) =>
+                {
+                    String::new()
+                }
+                ComponentType::Keybind(keybind) if to < VERSION_1_12 => {
+                    keybind::default_key_name(keybind.get_keybind())
+                        .unwrap_or(keybind.get_keybind())
+                        .to_string()
+                }
+                ComponentType::Score(score) if to < VERSION_1_8 => {
+                    score.get_value().unwrap_or_default().to_string()
+                }
+                ComponentType::Selector(selector) if to < VERSION_1_8 => {
+                    selector.get_selector().to_string()
+                }
+                _ => return,
+            };
+            component.kind = ComponentType::Text(TextComponent::from_text(text));
+        });
+        self.change_version(to);
+    }
+}
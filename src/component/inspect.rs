@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use crate::component::{ChatComponent, ComponentType};
+use crate::style::ChatColor;
+
+/// The amount of text nodes kept in [`ComponentStats::largest_texts`].
+const LARGEST_TEXTS: usize = 5;
+
+/// Statistics about a component tree, see [`ChatComponent::inspect()`].
+#[derive(Clone, Debug, Default)]
+pub struct ComponentStats<'a> {
+    /// The total amount of components, including translation arguments and hover texts
+    pub components: usize,
+    pub texts: usize,
+    pub translations: usize,
+    pub scores: usize,
+    pub selectors: usize,
+    pub keybinds: usize,
+    pub nbts: usize,
+    pub customs: usize,
+    pub click_events: usize,
+    pub hover_events: usize,
+    /// How many components set each color
+    pub colors: HashMap<ChatColor, usize>,
+    /// The longest texts in the tree by character count, longest first and
+    /// texts of the same length in tree order
+    pub largest_texts: Vec<&'a str>,
+    /// The amount of characters in all texts combined
+    pub total_text_length: usize,
+}
+
+impl ChatComponent {
+    /// Profiles this component tree, useful to quickly spot suspicious or oversized messages.
+    pub fn inspect(&self) -> ComponentStats<'_> {
+        let mut stats = ComponentStats::default();
+        self.walk(&mut |component| {
+            stats.components += 1;
+            match component.get_kind() {
+                ComponentType::Text(text) => {
+                    stats.texts += 1;
+                    stats.total_text_length += text.get_text().chars().count();
+                    stats.largest_texts.push(text.get_text());
+                }
+                ComponentType::Translation(_) => stats.translations += 1,
+                ComponentType::Score(_) => stats.scores += 1,
+                ComponentType::Selector(_) => stats.selectors += 1,
+                ComponentType::Keybind(_) => stats.keybinds += 1,
+                ComponentType::Nbt(_) => stats.nbts += 1,
+                ComponentType::Custom(_) => stats.customs += 1,
+            }
+            if component.get_click_event().is_some() {
+                stats.click_events += 1;
+            }
+            if component.get_hover_event().is_some() {
+                stats.hover_events += 1;
+            }
+            if let Some(color) = component.get_color() {
+                *stats.colors.entry(color.clone()).or_insert(0) += 1;
+            }
+        });
+        stats
+            .largest_texts
+            .sort_by_key(|text| std::cmp::Reverse(text.chars().count()));
+        stats.largest_texts.truncate(LARGEST_TEXTS);
+        stats
+    }
+}
@@ -0,0 +1,320 @@
+use std::fmt::{Display, Formatter};
+
+use serde_json::{Map, Value};
+
+/// The error returned when JSON isn't a valid component, telling where and why.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComponentJsonErr {
+    path: String,
+    value: Option<Value>,
+    expected: String,
+}
+
+impl ComponentJsonErr {
+    pub(crate) fn new(path: &str, value: &Value, expected: &str) -> Self {
+        ComponentJsonErr {
+            path: path.to_string(),
+            value: Some(value.clone()),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// The path of the invalid value, like `extra[2].hoverEvent.contents.id`,
+    /// empty for the whole component.
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
+    /// The invalid value, [`None`] if the input isn't valid JSON at all.
+    pub fn get_value(&self) -> Option<&Value> {
+        self.value.as_ref()
+    }
+
+    /// What was expected instead, or the syntax error of invalid JSON.
+    pub fn get_expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl Display for ComponentJsonErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid component")?;
+        if !self.path.is_empty() {
+            write!(f, " at {}", self.path)?;
+        }
+        match &self.value {
+            Some(value) => write!(f, ": expected {}, found {}", self.expected, value),
+            None => write!(f, ": {}", self.expected),
+        }
+    }
+}
+
+impl std::error::Error for ComponentJsonErr {}
+
+impl From<serde_json::Error> for ComponentJsonErr {
+    fn from(err: serde_json::Error) -> Self {
+        ComponentJsonErr {
+            path: String::new(),
+            value: None,
+            expected: err.to_string(),
+        }
+    }
+}
+
+const STYLE_FIELDS: &[&str] = &[
+    "bold",
+    "italic",
+    "underlined",
+    "strikethrough",
+    "obfuscated",
+    "color",
+    "insertion",
+    "font",
+    "shadow_color",
+    "clickEvent",
+    "hoverEvent",
+    "extra",
+];
+
+pub(crate) fn field(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+type Check = Result<(), ComponentJsonErr>;
+
+fn string(map: &Map<String, Value>, path: &str, key: &str, required: bool) -> Check {
+    match map.get(key) {
+        Some(Value::String(_)) => Ok(()),
+        None if !required => Ok(()),
+        value => Err(ComponentJsonErr::new(
+            &field(path, key),
+            value.unwrap_or(&Value::Null),
+            "a string",
+        )),
+    }
+}
+
+/// Whether `value` is a shadow color, a 32 bit ARGB integer or 4 floats.
+pub(crate) fn is_shadow_color(value: &Value) -> bool {
+    match value {
+        Value::Number(number) => number
+            .as_i64()
+            .is_some_and(|argb| (i32::MIN as i64..=u32::MAX as i64).contains(&argb)),
+        Value::Array(channels) => channels.len() == 4 && channels.iter().all(Value::is_number),
+        _ => false,
+    }
+}
+
+fn shadow_color(map: &Map<String, Value>, path: &str) -> Check {
+    match map.get("shadow_color") {
+        Some(value) if !is_shadow_color(value) => Err(ComponentJsonErr::new(
+            &field(path, "shadow_color"),
+            value,
+            "an ARGB integer or a list of 4 floats",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn components(value: &Value, path: &str) -> Check {
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, value)| component(value, &format!("{}[{}]", path, i))),
+        value => Err(ComponentJsonErr::new(path, value, "a list of components")),
+    }
+}
+
+fn click_event(event: &Value, path: &str) -> Check {
+    let event = match event {
+        Value::Object(event) => event,
+        event => return Err(ComponentJsonErr::new(path, event, "a click event")),
+    };
+    string(event, path, "action", true)?;
+    let value_path = field(path, "value");
+    let value = event.get("value").unwrap_or(&Value::Null);
+    match event["action"].as_str().unwrap_or_default() {
+        "change_page" => match value {
+            Value::Number(page) if page.is_u64() => Ok(()),
+            value => Err(ComponentJsonErr::new(&value_path, value, "a page number")),
+        },
+        "open_url" | "run_command" | "suggest_command" | "copy_to_clipboard" => match value {
+            Value::String(_) => Ok(()),
+            value => Err(ComponentJsonErr::new(&value_path, value, "a string")),
+        },
+        _ => Err(ComponentJsonErr::new(
+            &field(path, "action"),
+            &event["action"],
+            "open_url, run_command, suggest_command, change_page or copy_to_clipboard",
+        )),
+    }
+}
+
+fn hover_event(event: &Value, path: &str) -> Check {
+    let event = match event {
+        Value::Object(event) => event,
+        event => return Err(ComponentJsonErr::new(path, event, "a hover event")),
+    };
+    string(event, path, "action", true)?;
+    let key = if event.contains_key("contents") {
+        "contents"
+    } else {
+        "value"
+    };
+    let contents_path = field(path, key);
+    let contents = event.get(key).unwrap_or(&Value::Null);
+    match event["action"].as_str().unwrap_or_default() {
+        "show_text" => component(contents, &contents_path),
+        "show_item" => match contents {
+            Value::String(_) => Ok(()),
+            contents => Err(ComponentJsonErr::new(
+                &contents_path,
+                contents,
+                "an item as SNBT string",
+            )),
+        },
+        "show_entity" => match contents {
+            Value::String(_) => Ok(()),
+            Value::Object(entity) => {
+                string(entity, &contents_path, "type", true)?;
+                string(entity, &contents_path, "id", true)?;
+                match entity.get("name") {
+                    Some(name) => component(name, &field(&contents_path, "name")),
+                    None => Ok(()),
+                }
+            }
+            contents => Err(ComponentJsonErr::new(&contents_path, contents, "an entity")),
+        },
+        _ => Err(ComponentJsonErr::new(
+            &field(path, "action"),
+            &event["action"],
+            "show_text, show_item or show_entity",
+        )),
+    }
+}
+
+/// Finds the first value in component JSON that the deserializer rejects.
+fn separator(map: &Map<String, Value>, path: &str) -> Check {
+    match map.get("separator") {
+        Some(separator) => component(separator, &field(path, "separator")),
+        None => Ok(()),
+    }
+}
+
+fn component(value: &Value, path: &str) -> Check {
+    let map = match value {
+        Value::String(_) | Value::Number(_) | Value::Bool(_) => return Ok(()),
+        Value::Array(values) if values.is_empty() => {
+            return Err(ComponentJsonErr::new(
+                path,
+                value,
+                "a non-empty list of components",
+            ))
+        }
+        Value::Array(_) => return components(value, path),
+        Value::Object(map) => map,
+        Value::Null => return Err(ComponentJsonErr::new(path, value, "a component")),
+    };
+    if let Some(text) = map.get("text") {
+        if !text.is_string() {
+            return Err(ComponentJsonErr::new(
+                &field(path, "text"),
+                text,
+                "a string",
+            ));
+        }
+    } else if map.contains_key("translate") {
+        string(map, path, "translate", true)?;
+        string(map, path, "fallback", false)?;
+        if let Some(args) = map.get("with") {
+            components(args, &field(path, "with"))?;
+        }
+    } else if let Some(score) = map.get("score") {
+        let score_path = field(path, "score");
+        match score {
+            Value::Object(score) => {
+                string(score, &score_path, "name", true)?;
+                string(score, &score_path, "objective", true)?;
+                string(score, &score_path, "value", false)?;
+            }
+            score => return Err(ComponentJsonErr::new(&score_path, score, "a score")),
+        }
+    } else if map.contains_key("selector") {
+        string(map, path, "selector", true)?;
+        separator(map, path)?;
+    } else if map.contains_key("keybind") {
+        string(map, path, "keybind", true)?;
+    } else if map.contains_key("nbt") {
+        string(map, path, "nbt", true)?;
+        separator(map, path)?;
+        if !matches!(map.get("interpret"), Some(Value::Bool(_)) | None) {
+            return Err(ComponentJsonErr::new(
+                &field(path, "interpret"),
+                &map["interpret"],
+                "a boolean",
+            ));
+        }
+        match ["block", "entity", "storage"]
+            .iter()
+            .find(|key| map.contains_key(**key))
+        {
+            Some(source) => string(map, path, source, true)?,
+            None => {
+                return Err(ComponentJsonErr::new(
+                    path,
+                    value,
+                    "an nbt component with a block, entity or storage",
+                ))
+            }
+        }
+    } else if map.keys().all(|key| STYLE_FIELDS.contains(&key.as_str())) {
+        // other fields might belong to a custom component
+        return Err(ComponentJsonErr::new(
+            path,
+            value,
+            "a component with text, translate, score, selector, keybind or nbt",
+        ));
+    }
+    for key in [
+        "bold",
+        "italic",
+        "underlined",
+        "strikethrough",
+        "obfuscated",
+    ] {
+        match map.get(key) {
+            Some(Value::Bool(_)) | None => {}
+            Some(value) => {
+                return Err(ComponentJsonErr::new(&field(path, key), value, "a boolean"))
+            }
+        }
+    }
+    for key in ["color", "insertion", "font"] {
+        string(map, path, key, false)?;
+    }
+    shadow_color(map, path)?;
+    if let Some(event) = map.get("clickEvent") {
+        click_event(event, &field(path, "clickEvent"))?;
+    }
+    if let Some(event) = map.get("hoverEvent") {
+        hover_event(event, &field(path, "hoverEvent"))?;
+    }
+    if let Some(extra) = map.get("extra") {
+        components(extra, &field(path, "extra"))?;
+    }
+    Ok(())
+}
+
+/// Explains why `value` couldn't be deserialized into a component, falling back to `err`
+/// if the reason can't be found (e.g. for custom components).
+pub(crate) fn explain(value: &Value, err: serde_json::Error) -> ComponentJsonErr {
+    match component(value, "") {
+        Err(explained) => explained,
+        Ok(()) => err.into(),
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A vanilla keybind, written as its identifier like `key.jump`.
+///
+/// Clients show the identifier itself for keybinds they don't know, so using these
+/// instead of strings catches typos, e.g.
+/// `ChatComponent::from_keybind(Keybind::Jump, style)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Keybind {
+    Attack,
+    Use,
+    PickItem,
+    Forward,
+    Left,
+    Back,
+    Right,
+    Jump,
+    Sneak,
+    Sprint,
+    Drop,
+    Inventory,
+    SwapOffhand,
+    Chat,
+    Command,
+    PlayerList,
+    Screenshot,
+    TogglePerspective,
+    Fullscreen,
+    Advancements,
+    SocialInteractions,
+    SaveToolbarActivator,
+    LoadToolbarActivator,
+    Hotbar1,
+    Hotbar2,
+    Hotbar3,
+    Hotbar4,
+    Hotbar5,
+    Hotbar6,
+    Hotbar7,
+    Hotbar8,
+    Hotbar9,
+}
+
+/// The error returned when a string isn't the identifier of a vanilla keybind.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownKeybindErr(pub String);
+
+impl Display for UnknownKeybindErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is not a vanilla keybind!", self.0)
+    }
+}
+
+impl std::error::Error for UnknownKeybindErr {}
+
+/// The vanilla keybinds with their identifiers and the default keys
+/// they are bound to, as displayed by an english client.
+const KEYBINDS: &[(Keybind, &str, &str)] = &[
+    (Keybind::Attack, "key.attack", "Left Button"),
+    (Keybind::Use, "key.use", "Right Button"),
+    (Keybind::PickItem, "key.pickItem", "Middle Button"),
+    (Keybind::Forward, "key.forward", "W"),
+    (Keybind::Left, "key.left", "A"),
+    (Keybind::Back, "key.back", "S"),
+    (Keybind::Right, "key.right", "D"),
+    (Keybind::Jump, "key.jump", "Space"),
+    (Keybind::Sneak, "key.sneak", "Left Shift"),
+    (Keybind::Sprint, "key.sprint", "Left Control"),
+    (Keybind::Drop, "key.drop", "Q"),
+    (Keybind::Inventory, "key.inventory", "E"),
+    (Keybind::SwapOffhand, "key.swapOffhand", "F"),
+    (Keybind::Chat, "key.chat", "T"),
+    (Keybind::Command, "key.command", "/"),
+    (Keybind::PlayerList, "key.playerlist", "Tab"),
+    (Keybind::Screenshot, "key.screenshot", "F2"),
+    (Keybind::TogglePerspective, "key.togglePerspective", "F5"),
+    (Keybind::Fullscreen, "key.fullscreen", "F11"),
+    (Keybind::Advancements, "key.advancements", "L"),
+    (Keybind::SocialInteractions, "key.socialInteractions", "P"),
+    (
+        Keybind::SaveToolbarActivator,
+        "key.saveToolbarActivator",
+        "C",
+    ),
+    (
+        Keybind::LoadToolbarActivator,
+        "key.loadToolbarActivator",
+        "X",
+    ),
+    (Keybind::Hotbar1, "key.hotbar.1", "1"),
+    (Keybind::Hotbar2, "key.hotbar.2", "2"),
+    (Keybind::Hotbar3, "key.hotbar.3", "3"),
+    (Keybind::Hotbar4, "key.hotbar.4", "4"),
+    (Keybind::Hotbar5, "key.hotbar.5", "5"),
+    (Keybind::Hotbar6, "key.hotbar.6", "6"),
+    (Keybind::Hotbar7, "key.hotbar.7", "7"),
+    (Keybind::Hotbar8, "key.hotbar.8", "8"),
+    (Keybind::Hotbar9, "key.hotbar.9", "9"),
+];
+
+impl Keybind {
+    /// The identifier of this keybind, like `key.jump`.
+    pub fn id(self) -> &'static str {
+        KEYBINDS
+            .iter()
+            .find(|(keybind, _, _)| *keybind == self)
+            .map_or("", |(_, id, _)| *id)
+    }
+
+    /// The name of the key this keybind is bound to by default, like `Space`.
+    pub fn default_key_name(self) -> &'static str {
+        KEYBINDS
+            .iter()
+            .find(|(keybind, _, _)| *keybind == self)
+            .map_or("", |(_, _, key)| *key)
+    }
+}
+
+impl Display for Keybind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+impl FromStr for Keybind {
+    type Err = UnknownKeybindErr;
+
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        KEYBINDS
+            .iter()
+            .find(|(_, known, _)| *known == id)
+            .map(|(keybind, _, _)| *keybind)
+            .ok_or_else(|| UnknownKeybindErr(id.to_string()))
+    }
+}
+
+impl From<Keybind> for String {
+    fn from(keybind: Keybind) -> Self {
+        keybind.id().to_string()
+    }
+}
+
+/// Returns the name of the key a vanilla keybind (e.g. `key.jump`) is bound to by default.
+pub fn default_key_name(keybind: &str) -> Option<&'static str> {
+    keybind.parse().ok().map(Keybind::default_key_name)
+}
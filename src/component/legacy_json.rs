@@ -0,0 +1,108 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::component::json_error::{explain, field, ComponentJsonErr};
+use crate::component::ChatComponent;
+use crate::style::VERSION_1_7;
+
+const DECORATIONS: &[&str] = &[
+    "bold",
+    "italic",
+    "underlined",
+    "strikethrough",
+    "obfuscated",
+];
+
+type Check = Result<(), ComponentJsonErr>;
+
+/// Checks that `value` only uses what 1.7 clients understand.
+fn component(value: &Value, path: &str) -> Check {
+    let map = match value {
+        Value::Object(map) => map,
+        Value::Array(values) => {
+            return values
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, value)| component(value, &format!("{}[{}]", path, i)))
+        }
+        // primitives are read as their text
+        _ => return Ok(()),
+    };
+    if !map.contains_key("text") && !map.contains_key("translate") {
+        return Err(ComponentJsonErr::new(
+            path,
+            value,
+            "a component with text or translate",
+        ));
+    }
+    for key in DECORATIONS {
+        match map.get(*key) {
+            Some(Value::Bool(_)) | None => {}
+            Some(value) => {
+                return Err(ComponentJsonErr::new(
+                    &field(path, key),
+                    value,
+                    "true or false",
+                ))
+            }
+        }
+    }
+    if let Some(Value::Object(event)) = map.get("hoverEvent") {
+        let value = event.get("value").unwrap_or(&Value::Null);
+        if !value.is_string() {
+            return Err(ComponentJsonErr::new(
+                &field(&field(path, "hoverEvent"), "value"),
+                value,
+                "a string",
+            ));
+        }
+    }
+    for key in ["with", "extra"] {
+        if let Some(Value::Array(values)) = map.get(key) {
+            for (i, value) in values.iter().enumerate() {
+                component(value, &format!("{}[{}]", field(path, key), i))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Replaces the `§` formatted hover texts by the components they stand for.
+fn read_hover_texts(value: &mut Value) {
+    let map: &mut Map<String, Value> = match value {
+        Value::Object(map) => map,
+        Value::Array(values) => return values.iter_mut().for_each(read_hover_texts),
+        _ => return,
+    };
+    if let Some(Value::Object(event)) = map.get_mut("hoverEvent") {
+        if event.get("action").and_then(Value::as_str) == Some("show_text") {
+            if let Some(Value::String(text)) = event.get("value") {
+                let text = ChatComponent::from_legacy_text(text, VERSION_1_7);
+                event.insert("value".to_string(), Value::from(text));
+            }
+        }
+    }
+    for key in ["with", "extra"] {
+        if let Some(values) = map.get_mut(key) {
+            read_hover_texts(values);
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Parses component JSON the way 1.7 clients read it: only text and translation
+    /// components exist, decorations must be `true` or `false` and hover values are
+    /// strings, `§` formatted for hover texts. The components get the 1.7 version.
+    ///
+    /// Components are written in this shape for versions older than 1.8, see
+    /// [`ChatComponent::downgrade()`].
+    pub fn from_json_1_7(json: &str) -> Result<ChatComponent, ComponentJsonErr> {
+        let mut value: Value = serde_json::from_str(json)?;
+        component(&value, "")?;
+        read_hover_texts(&mut value);
+        let mut component =
+            ChatComponent::deserialize(&value).map_err(|err| explain(&value, err))?;
+        component.change_version(VERSION_1_7);
+        Ok(component)
+    }
+}
@@ -0,0 +1,128 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::component::json_error::{explain, is_shadow_color, ComponentJsonErr};
+use crate::component::ChatComponent;
+
+const DECORATIONS: &[&str] = &[
+    "bold",
+    "italic",
+    "underlined",
+    "strikethrough",
+    "obfuscated",
+];
+const STYLE: &[&str] = &[
+    "color",
+    "insertion",
+    "font",
+    "shadow_color",
+    "clickEvent",
+    "hoverEvent",
+    "extra",
+];
+
+/// Reads a value that should be a boolean like the client does, e.g. `"true"` or `1`.
+fn coerce_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(value) => Some(*value),
+        Value::Number(number) => number.as_f64().map(|number| number != 0.0),
+        Value::String(string) => match string.to_ascii_lowercase().as_str() {
+            "true" | "1" => Some(true),
+            "false" | "0" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Makes a list out of a component list, a single component or nothing.
+fn coerce_components(value: Value) -> Vec<Value> {
+    let values = match value {
+        Value::Array(values) => values,
+        Value::Null => vec![],
+        value => vec![value],
+    };
+    values
+        .into_iter()
+        .filter(|value| !value.is_null())
+        .map(coerce_component)
+        .collect()
+}
+
+/// Rewrites component JSON into a form the strict deserializer accepts.
+fn coerce_component(value: Value) -> Value {
+    let mut map = match value {
+        Value::Object(map) => map,
+        Value::Null => Map::new(),
+        Value::Array(values) => {
+            let values = coerce_components(Value::Array(values));
+            if values.is_empty() {
+                return coerce_component(Value::Null);
+            }
+            return Value::Array(values);
+        }
+        value => return value,
+    };
+    map.retain(|_, value| !value.is_null());
+    for key in DECORATIONS {
+        if let Some(value) = map.remove(*key) {
+            if let Some(value) = coerce_bool(&value) {
+                map.insert(key.to_string(), Value::Bool(value));
+            }
+        }
+    }
+    for key in ["color", "insertion", "font"] {
+        if map.get(key).is_some_and(|value| !value.is_string()) {
+            map.remove(key);
+        }
+    }
+    if map
+        .get("shadow_color")
+        .is_some_and(|value| !is_shadow_color(value))
+    {
+        map.remove("shadow_color");
+    }
+    if let Some(text) = map.get_mut("text") {
+        match text {
+            Value::String(_) => {}
+            Value::Number(_) | Value::Bool(_) => *text = Value::String(text.to_string()),
+            _ => *text = Value::String(String::new()),
+        }
+    }
+    for key in ["extra", "with"] {
+        if let Some(values) = map.remove(key) {
+            map.insert(key.to_string(), Value::Array(coerce_components(values)));
+        }
+    }
+    if let Some(Value::Object(hover)) = map.get_mut("hoverEvent") {
+        if hover.get("action").and_then(Value::as_str) == Some("show_text") {
+            for field in ["contents", "value"] {
+                if let Some(text) = hover.remove(field) {
+                    hover.insert(field.to_string(), coerce_component(text));
+                }
+            }
+        }
+    }
+    // an object with nothing but style (like `{}`) is shown as empty text,
+    // others might be custom components
+    let style_only = map
+        .keys()
+        .all(|key| DECORATIONS.contains(&key.as_str()) || STYLE.contains(&key.as_str()));
+    if style_only {
+        map.insert("text".to_string(), Value::String(String::new()));
+    }
+    Value::Object(map)
+}
+
+impl ChatComponent {
+    /// Parses component JSON while tolerating the mistakes the client tolerates too:
+    /// decorations written as `"true"` or `1`, `null` fields and list entries, empty
+    /// objects, single components instead of lists and styles of the wrong type.
+    ///
+    /// Fields that can't be read are left out instead, use [`ChatComponent::from_json()`]
+    /// to parse strictly.
+    pub fn from_json_lenient(json: &str) -> Result<ChatComponent, ComponentJsonErr> {
+        let value = coerce_component(serde_json::from_str(json)?);
+        ChatComponent::deserialize(&value).map_err(|err| explain(&value, err))
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::{Display, Formatter};
+
+use crate::component::{ChatComponent, ComponentPath, ComponentType};
+use crate::style::{ChatFormatting, ClickEvent, ComponentStyle};
+
+/// A likely mistake found by [`ChatComponent::lint()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintKind {
+    /// A style field (`color`, `bold`, ...) that is never visible because the component
+    /// has no text of its own and every sibling sets the field itself
+    OverriddenStyle(&'static str),
+    /// A text component without text and without siblings
+    EmptyText,
+    /// An `open_url` click event whose url doesn't start with `http://` or `https://`,
+    /// clients refuse to open these
+    UrlWithoutProtocol(String),
+    /// A translation key containing whitespace, probably text put into `translate` by accident
+    RawTranslationKey(String),
+    /// A `§` formatting code inside JSON text, use the style fields instead
+    LegacyCode,
+}
+
+impl Display for LintKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintKind::OverriddenStyle(field) => {
+                write!(f, "{} is overridden by every sibling", field)
+            }
+            LintKind::EmptyText => write!(f, "Empty text component"),
+            LintKind::UrlWithoutProtocol(url) => write!(f, "{} is missing a protocol", url),
+            LintKind::RawTranslationKey(key) => {
+                write!(f, "{} looks like text, not a translation key", key)
+            }
+            LintKind::LegacyCode => write!(f, "Legacy formatting code inside text"),
+        }
+    }
+}
+
+/// A warning of [`ChatComponent::lint()`] and where it was found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintWarning {
+    path: ComponentPath,
+    kind: LintKind,
+}
+
+impl LintWarning {
+    pub fn get_path(&self) -> &ComponentPath {
+        &self.path
+    }
+
+    pub fn get_kind(&self) -> &LintKind {
+        &self.kind
+    }
+}
+
+impl Display for LintWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.kind)
+    }
+}
+
+/// Checks whether a style field is set.
+type IsSet = fn(&ComponentStyle) -> bool;
+
+/// The style fields checked for [`LintKind::OverriddenStyle`].
+const STYLE_FIELDS: &[(&str, IsSet)] = &[
+    ("color", |style| style.get_color().is_some()),
+    ("bold", |style| style.get_bold().is_some()),
+    ("italic", |style| style.get_italic().is_some()),
+    ("underlined", |style| style.get_underlined().is_some()),
+    ("strikethrough", |style| style.get_strikethrough().is_some()),
+    ("obfuscated", |style| style.get_obfuscated().is_some()),
+    ("font", |style| style.get_font().is_some()),
+    ("shadow_color", |style| style.get_shadow_color().is_some()),
+];
+
+fn lint_component(component: &ChatComponent, warnings: &mut Vec<LintKind>) {
+    match component.get_kind() {
+        ComponentType::Text(text) => {
+            if text.get_text().is_empty() {
+                if component.get_siblings().is_empty() {
+                    warnings.push(LintKind::EmptyText);
+                } else {
+                    for (field, is_set) in STYLE_FIELDS {
+                        if is_set(component.get_style())
+                            && component
+                                .get_siblings()
+                                .iter()
+                                .all(|sibling| is_set(sibling.get_style()))
+                        {
+                            warnings.push(LintKind::OverriddenStyle(field));
+                        }
+                    }
+                }
+            }
+            if text.get_text().contains(ChatFormatting::SECTION_SIGN) {
+                warnings.push(LintKind::LegacyCode);
+            }
+        }
+        ComponentType::Translation(translation)
+            if translation.get_key().contains(char::is_whitespace) =>
+        {
+            warnings.push(LintKind::RawTranslationKey(
+                translation.get_key().to_string(),
+            ));
+        }
+        _ => {}
+    }
+    if let Some(ClickEvent::OpenUrl(url)) = component.get_click_event() {
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            warnings.push(LintKind::UrlWithoutProtocol(url.clone()));
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Checks this component and all of its children for common mistakes,
+    /// e.g. to validate components written by users in configs.
+    ///
+    /// None of the warnings make the component invalid, they are just unlikely to be intended.
+    pub fn lint(&self) -> Vec<LintWarning> {
+        let mut warnings = vec![];
+        for (path, component) in self.find_all(|_| true) {
+            let mut kinds = vec![];
+            lint_component(component, &mut kinds);
+            warnings.extend(kinds.into_iter().map(|kind| LintWarning {
+                path: path.clone(),
+                kind,
+            }));
+        }
+        warnings
+    }
+}
@@ -1,11 +1,68 @@
-use crate::style::ComponentStyle;
+use crate::intern::Interner;
+use crate::style::{ComponentStyle, HoverEvent};
+use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+mod arg;
+#[cfg(feature = "serde")]
+mod borrowed;
+mod chars;
+#[cfg(feature = "json")]
+mod compact;
+mod custom;
+mod downgrade;
+mod inspect;
+#[cfg(feature = "json")]
+mod json_error;
+mod keybind;
+#[cfg(feature = "json")]
+mod legacy_json;
+#[cfg(feature = "json")]
+mod lenient;
+mod lint;
+mod narration;
+#[cfg(feature = "preserve_order")]
+mod order;
+mod path;
+mod replace;
+mod selector;
+#[cfg(feature = "serde")]
+mod serde_support;
+mod size;
+mod spans;
+mod translate;
+
+pub use arg::IntoComponentArg;
+#[cfg(feature = "serde")]
+pub use borrowed::{BorrowedComponent, BorrowedKind};
+pub use custom::CustomComponent;
+#[cfg(feature = "json")]
+pub use custom::{register_custom_component, CustomComponentParser, UnknownComponent};
+pub use inspect::ComponentStats;
+#[cfg(feature = "json")]
+pub use json_error::ComponentJsonErr;
+pub use keybind::{default_key_name, Keybind, UnknownKeybindErr};
+pub use lint::{LintKind, LintWarning};
+#[cfg(feature = "preserve_order")]
+pub use order::FieldOrder;
+pub use path::{ComponentPath, PathSegment};
+pub use selector::{Selector, SelectorTarget};
+#[cfg(feature = "preserve_unknown")]
+pub(crate) use serde_support::deserialize_unknown_fields;
+#[cfg(feature = "json")]
+pub use serde_support::ComponentValueErr;
+pub(crate) use size::shared_str_size;
+pub(crate) use translate::{format_translation, translation_pieces, TranslationPiece};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "preserve_unknown")]
+use serde_json::{Map, Value};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "serde_support::ComponentData"))]
 pub struct ChatComponent {
     #[cfg_attr(feature = "serde", serde(flatten))]
     kind: ComponentType,
@@ -16,6 +73,10 @@ pub struct ChatComponent {
         serde(rename = "extra", skip_serializing_if = "Vec::is_empty", default)
     )]
     siblings: Vec<ChatComponent>,
+    /// Fields this crate doesn't know, kept so they are serialized again
+    #[cfg(feature = "preserve_unknown")]
+    #[serde(flatten)]
+    unknown: Map<String, Value>,
 }
 
 impl ChatComponent {
@@ -24,23 +85,20 @@ impl ChatComponent {
             kind,
             style,
             siblings: vec![],
+            #[cfg(feature = "preserve_unknown")]
+            unknown: Map::new(),
         }
     }
 
     pub fn from_text<T: Into<String>>(text: T, style: ComponentStyle) -> Self {
-        ChatComponent {
-            kind: ComponentType::Text(TextComponent::from_text(text)),
-            style,
-            siblings: vec![],
-        }
+        ChatComponent::from_component(ComponentType::Text(TextComponent::from_text(text)), style)
     }
 
     pub fn from_key<T: Into<String>>(key: T, style: ComponentStyle) -> Self {
-        ChatComponent {
-            kind: ComponentType::Translation(TranslationComponent::from_key(key)),
+        ChatComponent::from_component(
+            ComponentType::Translation(TranslationComponent::from_key(key)),
             style,
-            siblings: vec![],
-        }
+        )
     }
 
     pub fn from_score<T: Into<String>, U: Into<String>>(
@@ -48,27 +106,31 @@ impl ChatComponent {
         objective: U,
         style: ComponentStyle,
     ) -> Self {
-        ChatComponent {
-            kind: ComponentType::Score(ScoreComponent::from_score(name, objective)),
+        ChatComponent::from_component(
+            ComponentType::Score(ScoreComponent::from_score(name, objective)),
             style,
-            siblings: vec![],
-        }
+        )
     }
 
     pub fn from_selector<T: Into<String>>(selector: T, style: ComponentStyle) -> Self {
-        ChatComponent {
-            kind: ComponentType::Selector(SelectorComponent::from_selector(selector)),
+        ChatComponent::from_component(
+            ComponentType::Selector(SelectorComponent::from_selector(selector)),
             style,
-            siblings: vec![],
-        }
+        )
     }
 
     pub fn from_keybind<T: Into<String>>(keybind: T, style: ComponentStyle) -> Self {
-        ChatComponent {
-            kind: ComponentType::Keybind(KeybindComponent::from_keybind(keybind)),
+        ChatComponent::from_component(
+            ComponentType::Keybind(KeybindComponent::from_keybind(keybind)),
             style,
-            siblings: vec![],
-        }
+        )
+    }
+
+    pub fn from_nbt<T: Into<String>>(nbt: T, source: NbtSource, style: ComponentStyle) -> Self {
+        ChatComponent::from_component(
+            ComponentType::Nbt(NbtComponent::from_nbt(nbt, source)),
+            style,
+        )
     }
 
     pub fn get_kind(&self) -> &ComponentType {
@@ -94,6 +156,162 @@ impl ChatComponent {
     pub fn get_siblings_mut(&mut self) -> &mut Vec<ChatComponent> {
         &mut self.siblings
     }
+
+    /// Returns the fields of the deserialized JSON this crate doesn't understand,
+    /// e.g. ones added by newer versions or mods. They are written back when serializing.
+    ///
+    /// Only kept with the `preserve_unknown` feature, they are dropped otherwise.
+    #[cfg(feature = "preserve_unknown")]
+    pub fn get_unknown_fields(&self) -> &Map<String, Value> {
+        &self.unknown
+    }
+
+    #[cfg(feature = "preserve_unknown")]
+    pub fn get_unknown_fields_mut(&mut self) -> &mut Map<String, Value> {
+        &mut self.unknown
+    }
+
+    /// Removes the unknown fields of this component, its style and all of its children,
+    /// for when fields this crate doesn't understand shouldn't be passed on.
+    #[cfg(feature = "preserve_unknown")]
+    pub fn clear_unknown_fields(&mut self) {
+        self.walk_mut(&mut |component| {
+            component.unknown.clear();
+            component.style.get_unknown_fields_mut().clear();
+        });
+    }
+
+    /// Changes the version of this component and all of its children
+    /// (siblings, translation arguments, separators and hover texts).
+    pub fn change_version(&mut self, to: u32) {
+        self.style.change_version(to);
+        if let ComponentType::Translation(translation) = &mut self.kind {
+            for arg in translation.get_args_mut() {
+                arg.change_version(to);
+            }
+        }
+        if let Some(separator) = self.kind.separator_mut() {
+            separator.change_version(to);
+        }
+        for sibling in &mut self.siblings {
+            sibling.change_version(to);
+        }
+    }
+
+    /// Replaces the name of every score of the reading player (see [`ScoreComponent::READER`])
+    /// with `player`, so a message can be resolved for one specific recipient.
+    pub fn resolve_reader<T: AsRef<str>>(&mut self, player: T) {
+        let player = player.as_ref();
+        self.walk_mut(&mut |component| {
+            if let ComponentType::Score(score) = &mut component.kind {
+                if score.is_reader() {
+                    score.set_name(player);
+                }
+            }
+        });
+    }
+
+    /// Returns this component and its siblings (recursively) in reading order,
+    /// each paired with the style it inherits from its parents.
+    pub fn resolved_styles(&self) -> Vec<(&ChatComponent, ComponentStyle)> {
+        let mut resolved = vec![];
+        self.resolve_styles(None, &mut resolved);
+        resolved
+    }
+
+    fn resolve_styles<'a>(
+        &'a self,
+        parent: Option<&ComponentStyle>,
+        resolved: &mut Vec<(&'a ChatComponent, ComponentStyle)>,
+    ) {
+        let mut style = self.style.clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+        resolved.push((self, style.clone()));
+        for sibling in &self.siblings {
+            sibling.resolve_styles(Some(&style), resolved);
+        }
+    }
+
+    /// Returns the text of this component and its siblings without any formatting,
+    /// e.g. for logs or chat filters.
+    ///
+    /// Translations use their key as pattern for their arguments, keybinds show
+    /// their default key (see [`default_key_name()`]) and scores their value if resolved.
+    pub fn to_plain_text(&self) -> String {
+        let mut out = String::new();
+        self.append_plain_text(&mut out);
+        out
+    }
+
+    /// Appends the text of this component and its siblings to `out`, see [`ChatComponent::to_plain_text()`].
+    pub(crate) fn append_plain_text(&self, out: &mut String) {
+        match &self.kind {
+            ComponentType::Text(text) => out.push_str(text.get_text()),
+            ComponentType::Translation(translation) => {
+                let args: Vec<String> = translation
+                    .get_args()
+                    .iter()
+                    .map(ChatComponent::to_plain_text)
+                    .collect();
+                out.push_str(&format_translation(translation.get_key(), &args));
+            }
+            ComponentType::Score(score) => {
+                if let Some(value) = score.get_value() {
+                    out.push_str(value);
+                }
+            }
+            ComponentType::Selector(selector) => out.push_str(selector.get_selector()),
+            ComponentType::Keybind(keybind) => out
+                .push_str(default_key_name(keybind.get_keybind()).unwrap_or(keybind.get_keybind())),
+            // resolved by the server, there is nothing to show without it
+            ComponentType::Nbt(_) => {}
+            ComponentType::Custom(custom) => out.push_str(&custom.plain_text()),
+        }
+        for sibling in &self.siblings {
+            sibling.append_plain_text(out);
+        }
+    }
+
+    /// Visits this component and every nested component (translation arguments,
+    /// separators, hover texts and siblings), parents before their children.
+    pub(crate) fn walk<'a>(&'a self, visitor: &mut dyn FnMut(&'a ChatComponent)) {
+        visitor(self);
+        if let ComponentType::Translation(translation) = &self.kind {
+            for arg in translation.get_args() {
+                arg.walk(visitor);
+            }
+        }
+        if let Some(separator) = self.kind.separator() {
+            separator.walk(visitor);
+        }
+        if let Some(HoverEvent::ShowText(text)) = self.style.get_hover_event() {
+            text.walk(visitor);
+        }
+        for sibling in &self.siblings {
+            sibling.walk(visitor);
+        }
+    }
+
+    /// Mutable counterpart of [`ChatComponent::walk`].
+    pub(crate) fn walk_mut(&mut self, visitor: &mut dyn FnMut(&mut ChatComponent)) {
+        visitor(self);
+        if let ComponentType::Translation(translation) = &mut self.kind {
+            for arg in translation.get_args_mut() {
+                arg.walk_mut(visitor);
+            }
+        }
+        if let Some(separator) = self.kind.separator_mut() {
+            separator.walk_mut(visitor);
+        }
+        if let Some(HoverEvent::ShowText(text)) = self.style.get_hover_event_mut() {
+            text.walk_mut(visitor);
+        }
+        for sibling in &mut self.siblings {
+            sibling.walk_mut(visitor);
+        }
+    }
 }
 
 impl Deref for ChatComponent {
@@ -111,9 +329,8 @@ impl DerefMut for ChatComponent {
 }
 
 /// The different kinds of components Minecraft chat messages
-/// can be made up of. One component (`storage`-component, since 1.15) is missing,
-/// further research and contributions on this would be appreciated!
-#[derive(Clone, Debug)]
+/// can be made up of.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(untagged))]
 pub enum ComponentType {
@@ -137,26 +354,77 @@ pub enum ComponentType {
     /// This crate does not check any version,
     /// it is up to the user to deal with this safely!
     Keybind(KeybindComponent),
-    // TODO: research the `storage` component (since 1.15)
+    /// # Warning
+    /// Since **1.14**, reading from a storage since **1.15**!
+    ///
+    /// This crate does not check any version,
+    /// it is up to the user to deal with this safely!
+    Nbt(NbtComponent),
+    /// A kind defined outside of this crate, see [`CustomComponent`].
+    /// Kinds no registered parser reads become an `UnknownComponent`.
+    ///
+    /// (De)serializing requires the `json` feature.
+    #[cfg_attr(all(feature = "serde", not(feature = "json")), serde(skip))]
+    Custom(Box<dyn CustomComponent>),
+}
+
+impl ComponentType {
+    /// The separator of a selector or NBT component.
+    pub(crate) fn separator(&self) -> Option<&ChatComponent> {
+        match self {
+            ComponentType::Selector(selector) => selector.get_separator(),
+            ComponentType::Nbt(nbt) => nbt.get_separator(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn separator_mut(&mut self) -> Option<&mut ChatComponent> {
+        match self {
+            ComponentType::Selector(selector) => selector.get_separator_mut(),
+            ComponentType::Nbt(nbt) => nbt.get_separator_mut(),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TextComponent {
-    text: String,
+    text: Box<str>,
+    /// Set for arguments given as a primitive, it is only how they are written
+    /// and doesn't make texts different
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) primitive: Option<arg::Primitive>,
+}
+
+impl PartialEq for TextComponent {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for TextComponent {}
+
+impl Hash for TextComponent {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state);
+    }
 }
 
 impl TextComponent {
     pub fn from_text<T: Into<String>>(text: T) -> Self {
-        TextComponent { text: text.into() }
+        TextComponent {
+            text: text.into().into_boxed_str(),
+            primitive: None,
+        }
     }
 
-    pub fn get_text(&self) -> &String {
+    pub fn get_text(&self) -> &str {
         &self.text
     }
 
     pub fn set_text<T: Into<String>>(&mut self, text: T) {
-        self.text = text.into()
+        self.text = text.into().into_boxed_str()
     }
 
     pub fn text<T: Into<String>>(mut self, text: T) -> Self {
@@ -165,28 +433,32 @@ impl TextComponent {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TranslationComponent {
     #[cfg_attr(feature = "serde", serde(rename = "translate"))]
-    key: String,
+    key: Arc<str>,
+    #[cfg_attr(feature = "json", serde(serialize_with = "arg::serialize_args"))]
     with: Vec<ChatComponent>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    fallback: Option<Box<str>>,
 }
 
 impl TranslationComponent {
     pub fn from_key<T: Into<String>>(key: T) -> Self {
         TranslationComponent {
-            key: key.into(),
+            key: Arc::from(key.into()),
             with: vec![],
+            fallback: None,
         }
     }
 
-    pub fn get_key(&self) -> &String {
+    pub fn get_key(&self) -> &str {
         &self.key
     }
 
     pub fn set_key<T: Into<String>>(&mut self, key: T) {
-        self.key = key.into()
+        self.key = Arc::from(key.into())
     }
 
     pub fn key<T: Into<String>>(mut self, key: T) -> Self {
@@ -194,40 +466,87 @@ impl TranslationComponent {
         self
     }
 
-    pub fn add_arg(&mut self, component: ChatComponent) {
-        self.with.push(component)
+    /// The text shown by clients that don't know the key, instead of the key itself.
+    ///
+    /// # Warning
+    /// Since **1.19.4**!
+    pub fn get_fallback(&self) -> Option<&str> {
+        self.fallback.as_deref()
+    }
+
+    pub fn set_fallback<T: Into<String>>(&mut self, fallback: Option<T>) {
+        self.fallback = fallback.map(|fallback| fallback.into().into_boxed_str());
+    }
+
+    pub fn fallback<T: Into<String>>(mut self, fallback: Option<T>) -> Self {
+        self.set_fallback(fallback);
+        self
+    }
+
+    pub fn get_args(&self) -> &Vec<ChatComponent> {
+        &self.with
+    }
+
+    pub fn get_args_mut(&mut self) -> &mut Vec<ChatComponent> {
+        &mut self.with
+    }
+
+    pub(crate) fn intern(&mut self, interner: &mut Interner) {
+        self.key = interner.intern(&self.key);
+    }
+
+    /// Adds an argument, a component or a string, integer, float or boolean
+    /// (see [`IntoComponentArg`]).
+    pub fn add_arg<T: IntoComponentArg>(&mut self, arg: T) {
+        self.with.push(arg.into_component_arg())
     }
 
-    pub fn argument(mut self, component: ChatComponent) -> Self {
-        self.add_arg(component);
+    pub fn argument<T: IntoComponentArg>(mut self, arg: T) -> Self {
+        self.add_arg(arg);
         self
     }
 }
 
-#[derive(Clone, Debug)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// Written like vanilla, with its fields nested in `score`
+/// (`{"score":{"name":"*","objective":"kills"}}`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "serde_support::ScoreData"))]
 pub struct ScoreComponent {
-    name: String,
-    objective: String,
-    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-    value: Option<String>,
+    name: Box<str>,
+    objective: Arc<str>,
+    value: Option<Box<str>>,
 }
 
 impl ScoreComponent {
+    /// The special score name meaning "the player reading this message".
+    pub const READER: &'static str = "*";
+
     pub fn from_score<T: Into<String>, U: Into<String>>(name: T, objective: U) -> Self {
         ScoreComponent {
-            name: name.into(),
-            objective: objective.into(),
+            name: name.into().into_boxed_str(),
+            objective: Arc::from(objective.into()),
             value: None,
         }
     }
 
-    pub fn get_name(&self) -> &String {
+    /// A score of the player reading the message, displayed
+    /// by every client with its own score.
+    pub fn reader<T: Into<String>>(objective: T) -> Self {
+        ScoreComponent::from_score(ScoreComponent::READER, objective)
+    }
+
+    pub fn get_name(&self) -> &str {
         &self.name
     }
 
+    /// Returns whether this score is the score of the reading player (see [`ScoreComponent::READER`]).
+    pub fn is_reader(&self) -> bool {
+        &*self.name == ScoreComponent::READER
+    }
+
     pub fn set_name<T: Into<String>>(&mut self, name: T) {
-        self.name = name.into()
+        self.name = name.into().into_boxed_str()
     }
 
     pub fn name<T: Into<String>>(mut self, name: T) -> Self {
@@ -235,12 +554,12 @@ impl ScoreComponent {
         self
     }
 
-    pub fn get_objective(&self) -> &String {
+    pub fn get_objective(&self) -> &str {
         &self.objective
     }
 
     pub fn set_objective<T: Into<String>>(&mut self, objective: T) {
-        self.objective = objective.into()
+        self.objective = Arc::from(objective.into())
     }
 
     pub fn objective<T: Into<String>>(mut self, objective: T) -> Self {
@@ -248,12 +567,16 @@ impl ScoreComponent {
         self
     }
 
-    pub fn get_value(&self) -> Option<&String> {
-        self.value.as_ref()
+    pub(crate) fn intern(&mut self, interner: &mut Interner) {
+        self.objective = interner.intern(&self.objective);
+    }
+
+    pub fn get_value(&self) -> Option<&str> {
+        self.value.as_deref()
     }
 
     pub fn set_value<T: Into<String>>(&mut self, value: Option<T>) {
-        self.value = value.map(|value| value.into());
+        self.value = value.map(|value| value.into().into_boxed_str());
     }
 
     pub fn value<T: Into<String>>(mut self, value: Option<T>) -> Self {
@@ -262,52 +585,84 @@ impl ScoreComponent {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SelectorComponent {
-    selector: String,
+    selector: Box<str>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    separator: Option<Box<ChatComponent>>,
 }
 
 impl SelectorComponent {
     pub fn from_selector<T: Into<String>>(selector: T) -> Self {
         SelectorComponent {
-            selector: selector.into(),
+            selector: selector.into().into_boxed_str(),
+            separator: None,
         }
     }
 
-    pub fn get_selector(&self) -> &String {
+    pub fn get_selector(&self) -> &str {
         &self.selector
     }
 
     pub fn set_selector<T: Into<String>>(&mut self, selector: T) {
-        self.selector = selector.into()
+        self.selector = selector.into().into_boxed_str()
     }
 
     pub fn selector<T: Into<String>>(mut self, selector: T) -> Self {
         self.set_selector(selector);
         self
     }
+
+    /// The component displayed between the resolved entries, `, ` in gray if there is none.
+    ///
+    /// # Warning
+    /// Since **1.17**!
+    pub fn get_separator(&self) -> Option<&ChatComponent> {
+        self.separator.as_deref()
+    }
+
+    pub fn get_separator_mut(&mut self) -> Option<&mut ChatComponent> {
+        self.separator.as_deref_mut()
+    }
+
+    pub fn set_separator(&mut self, separator: Option<ChatComponent>) {
+        self.separator = separator.map(Box::new)
+    }
+
+    pub fn separator(mut self, separator: Option<ChatComponent>) -> Self {
+        self.set_separator(separator);
+        self
+    }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KeybindComponent {
-    keybind: String,
+    keybind: Box<str>,
 }
 
 impl KeybindComponent {
     pub fn from_keybind<T: Into<String>>(keybind: T) -> Self {
         KeybindComponent {
-            keybind: keybind.into(),
+            keybind: keybind.into().into_boxed_str(),
         }
     }
 
-    pub fn get_keybind(&self) -> &String {
+    pub fn get_keybind(&self) -> &str {
         &self.keybind
     }
 
+    /// The vanilla keybind this component shows, [`None`] for keybinds of mods.
+    pub fn get_vanilla_keybind(&self) -> Option<Keybind> {
+        self.keybind.parse().ok()
+    }
+
     pub fn set_keybind<T: Into<String>>(&mut self, keybind: T) {
-        self.keybind = keybind.into()
+        self.keybind = keybind.into().into_boxed_str()
     }
 
     pub fn keybind<T: Into<String>>(mut self, keybind: T) -> Self {
@@ -315,3 +670,145 @@ impl KeybindComponent {
         self
     }
 }
+
+/// Where the NBT of an [`NbtComponent`] is read from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum NbtSource {
+    /// The block entity at coordinates like `12 34 56`, which may be relative
+    /// (`~ ~1 ~` or `^ ^ ^1`) to the command's position
+    Block(Box<str>),
+    /// The first entity matched by a selector like `@e[limit=1]` (or a player name or UUID)
+    Entity(Box<str>),
+    /// A command storage by its id, like `minecraft:data`
+    Storage(Box<str>),
+}
+
+impl NbtSource {
+    pub fn block<T: Into<String>>(coordinates: T) -> Self {
+        NbtSource::Block(coordinates.into().into_boxed_str())
+    }
+
+    /// The block entity at the absolute coordinates `x`, `y` and `z`.
+    pub fn block_at(x: i32, y: i32, z: i32) -> Self {
+        NbtSource::block(format!("{} {} {}", x, y, z))
+    }
+
+    pub fn entity<T: Into<String>>(selector: T) -> Self {
+        NbtSource::Entity(selector.into().into_boxed_str())
+    }
+
+    pub fn storage<T: Into<String>>(id: T) -> Self {
+        NbtSource::Storage(id.into().into_boxed_str())
+    }
+
+    /// Returns the coordinates of a block source if they are absolute.
+    pub fn get_block_position(&self) -> Option<(i32, i32, i32)> {
+        let coordinates = match self {
+            NbtSource::Block(coordinates) => coordinates,
+            _ => return None,
+        };
+        let mut parts = coordinates.split_whitespace().map(str::parse::<i32>);
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(x)), Some(Ok(y)), Some(Ok(z)), None) => Some((x, y, z)),
+            _ => None,
+        }
+    }
+}
+
+/// Displays NBT data, the server replaces it with the value at the `nbt` path
+/// (like `Items[0].id`) of its source when sending it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NbtComponent {
+    nbt: Box<str>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    source: NbtSource,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
+    interpret: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    separator: Option<Box<ChatComponent>>,
+}
+
+#[cfg(feature = "serde")]
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl NbtComponent {
+    pub fn from_nbt<T: Into<String>>(nbt: T, source: NbtSource) -> Self {
+        NbtComponent {
+            nbt: nbt.into().into_boxed_str(),
+            source,
+            interpret: false,
+            separator: None,
+        }
+    }
+
+    /// The path of the displayed NBT.
+    pub fn get_nbt(&self) -> &str {
+        &self.nbt
+    }
+
+    pub fn set_nbt<T: Into<String>>(&mut self, nbt: T) {
+        self.nbt = nbt.into().into_boxed_str()
+    }
+
+    pub fn nbt<T: Into<String>>(mut self, nbt: T) -> Self {
+        self.set_nbt(nbt);
+        self
+    }
+
+    pub fn get_source(&self) -> &NbtSource {
+        &self.source
+    }
+
+    pub fn set_source(&mut self, source: NbtSource) {
+        self.source = source
+    }
+
+    pub fn source(mut self, source: NbtSource) -> Self {
+        self.set_source(source);
+        self
+    }
+
+    /// Returns whether the NBT (which has to be a string then) is read as component JSON
+    /// instead of being displayed as it is.
+    pub fn get_interpret(&self) -> bool {
+        self.interpret
+    }
+
+    pub fn set_interpret(&mut self, interpret: bool) {
+        self.interpret = interpret
+    }
+
+    pub fn interpret(mut self, interpret: bool) -> Self {
+        self.set_interpret(interpret);
+        self
+    }
+
+    /// The component displayed between the resolved entries, `, ` if there is none.
+    ///
+    /// # Warning
+    /// Since **1.17**!
+    pub fn get_separator(&self) -> Option<&ChatComponent> {
+        self.separator.as_deref()
+    }
+
+    pub fn get_separator_mut(&mut self) -> Option<&mut ChatComponent> {
+        self.separator.as_deref_mut()
+    }
+
+    pub fn set_separator(&mut self, separator: Option<ChatComponent>) {
+        self.separator = separator.map(Box::new)
+    }
+
+    pub fn separator(mut self, separator: Option<ChatComponent>) -> Self {
+        self.set_separator(separator);
+        self
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use crate::component::translate::format_translation;
+use crate::component::{keybind, ChatComponent, ComponentType};
+use crate::style::{ClickEvent, ComponentStyle};
+
+impl ChatComponent {
+    /// Returns the text a screen reader should read for this component, similar to the client's narrator.
+    ///
+    /// Translations are resolved with `translations` (falling back to their key),
+    /// links are described after the text they're attached to and obfuscated text is skipped.
+    pub fn to_narration_string(&self, translations: &HashMap<String, String>) -> String {
+        let mut out = String::new();
+        self.narrate(None, translations, &mut out);
+        out
+    }
+
+    fn narrate(
+        &self,
+        parent: Option<&ComponentStyle>,
+        translations: &HashMap<String, String>,
+        out: &mut String,
+    ) {
+        let mut style = self.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+
+        if !style.is_obfuscated() {
+            match self.get_kind() {
+                ComponentType::Text(text) => out.push_str(text.get_text()),
+                ComponentType::Translation(translation) => {
+                    let args: Vec<String> = translation
+                        .get_args()
+                        .iter()
+                        .map(|arg| {
+                            let mut narrated = String::new();
+                            arg.narrate(Some(&style), translations, &mut narrated);
+                            narrated
+                        })
+                        .collect();
+                    let pattern = translations
+                        .get(translation.get_key())
+                        .map(String::as_str)
+                        .unwrap_or(translation.get_key());
+                    out.push_str(&format_translation(pattern, &args));
+                }
+                ComponentType::Score(score) => out.push_str(score.get_value().unwrap_or_default()),
+                ComponentType::Selector(selector) => out.push_str(selector.get_selector()),
+                ComponentType::Keybind(keybind) => out.push_str(
+                    keybind::default_key_name(keybind.get_keybind())
+                        .unwrap_or(keybind.get_keybind()),
+                ),
+                ComponentType::Nbt(_) => {}
+                ComponentType::Custom(custom) => out.push_str(&custom.plain_text()),
+            }
+        }
+        for sibling in self.get_siblings() {
+            sibling.narrate(Some(&style), translations, out);
+        }
+
+        // only describe the link where it's set, not on every sibling inheriting it
+        if let Some(ClickEvent::OpenUrl(url)) = self.get_click_event() {
+            out.push_str(" (link: ");
+            out.push_str(url);
+            out.push(')');
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use serde_json::{Map, Value};
+
+use crate::component::ChatComponent;
+
+/// The layout of a parsed JSON message: the order of its fields and which parts
+/// were written in a shortcut form (a plain string instead of a text component).
+///
+/// Serializing with a recorded layout reproduces both, so tools rewriting files
+/// (e.g. datapacks) only change what they actually edited.
+/// Requires the `preserve_order` feature.
+#[derive(Clone, Debug)]
+pub struct FieldOrder {
+    node: Node,
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Object(Vec<(String, Node)>),
+    Array(Vec<Node>),
+    String,
+    Other,
+}
+
+impl Node {
+    fn record(value: &Value) -> Node {
+        match value {
+            Value::Object(fields) => Node::Object(
+                fields
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Node::record(value)))
+                    .collect(),
+            ),
+            Value::Array(items) => Node::Array(items.iter().map(Node::record).collect()),
+            Value::String(_) => Node::String,
+            _ => Node::Other,
+        }
+    }
+
+    fn apply(&self, value: Value) -> Value {
+        match (self, value) {
+            (Node::Object(order), Value::Object(mut fields)) => {
+                let mut ordered = Map::new();
+                for (key, node) in order {
+                    if let Some(value) = fields.shift_remove(key) {
+                        ordered.insert(key.clone(), node.apply(value));
+                    }
+                }
+                // fields added since parsing keep their default order
+                ordered.extend(fields);
+                Value::Object(ordered)
+            }
+            (Node::Array(order), Value::Array(items)) => Value::Array(
+                items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, item)| match order.get(i) {
+                        Some(node) => node.apply(item),
+                        None => item,
+                    })
+                    .collect(),
+            ),
+            (Node::String, Value::Object(mut fields)) => {
+                let shortcut =
+                    fields.len() == 1 && fields.get("text").is_some_and(Value::is_string);
+                if shortcut {
+                    fields.remove("text").unwrap_or_default()
+                } else {
+                    Value::Object(fields)
+                }
+            }
+            (_, value) => value,
+        }
+    }
+}
+
+impl FieldOrder {
+    /// Records the layout of a JSON message.
+    pub fn record(json: &Value) -> Self {
+        FieldOrder {
+            node: Node::record(json),
+        }
+    }
+
+    /// Rearranges serialized JSON into the recorded layout, fields that weren't
+    /// recorded are put after the recorded ones.
+    pub fn apply(&self, json: Value) -> Value {
+        self.node.apply(json)
+    }
+}
+
+impl ChatComponent {
+    /// Parses a JSON message and records its layout, see [`FieldOrder`].
+    pub fn from_json_preserving_order(
+        json: &str,
+    ) -> Result<(ChatComponent, FieldOrder), serde_json::Error> {
+        let value: Value = serde_json::from_str(json)?;
+        let order = FieldOrder::record(&value);
+        Ok((serde_json::from_value(value)?, order))
+    }
+
+    /// Serializes this component in the layout recorded when it was parsed,
+    /// see [`ChatComponent::from_json_preserving_order()`].
+    pub fn to_json_preserving_order(&self, order: &FieldOrder) -> String {
+        let value = serde_json::to_value(self).expect("components always serialize to JSON");
+        order.apply(value).to_string()
+    }
+}
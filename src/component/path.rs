@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter};
+
+use crate::component::{ChatComponent, ComponentType};
+use crate::style::HoverEvent;
+
+/// One step from a component to one of its nested components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PathSegment {
+    /// The sibling (`extra`) with the given index
+    Sibling(usize),
+    /// The translation argument (`with`) with the given index
+    Argument(usize),
+    /// The text of a [`HoverEvent::ShowText`]
+    HoverText,
+    /// The separator of a selector or NBT component
+    Separator,
+}
+
+/// The location of a nested component, relative to the root of its tree.
+///
+/// Displays like a JSON path, e.g. `extra[2].with[0].hoverEvent`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ComponentPath {
+    segments: Vec<PathSegment>,
+}
+
+impl ComponentPath {
+    /// The path pointing to the root component itself.
+    pub fn root() -> Self {
+        ComponentPath { segments: vec![] }
+    }
+
+    pub fn get_segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    pub fn push(&mut self, segment: PathSegment) {
+        self.segments.push(segment);
+    }
+
+    pub fn child(mut self, segment: PathSegment) -> Self {
+        self.push(segment);
+        self
+    }
+
+    /// Returns the path of the parent component, or [`None`] for the root.
+    pub fn parent(&self) -> Option<ComponentPath> {
+        let (_, parent) = self.segments.split_last()?;
+        Some(ComponentPath {
+            segments: parent.to_vec(),
+        })
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.segments.is_empty()
+    }
+}
+
+impl Display for ComponentPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_root() {
+            return write!(f, "$");
+        }
+        for (i, segment) in self.segments.iter().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+            match segment {
+                PathSegment::Sibling(index) => write!(f, "extra[{}]", index)?,
+                PathSegment::Argument(index) => write!(f, "with[{}]", index)?,
+                PathSegment::HoverText => write!(f, "hoverEvent")?,
+                PathSegment::Separator => write!(f, "separator")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ChatComponent {
+    /// Returns every component in this tree matching `predicate` together with its path,
+    /// parents before their children.
+    ///
+    /// The paths can be used with [`ChatComponent::get_path_mut()`] to edit the matches afterwards.
+    pub fn find_all<F>(&self, predicate: F) -> Vec<(ComponentPath, &ChatComponent)>
+    where
+        F: Fn(&ChatComponent) -> bool,
+    {
+        let mut found = vec![];
+        self.find_at(ComponentPath::root(), &predicate, &mut found);
+        found
+    }
+
+    fn find_at<'a, F>(
+        &'a self,
+        path: ComponentPath,
+        predicate: &F,
+        found: &mut Vec<(ComponentPath, &'a ChatComponent)>,
+    ) where
+        F: Fn(&ChatComponent) -> bool,
+    {
+        if predicate(self) {
+            found.push((path.clone(), self));
+        }
+        if let ComponentType::Translation(translation) = self.get_kind() {
+            for (i, arg) in translation.get_args().iter().enumerate() {
+                arg.find_at(
+                    path.clone().child(PathSegment::Argument(i)),
+                    predicate,
+                    found,
+                );
+            }
+        }
+        if let Some(separator) = self.get_kind().separator() {
+            separator.find_at(path.clone().child(PathSegment::Separator), predicate, found);
+        }
+        if let Some(HoverEvent::ShowText(text)) = self.get_hover_event() {
+            text.find_at(path.clone().child(PathSegment::HoverText), predicate, found);
+        }
+        for (i, sibling) in self.get_siblings().iter().enumerate() {
+            sibling.find_at(
+                path.clone().child(PathSegment::Sibling(i)),
+                predicate,
+                found,
+            );
+        }
+    }
+
+    /// Returns the nested component at `path`, if there is one.
+    pub fn get_path(&self, path: &ComponentPath) -> Option<&ChatComponent> {
+        path.get_segments()
+            .iter()
+            .try_fold(self, |component, segment| match segment {
+                PathSegment::Sibling(index) => component.get_siblings().get(*index),
+                PathSegment::Argument(index) => match component.get_kind() {
+                    ComponentType::Translation(translation) => translation.get_args().get(*index),
+                    _ => None,
+                },
+                PathSegment::HoverText => match component.get_hover_event() {
+                    Some(HoverEvent::ShowText(text)) => Some(text.as_ref()),
+                    _ => None,
+                },
+                PathSegment::Separator => component.get_kind().separator(),
+            })
+    }
+
+    /// Returns the nested component at `path` mutably, if there is one.
+    pub fn get_path_mut(&mut self, path: &ComponentPath) -> Option<&mut ChatComponent> {
+        path.get_segments()
+            .iter()
+            .try_fold(self, |component, segment| match segment {
+                PathSegment::Sibling(index) => component.get_siblings_mut().get_mut(*index),
+                PathSegment::Argument(index) => match component.get_kind_mut() {
+                    ComponentType::Translation(translation) => {
+                        translation.get_args_mut().get_mut(*index)
+                    }
+                    _ => None,
+                },
+                PathSegment::HoverText => match component.get_hover_event_mut() {
+                    Some(HoverEvent::ShowText(text)) => Some(text.as_mut()),
+                    _ => None,
+                },
+                PathSegment::Separator => component.get_kind_mut().separator_mut(),
+            })
+    }
+}
@@ -0,0 +1,73 @@
+use crate::component::{ChatComponent, ComponentType, TextComponent};
+use crate::style::{ComponentStyle, HoverEvent};
+
+impl ChatComponent {
+    /// Replaces every occurrence of `pattern` in the text components of this tree with `replacement`.
+    pub fn replace_text(&mut self, pattern: &str, replacement: &str) {
+        if pattern.is_empty() {
+            return;
+        }
+        self.walk_mut(&mut |component| {
+            if let ComponentType::Text(text) = &mut component.kind {
+                if text.get_text().contains(pattern) {
+                    let replaced = text.get_text().replace(pattern, replacement);
+                    text.set_text(replaced);
+                }
+            }
+        });
+    }
+
+    /// Replaces every occurrence of `pattern` in the text components of this tree with
+    /// the component `replacement`, which inherits the style of the text it replaces.
+    pub fn replace_text_with_component(&mut self, pattern: &str, replacement: &ChatComponent) {
+        self.replace_text_with(pattern, |_| replacement.clone());
+    }
+
+    /// Like [`ChatComponent::replace_text_with_component()`], but the replacement
+    /// is produced by `replacement` from the matched text,
+    /// e.g. to turn item names into hoverable item components.
+    ///
+    /// The produced components aren't searched for more matches.
+    pub fn replace_text_with<F>(&mut self, pattern: &str, mut replacement: F)
+    where
+        F: FnMut(&str) -> ChatComponent,
+    {
+        if !pattern.is_empty() {
+            self.replace_in(pattern, &mut replacement);
+        }
+    }
+
+    fn replace_in(&mut self, pattern: &str, replacement: &mut dyn FnMut(&str) -> ChatComponent) {
+        // children first, so inserted replacements aren't searched again
+        if let ComponentType::Translation(translation) = &mut self.kind {
+            for arg in translation.get_args_mut() {
+                arg.replace_in(pattern, replacement);
+            }
+        }
+        if let Some(HoverEvent::ShowText(text)) = self.style.get_hover_event_mut() {
+            text.replace_in(pattern, replacement);
+        }
+        for sibling in &mut self.siblings {
+            sibling.replace_in(pattern, replacement);
+        }
+
+        if let ComponentType::Text(text) = &self.kind {
+            if !text.get_text().contains(pattern) {
+                return;
+            }
+            let mut parts = text.get_text().split(pattern);
+            let first = parts.next().unwrap_or_default().to_string();
+            let mut inserted = vec![];
+            for part in parts {
+                inserted.push(replacement(pattern));
+                if !part.is_empty() {
+                    // siblings inherit the style of this component
+                    let style = ComponentStyle::with_version(self.style.get_version());
+                    inserted.push(ChatComponent::from_text(part, style));
+                }
+            }
+            self.kind = ComponentType::Text(TextComponent::from_text(first));
+            self.siblings.splice(0..0, inserted);
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt::{Display, Formatter};
+
+/// Who a [`Selector`] selects before its arguments filter them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SelectorTarget {
+    /// `@p`
+    NearestPlayer,
+    /// `@a`
+    AllPlayers,
+    /// `@e`
+    AllEntities,
+    /// `@r`
+    RandomPlayer,
+    /// `@s`, the entity running the command
+    Executor,
+}
+
+impl SelectorTarget {
+    /// The character written after the `@`.
+    pub fn variable(self) -> char {
+        match self {
+            SelectorTarget::NearestPlayer => 'p',
+            SelectorTarget::AllPlayers => 'a',
+            SelectorTarget::AllEntities => 'e',
+            SelectorTarget::RandomPlayer => 'r',
+            SelectorTarget::Executor => 's',
+        }
+    }
+}
+
+/// A target selector like `@e[type=minecraft:zombie,distance=..10,limit=1]`, built
+/// from typed arguments so values are always written in a form Minecraft can read.
+///
+/// It turns into the string of [`SelectorComponent`]s and the score holder of
+/// [`ScoreComponent`]s, e.g. `SelectorComponent::from_selector(Selector::all_players())`.
+///
+/// [`SelectorComponent`]: crate::component::SelectorComponent
+/// [`ScoreComponent`]: crate::component::ScoreComponent
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Selector {
+    target: SelectorTarget,
+    arguments: Vec<(String, String)>,
+    scores: Vec<(String, String)>,
+}
+
+/// Writes a range like `1..5`, `..5` or just `3` if both ends are the same.
+fn range<T: Display + PartialEq>(min: Option<T>, max: Option<T>) -> String {
+    match (min, max) {
+        (Some(min), Some(max)) if min == max => min.to_string(),
+        (min, max) => format!(
+            "{}..{}",
+            min.map(|min| min.to_string()).unwrap_or_default(),
+            max.map(|max| max.to_string()).unwrap_or_default()
+        ),
+    }
+}
+
+/// Quotes `value` unless it only has the characters of an unquoted string.
+fn quote(value: &str) -> String {
+    let unquoted = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+'));
+    if unquoted {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+fn negated(negate: bool, value: String) -> String {
+    if negate {
+        format!("!{}", value)
+    } else {
+        value
+    }
+}
+
+impl Selector {
+    pub fn new(target: SelectorTarget) -> Self {
+        Selector {
+            target,
+            arguments: vec![],
+            scores: vec![],
+        }
+    }
+
+    pub fn nearest_player() -> Self {
+        Selector::new(SelectorTarget::NearestPlayer)
+    }
+
+    pub fn all_players() -> Self {
+        Selector::new(SelectorTarget::AllPlayers)
+    }
+
+    pub fn all_entities() -> Self {
+        Selector::new(SelectorTarget::AllEntities)
+    }
+
+    pub fn random_player() -> Self {
+        Selector::new(SelectorTarget::RandomPlayer)
+    }
+
+    pub fn executor() -> Self {
+        Selector::new(SelectorTarget::Executor)
+    }
+
+    pub fn get_target(&self) -> SelectorTarget {
+        self.target
+    }
+
+    /// The arguments except of `scores`, in the order they were added.
+    pub fn get_arguments(&self) -> &Vec<(String, String)> {
+        &self.arguments
+    }
+
+    /// Adds an argument written as is, for the ones without a method here.
+    pub fn argument<T: Into<String>, U: Into<String>>(mut self, key: T, value: U) -> Self {
+        self.arguments.push((key.into(), value.into()));
+        self
+    }
+
+    /// Selects at most `limit` entities.
+    pub fn limit(self, limit: u32) -> Self {
+        self.argument("limit", limit.to_string())
+    }
+
+    /// Selects entities between `min` and `max` blocks away, either of which can be open.
+    pub fn distance(self, min: Option<f64>, max: Option<f64>) -> Self {
+        self.argument("distance", range(min, max))
+    }
+
+    /// Selects players with an experience level between `min` and `max`.
+    pub fn level(self, min: Option<u32>, max: Option<u32>) -> Self {
+        self.argument("level", range(min, max))
+    }
+
+    /// Selects entities of a type like `minecraft:zombie` or a tag like `#minecraft:skeletons`,
+    /// or all other entities if `negate` is set.
+    pub fn entity_type<T: Into<String>>(self, entity_type: T, negate: bool) -> Self {
+        self.argument("type", negated(negate, entity_type.into()))
+    }
+
+    /// Selects entities with the name, or all others if `negate` is set.
+    pub fn name<T: Into<String>>(self, name: T, negate: bool) -> Self {
+        let name = quote(&name.into());
+        self.argument("name", negated(negate, name))
+    }
+
+    /// Selects entities with the tag, or without it if `negate` is set.
+    pub fn tag<T: Into<String>>(self, tag: T, negate: bool) -> Self {
+        let tag = quote(&tag.into());
+        self.argument("tag", negated(negate, tag))
+    }
+
+    /// Selects entities in the team, or in any other team if `negate` is set.
+    pub fn team<T: Into<String>>(self, team: T, negate: bool) -> Self {
+        let team = quote(&team.into());
+        self.argument("team", negated(negate, team))
+    }
+
+    /// Selects entities with a score between `min` and `max` in the objective,
+    /// all scores end up in one `scores` argument.
+    pub fn score<T: Into<String>>(
+        mut self,
+        objective: T,
+        min: Option<i32>,
+        max: Option<i32>,
+    ) -> Self {
+        self.scores
+            .push((quote(&objective.into()), range(min, max)));
+        self
+    }
+}
+
+impl Display for Selector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "@{}", self.target.variable())?;
+        let mut arguments: Vec<String> = self
+            .arguments
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        if !self.scores.is_empty() {
+            let scores: Vec<String> = self
+                .scores
+                .iter()
+                .map(|(objective, range)| format!("{}={}", objective, range))
+                .collect();
+            arguments.push(format!("scores={{{}}}", scores.join(",")));
+        }
+        if !arguments.is_empty() {
+            write!(f, "[{}]", arguments.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Selector> for String {
+    fn from(selector: Selector) -> Self {
+        selector.to_string()
+    }
+}
@@ -0,0 +1,308 @@
+use std::convert::TryFrom;
+#[cfg(feature = "json")]
+use std::fmt::{Display, Formatter};
+
+use serde::ser::SerializeMap;
+#[cfg(feature = "preserve_unknown")]
+use serde::Deserializer;
+use serde::{Deserialize, Serialize, Serializer};
+#[cfg(feature = "preserve_unknown")]
+use serde_json::Map;
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+use crate::component::arg::Primitive;
+#[cfg(feature = "json")]
+use crate::component::json_error::{explain, ComponentJsonErr};
+use crate::component::{ChatComponent, ComponentType, ScoreComponent, TextComponent};
+use crate::style::{default_style_version, ComponentStyle};
+
+/// The fields telling which of the built-in kinds a component is.
+#[cfg(feature = "json")]
+pub(crate) const CONTENT_FIELDS: &[&str] =
+    &["text", "translate", "score", "selector", "keybind", "nbt"];
+
+/// The fields read by the built-in component kinds and [`ComponentStyle`].
+#[cfg(feature = "json")]
+pub(crate) const KNOWN_FIELDS: &[&str] = &[
+    "text",
+    "translate",
+    "with",
+    "fallback",
+    "score",
+    "selector",
+    "keybind",
+    "nbt",
+    "block",
+    "entity",
+    "storage",
+    "interpret",
+    "separator",
+    "extra",
+    "bold",
+    "italic",
+    "underlined",
+    "strikethrough",
+    "obfuscated",
+    "color",
+    "insertion",
+    "font",
+    "shadow_color",
+    "clickEvent",
+    "hoverEvent",
+];
+
+#[derive(Deserialize)]
+pub(crate) struct ScoreData {
+    score: ScoreFields<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ScoreFields<T> {
+    name: T,
+    objective: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<T>,
+}
+
+impl From<ScoreData> for ScoreComponent {
+    fn from(data: ScoreData) -> Self {
+        ScoreComponent::from_score(data.score.name, data.score.objective).value(data.score.value)
+    }
+}
+
+impl Serialize for ScoreComponent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(
+            "score",
+            &ScoreFields {
+                name: self.get_name(),
+                objective: self.get_objective(),
+                value: self.get_value(),
+            },
+        )?;
+        map.end()
+    }
+}
+
+/// Every form a component can be written in, Minecraft reads strings, numbers
+/// and booleans as plain text and lists as their first element with the rest as siblings.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum ComponentData {
+    Text(String),
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    Component(Box<ChatComponentData>),
+    List(Vec<ChatComponent>),
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ChatComponentData {
+    #[serde(flatten)]
+    kind: ComponentType,
+    #[serde(flatten)]
+    style: ComponentStyle,
+    #[serde(rename = "extra", default)]
+    siblings: Vec<ChatComponent>,
+    #[cfg(feature = "preserve_unknown")]
+    #[serde(flatten, deserialize_with = "deserialize_unknown_fields")]
+    unknown: Map<String, Value>,
+}
+
+/// Flattened maps receive every field that isn't consumed by a struct,
+/// the untagged kind leaves its fields behind so they are filtered here.
+#[cfg(feature = "preserve_unknown")]
+pub(crate) fn deserialize_unknown_fields<'de, D>(
+    deserializer: D,
+) -> Result<Map<String, Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut fields = Map::deserialize(deserializer)?;
+    fields.retain(|key, _| !KNOWN_FIELDS.contains(&key.as_str()));
+    Ok(fields)
+}
+
+impl TryFrom<ComponentData> for ChatComponent {
+    type Error = &'static str;
+
+    fn try_from(data: ComponentData) -> Result<Self, Self::Error> {
+        let (text, primitive) = match data {
+            ComponentData::Text(text) => (text, None),
+            ComponentData::Bool(value) => (value.to_string(), Some(Primitive::Bool)),
+            ComponentData::Integer(value) => (value.to_string(), Some(Primitive::Integer)),
+            ComponentData::Float(value) => (value.to_string(), Some(Primitive::Float)),
+            ComponentData::Component(data) => return Ok(ChatComponent::from(*data)),
+            // the first component is the parent of the others, like vanilla reads it
+            ComponentData::List(components) => {
+                let mut components = components.into_iter();
+                let mut parent = components.next().ok_or("a component list can't be empty")?;
+                parent.siblings.extend(components);
+                return Ok(parent);
+            }
+        };
+        // kept so arguments are written back as the same primitive
+        let mut text = TextComponent::from_text(text);
+        text.primitive = primitive;
+        Ok(ChatComponent::from_component(
+            ComponentType::Text(text),
+            ComponentStyle::with_version(default_style_version()),
+        ))
+    }
+}
+
+impl From<ChatComponentData> for ChatComponent {
+    fn from(data: ChatComponentData) -> Self {
+        let mut component = ChatComponent::from_component(data.kind, data.style);
+        component.siblings = data.siblings;
+        #[cfg(feature = "preserve_unknown")]
+        {
+            let mut unknown = data.unknown;
+            // custom kinds are parsed from the same fields
+            if let ComponentType::Custom(custom) = &component.kind {
+                let fields = custom.to_json();
+                unknown.retain(|key, _| !fields.contains_key(key));
+            }
+            component.unknown = unknown;
+            // the flattened style saw the same fields, the component keeps them
+            component.style.get_unknown_fields_mut().clear();
+        }
+        component
+    }
+}
+
+/// Passes the JSON written by serde_json on to a [`std::fmt::Write`],
+/// bytes of a character split between writes are kept until it is complete.
+#[cfg(feature = "json")]
+struct FmtWriter<'a, W: std::fmt::Write> {
+    out: &'a mut W,
+    pending: Vec<u8>,
+}
+
+#[cfg(feature = "json")]
+impl<W: std::fmt::Write> std::io::Write for FmtWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(err) => return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, err)),
+        };
+        let text = std::str::from_utf8(&self.pending[..valid]).unwrap_or_default();
+        self.out.write_str(text).map_err(std::io::Error::other)?;
+        self.pending.drain(..valid);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "json")]
+impl ChatComponent {
+    /// Serializes this component as JSON directly into `writer`, e.g. the buffer
+    /// of a packet, without building a `String` first.
+    pub fn write_json<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+        serde_json::to_writer(writer, self).map_err(std::io::Error::from)
+    }
+
+    /// Serializes this component as JSON into a [`std::fmt::Write`],
+    /// see [`ChatComponent::write_json()`].
+    pub fn write_json_fmt<W: std::fmt::Write>(&self, writer: &mut W) -> std::fmt::Result {
+        let mut writer = FmtWriter {
+            out: writer,
+            pending: vec![],
+        };
+        serde_json::to_writer(&mut writer, self).map_err(|_| std::fmt::Error)
+    }
+
+    /// Parses a component from JSON text like `serde_json::from_str`, the error tells
+    /// which value is invalid and what was expected instead
+    /// (e.g. `Invalid component at extra[2].bold: expected a boolean, found "yes"`).
+    ///
+    /// With the `simd-json` feature the JSON is parsed by simd-json instead, only JSON
+    /// it can't read is parsed again by serde_json to explain the error.
+    pub fn from_json(json: &str) -> Result<ChatComponent, ComponentJsonErr> {
+        // simd-json parses in place, so it needs its own copy of the input
+        #[cfg(feature = "simd-json")]
+        if let Ok(component) = simd_json::serde::from_slice(&mut json.as_bytes().to_vec()) {
+            return Ok(component);
+        }
+        serde_json::from_str(json).map_err(|err| {
+            if err.is_syntax() || err.is_eof() {
+                return err.into();
+            }
+            match serde_json::from_str::<Value>(json) {
+                Ok(value) => explain(&value, err),
+                Err(_) => err.into(),
+            }
+        })
+    }
+}
+
+/// The error returned when a JSON value isn't a component.
+#[cfg(feature = "json")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ComponentValueErr {
+    /// `null`, which is no component
+    Null,
+    /// A list without any component
+    EmptyList,
+    /// An object without any of the fields deciding its kind (`text`, `translate`, ...)
+    MissingContent,
+    /// An invalid field, e.g. of the wrong type
+    Invalid(String),
+}
+
+#[cfg(feature = "json")]
+impl Display for ComponentValueErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComponentValueErr::Null => write!(f, "null is not a component"),
+            ComponentValueErr::EmptyList => write!(f, "A component list can't be empty"),
+            ComponentValueErr::MissingContent => write!(f, "The component has no content"),
+            ComponentValueErr::Invalid(err) => write!(f, "Invalid component: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl std::error::Error for ComponentValueErr {}
+
+#[cfg(feature = "json")]
+impl TryFrom<Value> for ChatComponent {
+    type Error = ComponentValueErr;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match &value {
+            Value::Null => return Err(ComponentValueErr::Null),
+            Value::Array(values) if values.is_empty() => return Err(ComponentValueErr::EmptyList),
+            _ => {}
+        }
+        let content = match &value {
+            Value::Object(map) => CONTENT_FIELDS.iter().any(|key| map.contains_key(*key)),
+            _ => true,
+        };
+        ChatComponent::deserialize(&value).map_err(|err| {
+            if content {
+                ComponentValueErr::Invalid(explain(&value, err).to_string())
+            } else {
+                ComponentValueErr::MissingContent
+            }
+        })
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<ChatComponent> for Value {
+    fn from(component: ChatComponent) -> Self {
+        serde_json::to_value(component).expect("components always serialize to JSON")
+    }
+}
@@ -0,0 +1,60 @@
+use std::mem::size_of;
+
+use crate::component::{ChatComponent, ComponentType, NbtSource};
+
+/// Heap bytes of an [`Arc<str>`](std::sync::Arc), including its reference counts.
+pub(crate) fn shared_str_size(string: &str) -> usize {
+    string.len() + 2 * size_of::<usize>()
+}
+
+impl ChatComponent {
+    /// Estimates the heap memory (in bytes) held by this component tree:
+    /// strings, vectors, events and nested components.
+    ///
+    /// Strings shared through an [`Interner`](crate::Interner) are counted for every use.
+    pub fn deep_size_of(&self) -> usize {
+        let kind = match &self.kind {
+            ComponentType::Text(text) => text.text.len(),
+            ComponentType::Translation(translation) => {
+                shared_str_size(&translation.key)
+                    + translation.with.capacity() * size_of::<ChatComponent>()
+                    + translation
+                        .with
+                        .iter()
+                        .map(ChatComponent::deep_size_of)
+                        .sum::<usize>()
+                    + translation
+                        .fallback
+                        .as_ref()
+                        .map_or(0, |fallback| fallback.len())
+            }
+            ComponentType::Score(score) => {
+                score.name.len()
+                    + shared_str_size(&score.objective)
+                    + score.value.as_ref().map_or(0, |value| value.len())
+            }
+            ComponentType::Selector(selector) => selector.selector.len(),
+            ComponentType::Keybind(keybind) => keybind.keybind.len(),
+            ComponentType::Nbt(nbt) => {
+                nbt.nbt.len()
+                    + match &nbt.source {
+                        NbtSource::Block(coordinates) => coordinates.len(),
+                        NbtSource::Entity(selector) => selector.len(),
+                        NbtSource::Storage(id) => id.len(),
+                    }
+            }
+            ComponentType::Custom(custom) => custom.deep_size_of(),
+        };
+        let separator = self.kind.separator().map_or(0, |separator| {
+            size_of::<ChatComponent>() + separator.deep_size_of()
+        });
+        kind + separator
+            + self.style.deep_size_of()
+            + self.siblings.capacity() * size_of::<ChatComponent>()
+            + self
+                .siblings
+                .iter()
+                .map(ChatComponent::deep_size_of)
+                .sum::<usize>()
+    }
+}
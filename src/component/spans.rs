@@ -0,0 +1,129 @@
+use crate::component::{
+    default_key_name, translation_pieces, ChatComponent, ComponentType, TranslationPiece,
+};
+use crate::style::ComponentStyle;
+
+#[cfg(feature = "colored")]
+use colored::{ColoredString, Colorize};
+#[cfg(feature = "crossterm")]
+use crossterm::style::{Attribute, Color, ContentStyle, StyledContent};
+
+impl ChatComponent {
+    /// Flattens this component into runs of text with the style they are displayed in
+    /// (inherited from their parents), in reading order.
+    ///
+    /// This is what terminal UI libraries with their own styled text types need, see
+    /// `to_crossterm()` and `to_colored()` behind the features of the same name.
+    /// Translations are split around their arguments like
+    /// [`ChatComponent::to_plain_text()`] fills them in, empty texts are left out.
+    pub fn to_styled_spans(&self) -> Vec<(String, ComponentStyle)> {
+        let mut spans = vec![];
+        self.append_spans(None, &mut spans);
+        spans
+    }
+
+    /// The spans of [`ChatComponent::to_styled_spans()`] styled for crossterm, e.g.
+    /// to be printed with `queue!(out, PrintStyledContent(span))`.
+    #[cfg(feature = "crossterm")]
+    pub fn to_crossterm(&self) -> Vec<StyledContent<String>> {
+        self.to_styled_spans()
+            .into_iter()
+            .map(|(text, style)| StyledContent::new(ContentStyle::from(&style), text))
+            .collect()
+    }
+
+    /// The spans of [`ChatComponent::to_styled_spans()`] colored by the colored crate,
+    /// colors are written as 24-bit colors.
+    #[cfg(feature = "colored")]
+    pub fn to_colored(&self) -> Vec<ColoredString> {
+        self.to_styled_spans()
+            .into_iter()
+            .map(|(text, style)| {
+                let mut span = ColoredString::from(text);
+                if let Some((red, green, blue)) = style.get_color().and_then(|color| color.to_rgb())
+                {
+                    span = span.truecolor(red, green, blue);
+                }
+                if style.get_bold() == Some(true) {
+                    span = span.bold();
+                }
+                if style.get_italic() == Some(true) {
+                    span = span.italic();
+                }
+                if style.get_underlined() == Some(true) {
+                    span = span.underline();
+                }
+                if style.get_strikethrough() == Some(true) {
+                    span = span.strikethrough();
+                }
+                span
+            })
+            .collect()
+    }
+
+    fn append_spans(
+        &self,
+        parent: Option<&ComponentStyle>,
+        spans: &mut Vec<(String, ComponentStyle)>,
+    ) {
+        let mut style = self.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+        let push = |text: &str, spans: &mut Vec<(String, ComponentStyle)>| {
+            if !text.is_empty() {
+                spans.push((text.to_string(), style.clone()));
+            }
+        };
+        match self.get_kind() {
+            ComponentType::Text(text) => push(text.get_text(), spans),
+            ComponentType::Translation(translation) => {
+                for piece in translation_pieces(translation.get_key()) {
+                    match piece {
+                        TranslationPiece::Text(text) => push(&text, spans),
+                        TranslationPiece::Arg(index) => {
+                            if let Some(arg) = translation.get_args().get(index) {
+                                arg.append_spans(Some(&style), spans);
+                            }
+                        }
+                    }
+                }
+            }
+            ComponentType::Score(score) => push(score.get_value().unwrap_or_default(), spans),
+            ComponentType::Selector(selector) => push(selector.get_selector(), spans),
+            ComponentType::Keybind(keybind) => push(
+                default_key_name(keybind.get_keybind()).unwrap_or(keybind.get_keybind()),
+                spans,
+            ),
+            ComponentType::Nbt(_) => {}
+            ComponentType::Custom(custom) => push(&custom.plain_text(), spans),
+        }
+        for sibling in self.get_siblings() {
+            sibling.append_spans(Some(&style), spans);
+        }
+    }
+}
+
+/// The colors as 24-bit colors and the decorations besides obfuscated, which has no
+/// attribute in terminals.
+#[cfg(feature = "crossterm")]
+impl From<&ComponentStyle> for ContentStyle {
+    fn from(style: &ComponentStyle) -> Self {
+        let mut content = ContentStyle::new();
+        if let Some((r, g, b)) = style.get_color().and_then(|color| color.to_rgb()) {
+            content.foreground_color = Some(Color::Rgb { r, g, b });
+        }
+        let decorations = [
+            (style.get_bold(), Attribute::Bold),
+            (style.get_italic(), Attribute::Italic),
+            (style.get_underlined(), Attribute::Underlined),
+            (style.get_strikethrough(), Attribute::CrossedOut),
+        ];
+        for (decoration, attribute) in decorations {
+            if decoration == Some(true) {
+                content.attributes.set(attribute);
+            }
+        }
+        content
+    }
+}
@@ -0,0 +1,86 @@
+/// A part of a translation pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum TranslationPiece {
+    Text(String),
+    /// The argument at this index
+    Arg(usize),
+}
+
+/// Splits a translation pattern into its text and the arguments placed in it,
+/// supporting `%s`, positional `%1$s` and escaped `%%`.
+pub(crate) fn translation_pieces(pattern: &str) -> Vec<TranslationPiece> {
+    let mut pieces = vec![];
+    let mut text = String::new();
+    let mut next = 0;
+    let mut chars = pattern.char_indices().peekable();
+    let arg = |text: &mut String, index: usize, pieces: &mut Vec<TranslationPiece>| {
+        if !text.is_empty() {
+            pieces.push(TranslationPiece::Text(std::mem::take(text)));
+        }
+        pieces.push(TranslationPiece::Arg(index));
+    };
+    while let Some((_, c)) = chars.next() {
+        if c != '%' {
+            text.push(c);
+            continue;
+        }
+        let mut position = String::new();
+        while let Some((_, digit)) = chars.peek().filter(|(_, digit)| digit.is_ascii_digit()) {
+            position.push(*digit);
+            chars.next();
+        }
+        match chars.next() {
+            Some((_, '%')) if position.is_empty() => text.push('%'),
+            Some((_, 's')) if position.is_empty() => {
+                arg(&mut text, next, &mut pieces);
+                next += 1;
+            }
+            Some((_, '$'))
+                if !position.is_empty() && chars.peek().map(|(_, c)| *c) == Some('s') =>
+            {
+                chars.next();
+                // `%0$s` (or an overflowing position) is no argument
+                if let Some(index) = position
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|i| i.checked_sub(1))
+                {
+                    arg(&mut text, index, &mut pieces);
+                }
+            }
+            // not a valid placeholder, keep it as is
+            Some((_, other)) => {
+                text.push('%');
+                text.push_str(&position);
+                text.push(other);
+            }
+            None => {
+                text.push('%');
+                text.push_str(&position);
+            }
+        }
+    }
+    if !text.is_empty() {
+        pieces.push(TranslationPiece::Text(text));
+    }
+    pieces
+}
+
+/// Fills the arguments into a translation pattern the way the client does,
+/// see [`translation_pieces()`].
+///
+/// Missing arguments are left out.
+pub(crate) fn format_translation(pattern: &str, args: &[String]) -> String {
+    let mut output = String::with_capacity(pattern.len());
+    for piece in translation_pieces(pattern) {
+        match piece {
+            TranslationPiece::Text(text) => output.push_str(&text),
+            TranslationPiece::Arg(index) => {
+                if let Some(arg) = args.get(index) {
+                    output.push_str(arg);
+                }
+            }
+        }
+    }
+    output
+}
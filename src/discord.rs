@@ -0,0 +1,359 @@
+//! Converting components to and from Discord's Markdown, e.g. for chat bridges.
+
+use crate::component::{default_key_name, format_translation, ChatComponent, ComponentType};
+use crate::style::{ClickEvent, ComponentStyle};
+
+/// A decoration Discord displays, in the order they are opened.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Marker {
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+}
+
+impl Marker {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Marker::Bold => "**",
+            Marker::Italic => "*",
+            Marker::Underline => "__",
+            Marker::Strikethrough => "~~",
+        }
+    }
+}
+
+fn markers(style: &ComponentStyle) -> Vec<Marker> {
+    let decorations = [
+        (style.get_bold(), Marker::Bold),
+        (style.get_italic(), Marker::Italic),
+        (style.get_underlined(), Marker::Underline),
+        (style.get_strikethrough(), Marker::Strikethrough),
+    ];
+    decorations
+        .iter()
+        .filter(|(value, _)| *value == Some(true))
+        .map(|(_, marker)| *marker)
+        .collect()
+}
+
+/// The url of `style` if Discord would link it.
+fn link(style: &ComponentStyle) -> Option<String> {
+    match style.get_click_event() {
+        Some(ClickEvent::OpenUrl(url))
+            if url.starts_with("http://") || url.starts_with("https://") =>
+        {
+            Some(url.replace(' ', "%20").replace(')', "%29"))
+        }
+        _ => None,
+    }
+}
+
+/// Escapes the characters Discord reads as Markdown (or mentions).
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\*_~`|[]<>".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+struct MarkdownWriter {
+    out: String,
+    /// The markers currently open, in the order they were opened
+    open: Vec<Marker>,
+    link: Option<String>,
+}
+
+impl MarkdownWriter {
+    /// Closes and opens markers until exactly `target` is open inside of `link`.
+    fn switch_to(&mut self, target: &[Marker], link: Option<String>) {
+        if link != self.link {
+            self.close_from(0);
+            if let Some(url) = self.link.take() {
+                self.out.push_str(&format!("]({})", url));
+            }
+            if link.is_some() {
+                self.out.push('[');
+            }
+            self.link = link;
+        }
+        let kept = self
+            .open
+            .iter()
+            .zip(target)
+            .take_while(|(open, target)| open == target)
+            .count();
+        self.close_from(kept);
+        for marker in &target[kept..] {
+            self.out.push_str(marker.as_str());
+            self.open.push(*marker);
+        }
+    }
+
+    fn close_from(&mut self, index: usize) {
+        while self.open.len() > index {
+            let marker = self.open.pop().expect("only open markers are closed");
+            self.out.push_str(marker.as_str());
+        }
+    }
+
+    fn write(&mut self, component: &ChatComponent, parent: Option<&ComponentStyle>) {
+        let mut style = component.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+        let (target, url) = (markers(&style), link(&style));
+
+        let text = match component.get_kind() {
+            ComponentType::Text(text) => escape(text.get_text()),
+            ComponentType::Translation(translation) => {
+                // every argument starts and ends with the translation's markers open
+                let args: Vec<String> = translation
+                    .get_args()
+                    .iter()
+                    .map(|arg| {
+                        let mut writer = MarkdownWriter {
+                            out: String::new(),
+                            open: target.clone(),
+                            link: url.clone(),
+                        };
+                        writer.write(arg, Some(&style));
+                        writer.switch_to(&target, url.clone());
+                        writer.out
+                    })
+                    .collect();
+                format_translation(&escape(translation.get_key()), &args)
+            }
+            ComponentType::Score(score) => escape(score.get_value().unwrap_or_default()),
+            ComponentType::Selector(selector) => escape(selector.get_selector()),
+            ComponentType::Keybind(keybind) => {
+                escape(default_key_name(keybind.get_keybind()).unwrap_or(keybind.get_keybind()))
+            }
+            ComponentType::Nbt(_) => String::new(),
+            ComponentType::Custom(custom) => escape(&custom.plain_text()),
+        };
+        if !text.is_empty() {
+            self.switch_to(&target, url);
+            self.out.push_str(&text);
+        }
+        for sibling in component.get_siblings() {
+            self.write(sibling, Some(&style));
+        }
+    }
+}
+
+struct MarkdownParser {
+    version: u32,
+    components: Vec<ChatComponent>,
+    text: String,
+    bold: bool,
+    /// The character that opened the italic text, `*` or `_`
+    italic: Option<char>,
+    underlined: bool,
+    strikethrough: bool,
+    /// The url of the current link and the length of its `(url)` part
+    link: Option<(String, usize)>,
+}
+
+impl MarkdownParser {
+    fn flush(&mut self) {
+        if self.text.is_empty() {
+            return;
+        }
+        let mut style = ComponentStyle::with_version(self.version);
+        let decorations = [
+            (
+                self.bold,
+                ComponentStyle::set_bold as fn(&mut ComponentStyle, bool),
+            ),
+            (self.italic.is_some(), ComponentStyle::set_italic),
+            (self.underlined, ComponentStyle::set_underlined),
+            (self.strikethrough, ComponentStyle::set_strikethrough),
+        ];
+        for (value, set) in decorations.iter() {
+            if *value {
+                set(&mut style, true);
+            }
+        }
+        if let Some((url, _)) = &self.link {
+            style.set_click_event(Some(ClickEvent::url(url.clone())));
+        }
+        let text = std::mem::take(&mut self.text);
+        self.components.push(ChatComponent::from_text(text, style));
+    }
+
+    /// Reads a link starting after its `[`, returning its url and where its text ends
+    /// (the index of `]`) and the length of the `](url)` following it.
+    fn read_link(rest: &str) -> Option<(String, usize, usize)> {
+        let mut escaped = false;
+        let end = rest.char_indices().find_map(|(i, c)| match c {
+            _ if escaped => {
+                escaped = false;
+                None
+            }
+            '\\' => {
+                escaped = true;
+                None
+            }
+            ']' => Some(i),
+            _ => None,
+        })?;
+        let target = rest[end + 1..].strip_prefix('(')?;
+        let close = target.find(')')?;
+        let url = target[..close].trim();
+        let url = url
+            .strip_prefix('<')
+            .and_then(|url| url.strip_suffix('>'))
+            .unwrap_or(url);
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return None;
+        }
+        Some((url.to_string(), end, close + 3))
+    }
+
+    fn parse(&mut self, input: &str) {
+        let mut rest = input;
+        let mut previous = None;
+        // the length of the input left where the current link's text ends
+        let mut link_end = None;
+        while let Some(c) = rest.chars().next() {
+            if link_end == Some(rest.len()) {
+                let (_, skip) = self.link.clone().expect("links end after they started");
+                self.flush();
+                self.link = None;
+                link_end = None;
+                rest = &rest[skip..];
+                previous = Some(')');
+                continue;
+            }
+            let starts = |marker: &str| rest.starts_with(marker);
+            // a marker only counts if it is closed again later
+            let closed_later = |marker: &str| rest[marker.len()..].contains(marker);
+            let next = rest[c.len_utf8()..].chars().next();
+            let mut toggled = None;
+            for (marker, open) in [
+                ("**", self.bold),
+                ("__", self.underlined),
+                ("~~", self.strikethrough),
+            ] {
+                if starts(marker) && (open || closed_later(marker)) {
+                    toggled = Some(marker);
+                    break;
+                }
+            }
+            if let Some(marker) = toggled {
+                self.flush();
+                match marker {
+                    "**" => self.bold = !self.bold,
+                    "__" => self.underlined = !self.underlined,
+                    _ => self.strikethrough = !self.strikethrough,
+                }
+                rest = &rest[2..];
+                previous = Some(c);
+                continue;
+            }
+            match c {
+                '\\' if next.is_some_and(|next| next.is_ascii_punctuation()) => {
+                    let next = next.unwrap_or_default();
+                    self.text.push(next);
+                    rest = &rest[1 + next.len_utf8()..];
+                    previous = Some(next);
+                    continue;
+                }
+                // `_` only opens at the start and closes at the end of a word,
+                // so snake_case stays as it is
+                '*' | '_'
+                    if self.italic == Some(c)
+                        && (c == '*' || !next.is_some_and(char::is_alphanumeric)) =>
+                {
+                    self.flush();
+                    self.italic = None;
+                    rest = &rest[1..];
+                    previous = Some(c);
+                    continue;
+                }
+                '*' | '_'
+                    if self.italic.is_none()
+                        && next != Some(c)
+                        && (c == '*' || !previous.is_some_and(char::is_alphanumeric))
+                        && closed_later(&c.to_string()) =>
+                {
+                    self.flush();
+                    self.italic = Some(c);
+                    rest = &rest[1..];
+                    previous = Some(c);
+                    continue;
+                }
+                '`' => {
+                    if let Some(end) = rest[1..].find('`') {
+                        self.text.push_str(&rest[1..end + 1]);
+                        rest = &rest[end + 2..];
+                        previous = Some(c);
+                        continue;
+                    }
+                }
+                '[' if self.link.is_none() => {
+                    if let Some((url, end, skip)) = MarkdownParser::read_link(&rest[1..]) {
+                        self.flush();
+                        self.link = Some((url, skip));
+                        link_end = Some(rest.len() - 1 - end);
+                        rest = &rest[1..];
+                        previous = Some(c);
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            self.text.push(c);
+            rest = &rest[c.len_utf8()..];
+            previous = Some(c);
+        }
+        self.flush();
+    }
+}
+
+impl ChatComponent {
+    /// Converts this component into Discord's Markdown: bold, italic, underlined and
+    /// strikethrough text get their markers and `open_url` click events become links.
+    ///
+    /// Colors and other events are dropped, Markdown in the text is escaped.
+    pub fn to_discord_markdown(&self) -> String {
+        let mut writer = MarkdownWriter {
+            out: String::new(),
+            open: vec![],
+            link: None,
+        };
+        writer.write(self, None);
+        writer.switch_to(&[], None);
+        writer.out
+    }
+
+    /// Parses Discord's Markdown (like a message sent to a chat bridge) into components
+    /// of the given version, see [`ChatComponent::to_discord_markdown()`].
+    ///
+    /// Markers that are never closed and everything else (like code blocks or headings)
+    /// are kept as text.
+    pub fn from_discord_markdown(input: &str, version: u32) -> ChatComponent {
+        let mut parser = MarkdownParser {
+            version,
+            components: vec![],
+            text: String::new(),
+            bold: false,
+            italic: None,
+            underlined: false,
+            strikethrough: false,
+            link: None,
+        };
+        parser.parse(input);
+        if parser.components.len() == 1 {
+            return parser.components.remove(0);
+        }
+        let mut component = ChatComponent::from_text("", ComponentStyle::with_version(version));
+        *component.get_siblings_mut() = parser.components;
+        component
+    }
+}
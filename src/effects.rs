@@ -0,0 +1,146 @@
+//! Color effects applied per character, like gradients.
+//!
+//! Custom colors require 1.16, older clients display the text without colors.
+
+use crate::component::ChatComponent;
+use crate::style::{ChatColor, ComponentStyle};
+
+/// How the progress of an animation is spread over its frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// Moves at a constant speed.
+    Linear,
+    /// Starts and ends slowly, moving fastest in the middle.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Maps the linear progress `t` (from 0 to 1) to the eased progress.
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInOut => (1.0 - (t * std::f32::consts::PI).cos()) / 2.0,
+        }
+    }
+}
+
+type Rgb = (u8, u8, u8);
+
+/// The color at `t` (from 0 to 1) of a gradient going through `stops` at equal distances.
+fn interpolate(stops: &[Rgb], t: f32) -> Rgb {
+    if stops.len() == 1 {
+        return stops[0];
+    }
+    let position = t.clamp(0.0, 1.0) * (stops.len() - 1) as f32;
+    let index = (position as usize).min(stops.len() - 2);
+    let t = position - index as f32;
+    let (from, to) = (stops[index], stops[index + 1]);
+    let mix = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t).round() as u8;
+    (mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+}
+
+fn stops(colors: &[ChatColor]) -> Vec<Rgb> {
+    colors.iter().filter_map(ChatColor::to_rgb).collect()
+}
+
+/// Colors every character of `text` with `color_at(index, length)`.
+fn color_chars<F>(text: &str, style: ComponentStyle, color_at: F) -> ChatComponent
+where
+    F: Fn(usize, usize) -> Option<Rgb>,
+{
+    let version = style.get_version();
+    let length = crate::text::units(text).count();
+    let mut component = ChatComponent::from_text(text, style);
+    component.style_chars(|i, _| {
+        let (red, green, blue) = color_at(i, length)?;
+        Some(ComponentStyle::with_version(version).color(Some(ChatColor::rgb(red, green, blue))))
+    });
+    component
+}
+
+/// Colors every character of `component`'s text with `color_at(index, length)`.
+fn color_component<F>(component: &mut ChatComponent, color_at: F)
+where
+    F: Fn(usize, usize) -> Option<Rgb>,
+{
+    let version = component.get_version();
+    let mut length = 0;
+    component.style_chars(|_, _| {
+        length += 1;
+        None
+    });
+    component.style_chars(|i, _| {
+        let (red, green, blue) = color_at(i, length)?;
+        Some(ComponentStyle::with_version(version).color(Some(ChatColor::rgb(red, green, blue))))
+    });
+}
+
+/// The color of a rainbow at `t` (from 0 to 1), starting and ending at red.
+fn hue(t: f32) -> Rgb {
+    let h = t.fract() * 6.0;
+    let x = 1.0 - (h % 2.0 - 1.0).abs();
+    let (red, green, blue) = match h as u32 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    let channel = |value: f32| (value * 255.0).round() as u8;
+    (channel(red), channel(green), channel(blue))
+}
+
+/// Colors the text of `component` (and its siblings) with a gradient, see [`gradient()`].
+pub fn apply_gradient(component: &mut ChatComponent, colors: &[ChatColor]) {
+    let stops = stops(colors);
+    color_component(component, |i, length| {
+        let last = length.saturating_sub(1).max(1);
+        (!stops.is_empty()).then(|| interpolate(&stops, i as f32 / last as f32))
+    });
+}
+
+/// Colors the text of `component` (and its siblings) with every hue once, starting
+/// at red and shifted by `phase` (from 0 to 1).
+pub fn apply_rainbow(component: &mut ChatComponent, phase: f32) {
+    color_component(component, |i, length| {
+        Some(hue(i as f32 / length as f32 + phase))
+    });
+}
+
+/// Colors `text` with a gradient from the first to the last of `colors`.
+///
+/// `style` is applied to the whole text, colors without RGB values
+/// ([`ChatColor::Reset`]) are ignored.
+pub fn gradient(text: &str, colors: &[ChatColor], style: ComponentStyle) -> ChatComponent {
+    let mut component = ChatComponent::from_text(text, style);
+    apply_gradient(&mut component, colors);
+    component
+}
+
+/// Builds `frames` components of `text` with a looping gradient through `colors`
+/// that moves along the text once over all frames, e.g. to animate an actionbar or bossbar.
+///
+/// Sending the frames in order (and starting over) plays a seamless loop.
+pub fn gradient_frames(
+    text: &str,
+    colors: &[ChatColor],
+    style: ComponentStyle,
+    frames: u32,
+    easing: Easing,
+) -> Vec<ChatComponent> {
+    let mut stops = stops(colors);
+    // the first color is repeated at the end so the loop has no visible seam
+    if let Some(first) = stops.first().copied() {
+        stops.push(first);
+    }
+    (0..frames)
+        .map(|frame| {
+            let phase = easing.apply(frame as f32 / frames as f32);
+            color_chars(text, style.clone(), |i, length| {
+                let t = (i as f32 / length as f32 + phase).fract();
+                (!stops.is_empty()).then(|| interpolate(&stops, t))
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,42 @@
+//! The formats the `mcchat` binary and the Python bindings convert between.
+//!
+//! Not part of the public API, it is only public because the binary is a separate crate.
+
+use crate::component::ChatComponent;
+use crate::html::HtmlTheme;
+use crate::style::VERSION_1_16;
+
+/// Parses `input` given in `format`.
+pub fn read(input: &str, format: &str) -> Result<ChatComponent, String> {
+    match format {
+        "json" => ChatComponent::from_json(input).map_err(|err| err.to_string()),
+        "legacy" => Ok(ChatComponent::from_legacy_text(input, VERSION_1_16)),
+        "discord" => Ok(ChatComponent::from_discord_markdown(input, VERSION_1_16)),
+        "rawtext" => {
+            ChatComponent::from_rawtext(input, VERSION_1_16).map_err(|err| err.to_string())
+        }
+        #[cfg(feature = "nbt")]
+        "snbt" => ChatComponent::from_snbt(input).map_err(|err| err.to_string()),
+        #[cfg(feature = "minimessage")]
+        "minimessage" => Ok(ChatComponent::from_minimessage(input, VERSION_1_16)),
+        _ => Err(format!("{} is not a supported input format!", format)),
+    }
+}
+
+/// Writes `component` in `format`.
+pub fn write(component: &ChatComponent, format: &str) -> Result<String, String> {
+    match format {
+        "json" => serde_json::to_string(component).map_err(|err| err.to_string()),
+        "legacy" => Ok(component.to_legacy_string()),
+        "ansi" => Ok(component.to_ansi()),
+        "bbcode" => Ok(component.to_bbcode()),
+        "irc" => Ok(component.to_irc()),
+        "html" => Ok(component.to_html(&HtmlTheme::default())),
+        "rawtext" => Ok(component.to_rawtext()),
+        "snbt" => Ok(component.to_snbt()),
+        "discord" => Ok(component.to_discord_markdown()),
+        #[cfg(feature = "minimessage")]
+        "minimessage" => Ok(component.to_minimessage()),
+        _ => Err(format!("{} is not a supported output format!", format)),
+    }
+}
@@ -0,0 +1,367 @@
+//! Rendering components as HTML, e.g. for web panels showing the chat.
+
+use crate::component::{default_key_name, format_translation, ChatComponent, ComponentType};
+use crate::style::{ChatFormatting, ClickEvent, ComponentStyle};
+
+/// How the style of rendered text is written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HtmlStyleMode {
+    /// `style` attributes, the output needs no stylesheet.
+    Inline,
+    /// Classes like `mc-bold` and `mc-red`, styled by [`HtmlTheme::stylesheet()`]
+    /// or your own stylesheet. Custom colors are still written inline.
+    Classes,
+}
+
+/// The background the HTML is displayed on, colors barely readable on it are adjusted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HtmlBackground {
+    Dark,
+    Light,
+}
+
+/// Builds the markup of obfuscated text from its (escaped) text.
+pub type ObfuscatedMarkup = fn(&str) -> String;
+
+/// Options for [`ChatComponent::to_html()`].
+#[derive(Clone, Debug)]
+pub struct HtmlTheme {
+    style_mode: HtmlStyleMode,
+    background: Option<HtmlBackground>,
+    obfuscated: Option<ObfuscatedMarkup>,
+}
+
+impl Default for HtmlTheme {
+    /// Inline styles without color adjustments.
+    fn default() -> Self {
+        HtmlTheme {
+            style_mode: HtmlStyleMode::Inline,
+            background: None,
+            obfuscated: None,
+        }
+    }
+}
+
+impl HtmlTheme {
+    pub fn new() -> Self {
+        HtmlTheme::default()
+    }
+
+    pub fn get_style_mode(&self) -> HtmlStyleMode {
+        self.style_mode
+    }
+
+    pub fn set_style_mode(&mut self, style_mode: HtmlStyleMode) {
+        self.style_mode = style_mode;
+    }
+
+    pub fn style_mode(mut self, style_mode: HtmlStyleMode) -> Self {
+        self.set_style_mode(style_mode);
+        self
+    }
+
+    pub fn get_background(&self) -> Option<HtmlBackground> {
+        self.background
+    }
+
+    /// Sets the background to adjust colors for, [`None`] keeps every color as it is.
+    pub fn set_background(&mut self, background: Option<HtmlBackground>) {
+        self.background = background;
+    }
+
+    pub fn background(mut self, background: Option<HtmlBackground>) -> Self {
+        self.set_background(background);
+        self
+    }
+
+    pub fn get_obfuscated(&self) -> Option<ObfuscatedMarkup> {
+        self.obfuscated
+    }
+
+    /// Sets the markup for obfuscated text, e.g. to animate it with a script.
+    /// By default it is rendered like any other text.
+    pub fn set_obfuscated(&mut self, obfuscated: Option<ObfuscatedMarkup>) {
+        self.obfuscated = obfuscated;
+    }
+
+    pub fn obfuscated(mut self, obfuscated: Option<ObfuscatedMarkup>) -> Self {
+        self.set_obfuscated(obfuscated);
+        self
+    }
+
+    /// Makes `color` readable on the background by mixing it with the opposite of
+    /// the background when their brightness is too close.
+    fn adjust(&self, (red, green, blue): (u8, u8, u8)) -> (u8, u8, u8) {
+        let luminance =
+            (0.2126 * red as f32 + 0.7152 * green as f32 + 0.0722 * blue as f32) / 255.0;
+        let target = match self.background {
+            Some(HtmlBackground::Dark) if luminance < 0.2 => 255.0,
+            Some(HtmlBackground::Light) if luminance > 0.7 => 0.0,
+            _ => return (red, green, blue),
+        };
+        let mix = |value: u8| (value as f32 + (target - value as f32) * 0.4).round() as u8;
+        (mix(red), mix(green), mix(blue))
+    }
+
+    /// Builds the stylesheet for the classes of [`HtmlStyleMode::Classes`], one rule per line
+    /// with the colors adjusted to the background. Obfuscated text gets no style.
+    pub fn stylesheet(&self) -> String {
+        let mut rules = vec![
+            ".mc-bold{font-weight:bold}".to_string(),
+            ".mc-italic{font-style:italic}".to_string(),
+            ".mc-underlined{text-decoration:underline}".to_string(),
+            ".mc-strikethrough{text-decoration:line-through}".to_string(),
+            ".mc-underlined.mc-strikethrough{text-decoration:underline line-through}".to_string(),
+        ];
+        for formatting in ChatFormatting::ALL.iter().filter(|f| f.is_color()) {
+            if let Some(rgb) = formatting.to_color().and_then(|color| color.to_rgb()) {
+                let (red, green, blue) = self.adjust(rgb);
+                rules.push(format!(
+                    ".mc-{}{{color:#{:02x}{:02x}{:02x}}}",
+                    formatting.get_name(),
+                    red,
+                    green,
+                    blue
+                ));
+            }
+        }
+        rules.join("\n")
+    }
+
+    /// The CSS declarations (inline mode) or classes (class mode) of `style`.
+    fn style(&self, style: &ComponentStyle) -> (Vec<String>, Vec<String>) {
+        let mut css = vec![];
+        let mut classes = vec![];
+        let mut text_decoration = vec![];
+        let decorations = [
+            (style.get_bold(), "bold"),
+            (style.get_italic(), "italic"),
+            (style.get_underlined(), "underlined"),
+            (style.get_strikethrough(), "strikethrough"),
+            (style.get_obfuscated(), "obfuscated"),
+        ];
+        for (value, name) in decorations.iter() {
+            if *value != Some(true) {
+                continue;
+            }
+            match (self.style_mode, *name) {
+                (HtmlStyleMode::Classes, name) => classes.push(format!("mc-{}", name)),
+                (_, "bold") => css.push("font-weight:bold".to_string()),
+                (_, "italic") => css.push("font-style:italic".to_string()),
+                (_, "underlined") => text_decoration.push("underline"),
+                (_, "strikethrough") => text_decoration.push("line-through"),
+                _ => {}
+            }
+        }
+        if !text_decoration.is_empty() {
+            css.push(format!("text-decoration:{}", text_decoration.join(" ")));
+        }
+
+        if let Some(color) = style.get_color() {
+            match (self.style_mode, ChatFormatting::from_color(color)) {
+                (HtmlStyleMode::Classes, Some(formatting)) => {
+                    classes.push(format!("mc-{}", formatting.get_name()))
+                }
+                _ => {
+                    if let Some(rgb) = color.to_rgb() {
+                        let (red, green, blue) = self.adjust(rgb);
+                        css.push(format!("color:#{:02x}{:02x}{:02x}", red, green, blue));
+                    }
+                }
+            }
+        }
+        (css, classes)
+    }
+}
+
+/// Escapes the characters with a meaning in HTML.
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '\n' => escaped.push_str("<br>"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Returns whether `url` is one clients open, other schemes like `javascript:` never become links.
+fn is_web_url(url: &str) -> bool {
+    let url = url.to_ascii_lowercase();
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+impl ChatComponent {
+    /// Renders this component as HTML `<span>`s styled according to `theme`,
+    /// text opening a url when clicked becomes a link.
+    ///
+    /// Translations are rendered with their key as pattern, `\n` becomes `<br>`.
+    pub fn to_html(&self, theme: &HtmlTheme) -> String {
+        let mut out = String::new();
+        self.render_html(None, theme, &mut out);
+        out
+    }
+
+    fn render_html(&self, parent: Option<&ComponentStyle>, theme: &HtmlTheme, out: &mut String) {
+        let mut style = self.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+
+        let mut content = match self.get_kind() {
+            ComponentType::Text(text) => escape_html(text.get_text()),
+            ComponentType::Translation(translation) => {
+                let args: Vec<String> = translation
+                    .get_args()
+                    .iter()
+                    .map(|arg| {
+                        let mut rendered = String::new();
+                        arg.render_html(Some(&style), theme, &mut rendered);
+                        rendered
+                    })
+                    .collect();
+                format_translation(&escape_html(translation.get_key()), &args)
+            }
+            ComponentType::Score(score) => escape_html(score.get_value().unwrap_or_default()),
+            ComponentType::Selector(selector) => escape_html(selector.get_selector()),
+            ComponentType::Keybind(keybind) => escape_html(
+                default_key_name(keybind.get_keybind()).unwrap_or(keybind.get_keybind()),
+            ),
+            ComponentType::Nbt(_) => String::new(),
+            ComponentType::Custom(custom) => escape_html(&custom.plain_text()),
+        };
+        if let (Some(markup), true) = (theme.obfuscated, style.is_obfuscated()) {
+            if !matches!(self.get_kind(), ComponentType::Translation(_)) {
+                content = markup(&content);
+            }
+        }
+
+        if !content.is_empty() {
+            let link = match style.get_click_event() {
+                Some(ClickEvent::OpenUrl(url)) if is_web_url(url) => Some(url),
+                _ => None,
+            };
+            if let Some(url) = link {
+                out.push_str(&format!(
+                    "<a href=\"{}\" rel=\"nofollow\">",
+                    escape_html(url)
+                ));
+            }
+            let (css, classes) = theme.style(&style);
+            if css.is_empty() && classes.is_empty() {
+                out.push_str(&content);
+            } else {
+                out.push_str("<span");
+                if !classes.is_empty() {
+                    out.push_str(&format!(" class=\"{}\"", classes.join(" ")));
+                }
+                if !css.is_empty() {
+                    out.push_str(&format!(" style=\"{}\"", css.join(";")));
+                }
+                out.push('>');
+                out.push_str(&content);
+                out.push_str("</span>");
+            }
+            if link.is_some() {
+                out.push_str("</a>");
+            }
+        }
+        for sibling in self.get_siblings() {
+            sibling.render_html(Some(&style), theme, out);
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Renders this component as the HTML subset Matrix allows in the
+    /// `org.matrix.custom.html` format of messages, e.g. for chat bridges.
+    ///
+    /// Colors become `<span data-mx-color>`, decorations `<b>`, `<i>`, `<u>` and `<del>`,
+    /// obfuscated text a spoiler and text opening a url a link. `\n` becomes `<br>`.
+    /// Matrix messages need a plain `body` too, see [`ChatComponent::to_plain_text()`].
+    pub fn to_matrix_html(&self) -> String {
+        let mut out = String::new();
+        self.render_matrix_html(None, &mut out);
+        out
+    }
+
+    fn render_matrix_html(&self, parent: Option<&ComponentStyle>, out: &mut String) {
+        let mut style = self.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+
+        let content = match self.get_kind() {
+            ComponentType::Text(text) => escape_html(text.get_text()),
+            ComponentType::Translation(translation) => {
+                let args: Vec<String> = translation
+                    .get_args()
+                    .iter()
+                    .map(|arg| {
+                        let mut rendered = String::new();
+                        arg.render_matrix_html(Some(&style), &mut rendered);
+                        rendered
+                    })
+                    .collect();
+                format_translation(&escape_html(translation.get_key()), &args)
+            }
+            ComponentType::Score(score) => escape_html(score.get_value().unwrap_or_default()),
+            ComponentType::Selector(selector) => escape_html(selector.get_selector()),
+            ComponentType::Keybind(keybind) => escape_html(
+                default_key_name(keybind.get_keybind()).unwrap_or(keybind.get_keybind()),
+            ),
+            ComponentType::Nbt(_) => String::new(),
+            ComponentType::Custom(custom) => escape_html(&custom.plain_text()),
+        };
+
+        if !content.is_empty() {
+            // the tags wrapping the content, from the outside in
+            let mut tags: Vec<(String, &str)> = vec![];
+            if let Some(ClickEvent::OpenUrl(url)) = style.get_click_event() {
+                if is_web_url(url) {
+                    tags.push((format!("<a href=\"{}\">", escape_html(url)), "</a>"));
+                }
+            }
+            if let Some((red, green, blue)) = style.get_color().and_then(|color| color.to_rgb()) {
+                tags.push((
+                    format!(
+                        "<span data-mx-color=\"#{:02x}{:02x}{:02x}\">",
+                        red, green, blue
+                    ),
+                    "</span>",
+                ));
+            }
+            let decorations = [
+                (style.get_bold(), "<b>", "</b>"),
+                (style.get_italic(), "<i>", "</i>"),
+                (style.get_underlined(), "<u>", "</u>"),
+                (style.get_strikethrough(), "<del>", "</del>"),
+            ];
+            for (value, open, close) in decorations.iter() {
+                if *value == Some(true) {
+                    tags.push((open.to_string(), close));
+                }
+            }
+            // arguments of a translation are hidden on their own
+            if style.is_obfuscated() && !matches!(self.get_kind(), ComponentType::Translation(_)) {
+                tags.push(("<span data-mx-spoiler>".to_string(), "</span>"));
+            }
+            for (open, _) in &tags {
+                out.push_str(open);
+            }
+            out.push_str(&content);
+            for (_, close) in tags.iter().rev() {
+                out.push_str(close);
+            }
+        }
+        for sibling in self.get_siblings() {
+            sibling.render_matrix_html(Some(&style), out);
+        }
+    }
+}
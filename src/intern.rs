@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::component::{ChatComponent, ComponentType};
+
+/// A registry deduplicating strings that repeat across many messages,
+/// like translation keys (`chat.type.text`), fonts and objectives.
+///
+/// Interned components share one allocation for every equal string,
+/// see [`ChatComponent::intern()`].
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    strings: HashSet<Arc<str>>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Interner::default()
+    }
+
+    /// Returns the shared copy of `string`, adding it to the registry if needed.
+    pub fn intern(&mut self, string: &str) -> Arc<str> {
+        if let Some(interned) = self.strings.get(string) {
+            return interned.clone();
+        }
+        let interned: Arc<str> = Arc::from(string);
+        self.strings.insert(interned.clone());
+        interned
+    }
+
+    /// The amount of distinct strings in this registry.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Removes every string that isn't used by any component anymore.
+    pub fn shrink(&mut self) {
+        self.strings.retain(|string| Arc::strong_count(string) > 1);
+    }
+}
+
+impl ChatComponent {
+    /// Replaces the translation keys, fonts and objectives in this tree
+    /// with the shared copies of `interner`.
+    pub fn intern(&mut self, interner: &mut Interner) {
+        self.walk_mut(&mut |component| {
+            match component.get_kind_mut() {
+                ComponentType::Translation(translation) => translation.intern(interner),
+                ComponentType::Score(score) => score.intern(interner),
+                _ => {}
+            }
+            component.get_style_mut().intern(interner);
+        });
+    }
+}
@@ -0,0 +1,194 @@
+//! Rendering components with mIRC formatting codes, e.g. for IRC bridges.
+
+use crate::component::{default_key_name, format_translation, ChatComponent, ComponentType};
+use crate::style::{ChatFormatting, ComponentStyle};
+
+const BOLD: char = '\x02';
+const COLOR: char = '\x03';
+const RESET: char = '\x0F';
+const ITALIC: char = '\x1D';
+const STRIKETHROUGH: char = '\x1E';
+const UNDERLINE: char = '\x1F';
+
+/// The number of a color in mIRC's 16 color palette.
+fn irc_color(formatting: ChatFormatting) -> Option<u8> {
+    Some(match formatting {
+        ChatFormatting::White => 0,
+        ChatFormatting::Black => 1,
+        ChatFormatting::DarkBlue => 2,
+        ChatFormatting::DarkGreen => 3,
+        ChatFormatting::Red => 4,
+        ChatFormatting::DarkRed => 5,
+        ChatFormatting::Purple => 6,
+        ChatFormatting::Gold => 7,
+        ChatFormatting::Yellow => 8,
+        ChatFormatting::Green => 9,
+        ChatFormatting::DarkCyan => 10,
+        ChatFormatting::Cyan => 11,
+        ChatFormatting::Blue => 12,
+        ChatFormatting::Pink => 13,
+        ChatFormatting::DarkGray => 14,
+        ChatFormatting::Gray => 15,
+        _ => return None,
+    })
+}
+
+/// The formatting IRC displays, unset values and [`ChatColor::Reset`] are unformatted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct IrcState {
+    color: Option<u8>,
+    bold: bool,
+    italic: bool,
+    underlined: bool,
+    strikethrough: bool,
+}
+
+impl IrcState {
+    fn of(style: &ComponentStyle) -> Self {
+        let color = style.get_color().and_then(|color| {
+            let named = color.to_nearest_legacy()?;
+            ChatFormatting::from_color(&named).and_then(irc_color)
+        });
+        IrcState {
+            color,
+            bold: style.get_bold() == Some(true),
+            italic: style.get_italic() == Some(true),
+            underlined: style.get_underlined() == Some(true),
+            strikethrough: style.get_strikethrough() == Some(true),
+        }
+    }
+
+    fn toggles(&self) -> [(bool, char); 4] {
+        [
+            (self.bold, BOLD),
+            (self.italic, ITALIC),
+            (self.underlined, UNDERLINE),
+            (self.strikethrough, STRIKETHROUGH),
+        ]
+    }
+}
+
+/// Removes the control codes IRC reads as formatting from text.
+fn strip_codes(text: &str) -> String {
+    text.chars()
+        .filter(|c| {
+            !matches!(
+                c,
+                '\x02' | '\x03' | '\x0F' | '\x11' | '\x16' | '\x1D'..='\x1F'
+            )
+        })
+        .collect()
+}
+
+struct IrcWriter {
+    out: String,
+    current: IrcState,
+    /// Whether the output ends with a color code
+    after_color: bool,
+}
+
+impl IrcWriter {
+    /// Writes the codes changing the formatting to `target`, formatting that ends
+    /// resets everything before the rest is applied again.
+    fn switch_to(&mut self, target: IrcState) {
+        if target == self.current {
+            return;
+        }
+        let current = self.current;
+        let ends = current
+            .toggles()
+            .iter()
+            .zip(target.toggles().iter())
+            .any(|((current, _), (target, _))| *current && !*target)
+            || (current.color.is_some() && target.color.is_none());
+        let current = if ends {
+            self.out.push(RESET);
+            IrcState::default()
+        } else {
+            current
+        };
+        for ((current, _), (target, code)) in current.toggles().iter().zip(target.toggles().iter())
+        {
+            if *target && !*current {
+                self.out.push(*code);
+            }
+        }
+        if let Some(color) = target.color.filter(|color| current.color != Some(*color)) {
+            // always two digits, so digits in the text can't change the color
+            self.out.push_str(&format!("{}{:02}", COLOR, color));
+            self.after_color = true;
+        }
+        self.current = target;
+    }
+
+    fn push_text(&mut self, text: &str) {
+        // `,` and a digit after a color would be read as its background
+        if text.starts_with(',') && self.after_color {
+            self.out.push(BOLD);
+            self.out.push(BOLD);
+        }
+        self.out.push_str(text);
+        self.after_color = false;
+    }
+
+    fn write(&mut self, component: &ChatComponent, parent: Option<&ComponentStyle>) {
+        let mut style = component.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+        let state = IrcState::of(&style);
+
+        let text = match component.get_kind() {
+            ComponentType::Text(text) => strip_codes(text.get_text()),
+            ComponentType::Translation(translation) => {
+                // every argument starts and ends in the translation's formatting
+                let args: Vec<String> = translation
+                    .get_args()
+                    .iter()
+                    .map(|arg| {
+                        let mut writer = IrcWriter {
+                            out: String::new(),
+                            current: state,
+                            after_color: false,
+                        };
+                        writer.write(arg, Some(&style));
+                        writer.switch_to(state);
+                        writer.out
+                    })
+                    .collect();
+                format_translation(&strip_codes(translation.get_key()), &args)
+            }
+            ComponentType::Score(score) => strip_codes(score.get_value().unwrap_or_default()),
+            ComponentType::Selector(selector) => strip_codes(selector.get_selector()),
+            ComponentType::Keybind(keybind) => strip_codes(
+                default_key_name(keybind.get_keybind()).unwrap_or(keybind.get_keybind()),
+            ),
+            ComponentType::Nbt(_) => String::new(),
+            ComponentType::Custom(custom) => strip_codes(&custom.plain_text()),
+        };
+        if !text.is_empty() {
+            self.switch_to(state);
+            self.push_text(&text);
+        }
+        for sibling in component.get_siblings() {
+            self.write(sibling, Some(&style));
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Renders this component with mIRC formatting codes for IRC, colors are changed
+    /// to the closest of the 16 colors of mIRC's palette.
+    ///
+    /// Formatting codes in the text are removed. Since IRC resets the formatting
+    /// at the end of each message, the output doesn't end with a reset.
+    pub fn to_irc(&self) -> String {
+        let mut writer = IrcWriter {
+            out: String::new(),
+            current: IrcState::default(),
+            after_color: false,
+        };
+        writer.write(self, None);
+        writer.out
+    }
+}
@@ -0,0 +1,33 @@
+//! Glyph widths of the default Minecraft font.
+
+/// The amount of pixels between two glyphs.
+const SPACING: u32 = 1;
+
+/// The width of glyphs not listed in [`glyph_width()`], most unicode glyphs are close to it.
+const DEFAULT_WIDTH: u32 = 5;
+
+/// The width (in pixels, without spacing) of a glyph of the default font.
+fn glyph_width(c: char) -> u32 {
+    match c {
+        ' ' => 3,
+        '!' | '\'' | ',' | '.' | ':' | ';' | 'i' | 'l' | '|' => 1,
+        '`' => 2,
+        '"' | 'I' | '[' | ']' | 't' => 3,
+        '(' | ')' | '*' | '<' | '>' | 'f' | 'k' | '{' | '}' => 4,
+        '@' | '~' => 6,
+        _ => DEFAULT_WIDTH,
+    }
+}
+
+/// The amount of pixels the client advances after drawing `c`, bold glyphs are one pixel wider.
+pub fn char_width(c: char, bold: bool) -> u32 {
+    match c {
+        '\n' => 0,
+        _ => glyph_width(c) + SPACING + bold as u32,
+    }
+}
+
+/// The width (in pixels) of a single line of text, see [`char_width()`].
+pub fn text_width(text: &str, bold: bool) -> u32 {
+    text.chars().map(|c| char_width(c, bold)).sum()
+}
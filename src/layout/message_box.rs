@@ -0,0 +1,147 @@
+use crate::component::ChatComponent;
+use crate::layout::{join, padding, separator, separator_of, split_lines, width, CHAT_WIDTH};
+use crate::style::{ChatColor, ComponentStyle};
+
+/// Frames content between two separator lines, with an optional title
+/// centered in the top line and padding in front of every line.
+///
+/// ```text
+/// ------------ Title ------------
+///   first line of content
+///   second line of content
+/// -------------------------------
+/// ```
+#[derive(Clone, Debug)]
+pub struct MessageBox {
+    content: ChatComponent,
+    title: Option<ChatComponent>,
+    width: u32,
+    padding: u32,
+    border: Option<char>,
+    border_color: Option<ChatColor>,
+}
+
+impl MessageBox {
+    /// A box as wide as the chat, with 8 pixels of padding and strikethrough borders.
+    pub fn new(content: ChatComponent) -> Self {
+        MessageBox {
+            content,
+            title: None,
+            width: CHAT_WIDTH,
+            padding: 8,
+            border: None,
+            border_color: None,
+        }
+    }
+
+    pub fn get_content(&self) -> &ChatComponent {
+        &self.content
+    }
+
+    pub fn get_title(&self) -> Option<&ChatComponent> {
+        self.title.as_ref()
+    }
+
+    pub fn set_title(&mut self, title: Option<ChatComponent>) {
+        self.title = title;
+    }
+
+    pub fn title(mut self, title: Option<ChatComponent>) -> Self {
+        self.set_title(title);
+        self
+    }
+
+    pub fn get_width(&self) -> u32 {
+        self.width
+    }
+
+    /// Sets the width (in pixels) of the borders.
+    pub fn set_width(&mut self, width: u32) {
+        self.width = width;
+    }
+
+    pub fn width(mut self, width: u32) -> Self {
+        self.set_width(width);
+        self
+    }
+
+    pub fn get_padding(&self) -> u32 {
+        self.padding
+    }
+
+    /// Sets the space (in pixels) in front of every line of content.
+    pub fn set_padding(&mut self, padding: u32) {
+        self.padding = padding;
+    }
+
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.set_padding(padding);
+        self
+    }
+
+    pub fn get_border(&self) -> Option<char> {
+        self.border
+    }
+
+    /// Sets the character the borders are made of, [`None`] draws them with strikethrough spaces.
+    pub fn set_border(&mut self, border: Option<char>) {
+        self.border = border;
+    }
+
+    pub fn border(mut self, border: Option<char>) -> Self {
+        self.set_border(border);
+        self
+    }
+
+    pub fn get_border_color(&self) -> Option<&ChatColor> {
+        self.border_color.as_ref()
+    }
+
+    pub fn set_border_color(&mut self, border_color: Option<ChatColor>) {
+        self.border_color = border_color;
+    }
+
+    pub fn border_color(mut self, border_color: Option<ChatColor>) -> Self {
+        self.set_border_color(border_color);
+        self
+    }
+
+    fn line(&self, pixels: u32) -> ChatComponent {
+        let version = self.content.get_version();
+        match self.border {
+            Some(c) => separator_of(c, pixels, self.border_color.clone(), version),
+            None => separator(pixels, self.border_color.clone(), version),
+        }
+    }
+
+    /// Builds the framed message.
+    pub fn build(&self) -> ChatComponent {
+        let version = self.content.get_version();
+        let newline = || ChatComponent::from_text("\n", ComponentStyle::with_version(version));
+        let mut parts = vec![];
+
+        match &self.title {
+            Some(title) => {
+                // one space on each side of the title
+                let gap = || ChatComponent::from_text(" ", ComponentStyle::with_version(version));
+                let free = self.width.saturating_sub(width(title) + 8);
+                parts.push(self.line(free / 2));
+                parts.push(gap());
+                parts.push(title.clone());
+                parts.push(gap());
+                parts.push(self.line(free - free / 2));
+            }
+            None => parts.push(self.line(self.width)),
+        }
+
+        for line in split_lines(&self.content) {
+            parts.push(newline());
+            parts.push(padding(self.padding, version));
+            parts.push(line);
+        }
+
+        parts.push(newline());
+        parts.push(self.line(self.width));
+        join(parts, version)
+    }
+}
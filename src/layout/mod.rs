@@ -0,0 +1,191 @@
+//! Measuring and arranging components for the default font, e.g. to build menus
+//! or columns in chat.
+//!
+//! Translations are measured by their key, as the client's translation isn't known.
+
+mod font;
+mod message_box;
+mod split;
+mod wrap;
+
+pub use font::{char_width, text_width};
+pub use message_box::MessageBox;
+pub use split::{message_length_limit, split_message};
+pub use wrap::{wrap, wrap_hover_texts, wrap_lines, TOOLTIP_WIDTH};
+
+use crate::component::{ChatComponent, ComponentType};
+use crate::style::{ChatColor, ComponentStyle};
+
+/// The width (in pixels) of the chat with default settings.
+pub const CHAT_WIDTH: u32 = 320;
+
+/// Where a line is placed inside of a wider area.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// The text of a single component of a resolved tree, without its siblings.
+fn own_text(component: &ChatComponent) -> String {
+    match component.get_kind() {
+        ComponentType::Text(text) => text.get_text().to_string(),
+        ComponentType::Translation(translation) => translation.get_key().to_string(),
+        ComponentType::Score(score) => score.get_value().unwrap_or_default().to_string(),
+        ComponentType::Selector(selector) => selector.get_selector().to_string(),
+        ComponentType::Keybind(keybind) => crate::default_key_name(keybind.get_keybind())
+            .unwrap_or(keybind.get_keybind())
+            .to_string(),
+        ComponentType::Nbt(_) => String::new(),
+        ComponentType::Custom(custom) => custom.plain_text(),
+    }
+}
+
+/// Flattens `component` into components without siblings, each carrying its resolved style.
+fn runs(component: &ChatComponent) -> Vec<ChatComponent> {
+    component
+        .resolved_styles()
+        .into_iter()
+        .filter_map(|(node, style)| match node.get_kind() {
+            ComponentType::Text(text) if text.get_text().is_empty() => None,
+            kind => Some(ChatComponent::from_component(kind.clone(), style)),
+        })
+        .collect()
+}
+
+fn run_width(run: &ChatComponent) -> u32 {
+    text_width(&own_text(run), run.get_bold().unwrap_or(false))
+}
+
+/// Joins components without inheriting any style between them.
+fn join(parts: Vec<ChatComponent>, version: u32) -> ChatComponent {
+    let mut joined = ChatComponent::from_text("", ComponentStyle::with_version(version));
+    *joined.get_siblings_mut() = parts;
+    joined
+}
+
+/// The width (in pixels) of the widest line of `component`.
+pub fn width(component: &ChatComponent) -> u32 {
+    split_lines(component)
+        .iter()
+        .map(|line| runs(line).iter().map(run_width).sum())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Splits `component` at every line break, every line keeps the styles resolved
+/// from the original tree.
+pub fn split_lines(component: &ChatComponent) -> Vec<ChatComponent> {
+    let version = component.get_version();
+    let mut lines = vec![];
+    let mut line = vec![];
+    for run in runs(component) {
+        let text = match run.get_kind() {
+            ComponentType::Text(text) if text.get_text().contains('\n') => {
+                text.get_text().to_string()
+            }
+            _ => {
+                line.push(run);
+                continue;
+            }
+        };
+        let mut parts = text.split('\n');
+        let push_part = |line: &mut Vec<ChatComponent>, part: &str| {
+            if !part.is_empty() {
+                line.push(ChatComponent::from_text(part, run.get_style().clone()));
+            }
+        };
+        push_part(&mut line, parts.next().unwrap_or_default());
+        for part in parts {
+            lines.push(join(std::mem::take(&mut line), version));
+            push_part(&mut line, part);
+        }
+    }
+    lines.push(join(line, version));
+    lines
+}
+
+/// Repeats `c` to fill as much of `pixels` as possible, using bold glyphs
+/// (one pixel wider) to make up the difference.
+fn fill(c: char, pixels: u32, style: ComponentStyle) -> ChatComponent {
+    let width = char_width(c, false);
+    if width == 0 {
+        return ChatComponent::from_text("", style);
+    }
+    let (regular, bold) = (0..=pixels / (width + 1))
+        .map(|bold| ((pixels - bold * (width + 1)) / width, bold))
+        .max_by_key(|(regular, bold)| regular * width + bold * (width + 1))
+        .unwrap_or((0, 0));
+    let version = style.get_version();
+    let mut filled = ChatComponent::from_text(c.to_string().repeat(regular as usize), style);
+    if bold > 0 {
+        filled.get_siblings_mut().push(ChatComponent::from_text(
+            c.to_string().repeat(bold as usize),
+            ComponentStyle::with_version(version).bold(true),
+        ));
+    }
+    filled
+}
+
+/// Builds unstyled whitespace as close to (but not wider than) `pixels` as possible.
+///
+/// Spaces are 4 pixels wide and bold ones 5, every width from 12 pixels on is exact.
+pub fn padding(pixels: u32, version: u32) -> ChatComponent {
+    fill(' ', pixels, ComponentStyle::with_version(version))
+}
+
+/// Builds a horizontal line `pixels` wide out of strikethrough spaces,
+/// e.g. to frame menus. Use [`CHAT_WIDTH`] for a line across the whole chat.
+pub fn separator(pixels: u32, color: Option<ChatColor>, version: u32) -> ChatComponent {
+    fill(
+        ' ',
+        pixels,
+        ComponentStyle::with_version(version)
+            .color(color)
+            .strikethrough(true),
+    )
+}
+
+/// Builds a line `pixels` wide out of `c` (e.g. `'-'` or `'='`), the last few
+/// pixels are filled with bold characters if needed.
+pub fn separator_of(c: char, pixels: u32, color: Option<ChatColor>, version: u32) -> ChatComponent {
+    fill(
+        c,
+        pixels,
+        ComponentStyle::with_version(version).color(color),
+    )
+}
+
+/// Places a single line inside of an area `width` pixels wide by padding it with spaces.
+///
+/// Lines wider than the area are left as they are.
+pub fn align(line: &ChatComponent, width: u32, alignment: Alignment) -> ChatComponent {
+    let version = line.get_version();
+    let free = width.saturating_sub(self::width(line));
+    let before = match alignment {
+        Alignment::Left => 0,
+        Alignment::Center => free / 2,
+        Alignment::Right => free,
+    };
+    if before == 0 {
+        return line.clone();
+    }
+    join(vec![padding(before, version), line.clone()], version)
+}
+
+/// Aligns every line of a multi-line component separately, see [`align()`].
+pub fn align_lines(component: &ChatComponent, width: u32, alignment: Alignment) -> ChatComponent {
+    let version = component.get_version();
+    let mut lines = vec![];
+    for (i, line) in split_lines(component).iter().enumerate() {
+        if i > 0 {
+            lines.push(ChatComponent::from_text(
+                "\n",
+                ComponentStyle::with_version(version),
+            ));
+        }
+        lines.push(align(line, width, alignment));
+    }
+    join(lines, version)
+}
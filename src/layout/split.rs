@@ -0,0 +1,61 @@
+use crate::component::{ChatComponent, ComponentType};
+use crate::layout::wrap::wrap_lines_by;
+
+const VERSION_1_11: u32 = 315;
+#[cfg(feature = "json")]
+const VERSION_1_13: u32 = 393;
+
+/// The number of characters a chat message may have in the given version.
+pub fn message_length_limit(version: u32) -> usize {
+    if version < VERSION_1_11 {
+        100
+    } else {
+        256
+    }
+}
+
+/// The number of characters the JSON of a chat packet may have in the given version.
+#[cfg(feature = "json")]
+fn json_length_limit(version: u32) -> usize {
+    if version < VERSION_1_13 {
+        32767
+    } else {
+        262144
+    }
+}
+
+fn char_count(run: &ChatComponent, text: &str) -> u32 {
+    let count = match run.get_kind() {
+        ComponentType::Text(_) => text.chars().count(),
+        _ => super::own_text(run).chars().count(),
+    };
+    count as u32
+}
+
+/// Splits `component` into messages ready to be sent to a client of `version`,
+/// each downgraded and within the client's limits (see [`message_length_limit()`]).
+///
+/// Messages are split between words where possible and at existing line breaks,
+/// every part keeps the styles and events resolved from the original tree.
+pub fn split_message(component: &ChatComponent, version: u32) -> Vec<ChatComponent> {
+    split_into(component, message_length_limit(version), version)
+}
+
+fn split_into(component: &ChatComponent, max_chars: usize, version: u32) -> Vec<ChatComponent> {
+    let mut messages = vec![];
+    for mut message in wrap_lines_by(component, max_chars as u32, char_count) {
+        message.downgrade(version);
+        // long events can make a short message too large, those are split further
+        #[cfg(feature = "json")]
+        if max_chars > 1 {
+            let json =
+                serde_json::to_string(&message).expect("components always serialize to JSON");
+            if json.chars().count() > json_length_limit(version) {
+                messages.extend(split_into(&message, max_chars / 2, version));
+                continue;
+            }
+        }
+        messages.push(message);
+    }
+    messages
+}
@@ -0,0 +1,241 @@
+use crate::component::{ChatComponent, ComponentType};
+use crate::layout::{join, runs, split_lines, text_width};
+use crate::style::{ComponentStyle, HoverEvent};
+
+/// The width (in pixels) hover texts are wrapped at by the client on small screens,
+/// larger screens allow up to half of their width.
+pub const TOOLTIP_WIDTH: u32 = 200;
+
+/// A part of a run, only text runs are ever split.
+struct Piece {
+    run: usize,
+    text: String,
+}
+
+enum Token {
+    Space(Piece),
+    Word(Vec<Piece>),
+}
+
+/// Measures a piece of text (or a whole non-text run) of a run.
+pub(super) type Measure = fn(&ChatComponent, &str) -> u32;
+
+fn pixel_width(run: &ChatComponent, text: &str) -> u32 {
+    match run.get_kind() {
+        ComponentType::Text(_) => text_width(text, run.get_bold().unwrap_or(false)),
+        _ => super::run_width(run),
+    }
+}
+
+struct Wrapper<'a> {
+    runs: &'a [ChatComponent],
+    measure: Measure,
+    max_width: u32,
+    lines: Vec<Vec<Piece>>,
+    line: Vec<Piece>,
+    line_width: u32,
+}
+
+impl Wrapper<'_> {
+    fn width(&self, piece: &Piece) -> u32 {
+        (self.measure)(&self.runs[piece.run], &piece.text)
+    }
+
+    fn break_line(&mut self) {
+        self.lines.push(std::mem::take(&mut self.line));
+        self.line_width = 0;
+    }
+
+    fn push(&mut self, piece: Piece) {
+        self.line_width += self.width(&piece);
+        self.line.push(piece);
+    }
+
+    /// Breaks a word that doesn't fit on a line of its own between characters
+    /// (grapheme clusters with the `unicode-segmentation` feature).
+    fn push_split(&mut self, word: Vec<Piece>) {
+        for piece in word {
+            let parts = match self.runs[piece.run].get_kind() {
+                ComponentType::Text(_) => crate::text::units(&piece.text)
+                    .map(|unit| Piece {
+                        run: piece.run,
+                        text: unit.to_string(),
+                    })
+                    .collect(),
+                _ => vec![piece],
+            };
+            for part in parts {
+                if !self.line.is_empty() && self.line_width + self.width(&part) > self.max_width {
+                    self.break_line();
+                }
+                self.push(part);
+            }
+        }
+    }
+
+    fn push_word(&mut self, spaces: Vec<Piece>, word: Vec<Piece>) {
+        let space_width: u32 = spaces.iter().map(|piece| self.width(piece)).sum();
+        let word_width: u32 = word.iter().map(|piece| self.width(piece)).sum();
+        if !self.line.is_empty() && self.line_width + space_width + word_width > self.max_width {
+            // spaces at a line break are dropped like the client does
+            self.break_line();
+        } else {
+            for space in spaces {
+                self.push(space);
+            }
+        }
+        if self.line_width + word_width > self.max_width {
+            self.push_split(word);
+        } else {
+            for piece in word {
+                self.push(piece);
+            }
+        }
+    }
+
+    /// Builds a line out of its pieces, merging consecutive pieces of the same run again.
+    fn build_line(&self, pieces: Vec<Piece>, version: u32) -> ChatComponent {
+        let mut merged: Vec<Piece> = vec![];
+        for piece in pieces {
+            match merged.last_mut() {
+                Some(last)
+                    if last.run == piece.run
+                        && matches!(self.runs[piece.run].get_kind(), ComponentType::Text(_)) =>
+                {
+                    last.text.push_str(&piece.text)
+                }
+                _ => merged.push(piece),
+            }
+        }
+        let parts = merged
+            .into_iter()
+            .map(|piece| {
+                let run = &self.runs[piece.run];
+                match run.get_kind() {
+                    ComponentType::Text(_) => {
+                        ChatComponent::from_text(piece.text, run.get_style().clone())
+                    }
+                    _ => run.clone(),
+                }
+            })
+            .collect();
+        join(parts, version)
+    }
+}
+
+fn tokenize(runs: &[ChatComponent]) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut word = vec![];
+    for (i, run) in runs.iter().enumerate() {
+        let text = match run.get_kind() {
+            ComponentType::Text(text) => text.get_text(),
+            _ => {
+                word.push(Piece {
+                    run: i,
+                    text: String::new(),
+                });
+                continue;
+            }
+        };
+        let mut current = String::new();
+        for c in text.chars() {
+            if c == ' ' {
+                if !current.is_empty() {
+                    word.push(Piece {
+                        run: i,
+                        text: std::mem::take(&mut current),
+                    });
+                }
+                if !word.is_empty() {
+                    tokens.push(Token::Word(std::mem::take(&mut word)));
+                }
+                tokens.push(Token::Space(Piece {
+                    run: i,
+                    text: " ".to_string(),
+                }));
+            } else {
+                current.push(c);
+            }
+        }
+        if !current.is_empty() {
+            word.push(Piece {
+                run: i,
+                text: current,
+            });
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(Token::Word(word));
+    }
+    tokens
+}
+
+/// Splits `component` into lines at most `max_width` pixels wide, breaking between
+/// words where possible like the client does. Existing line breaks are kept.
+pub fn wrap_lines(component: &ChatComponent, max_width: u32) -> Vec<ChatComponent> {
+    wrap_lines_by(component, max_width, pixel_width)
+}
+
+/// Like [`wrap_lines()`], measuring with `measure` instead of in pixels.
+pub(super) fn wrap_lines_by(
+    component: &ChatComponent,
+    max_width: u32,
+    measure: Measure,
+) -> Vec<ChatComponent> {
+    let version = component.get_version();
+    let mut lines = vec![];
+    for line in split_lines(component) {
+        let runs = runs(&line);
+        let mut wrapper = Wrapper {
+            runs: &runs,
+            measure,
+            max_width,
+            lines: vec![],
+            line: vec![],
+            line_width: 0,
+        };
+        let mut spaces = vec![];
+        for token in tokenize(&runs) {
+            match token {
+                Token::Space(space) => spaces.push(space),
+                Token::Word(word) => wrapper.push_word(std::mem::take(&mut spaces), word),
+            }
+        }
+        wrapper.break_line();
+        let pieces = std::mem::take(&mut wrapper.lines);
+        lines.extend(
+            pieces
+                .into_iter()
+                .map(|pieces| wrapper.build_line(pieces, version)),
+        );
+    }
+    lines
+}
+
+/// Wraps `component` into lines at most `max_width` pixels wide, see [`wrap_lines()`].
+pub fn wrap(component: &ChatComponent, max_width: u32) -> ChatComponent {
+    let version = component.get_version();
+    let mut parts = vec![];
+    for (i, line) in wrap_lines(component, max_width).into_iter().enumerate() {
+        if i > 0 {
+            parts.push(ChatComponent::from_text(
+                "\n",
+                ComponentStyle::with_version(version),
+            ));
+        }
+        parts.push(line);
+    }
+    join(parts, version)
+}
+
+/// Pre-wraps every hover text in `component` (see [`wrap()`]), so tooltips break
+/// into the same lines on every client instead of depending on its screen size.
+///
+/// [`TOOLTIP_WIDTH`] matches the narrowest tooltips of vanilla clients.
+pub fn wrap_hover_texts(component: &mut ChatComponent, max_width: u32) {
+    component.walk_mut(&mut |component| {
+        if let Some(HoverEvent::ShowText(text)) = component.get_hover_event_mut() {
+            **text = wrap(text, max_width);
+        }
+    });
+}
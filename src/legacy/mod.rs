@@ -0,0 +1,10 @@
+//! Legacy text, formatted with `§` codes like `§aGreen §lbold`.
+
+mod options;
+mod parser;
+mod serializer;
+
+pub use options::{HexFormat, LegacyOptions, UnknownCodes};
+pub use parser::LegacyParser;
+#[cfg(feature = "json")]
+pub(crate) use serializer::LegacyWriter;
@@ -0,0 +1,110 @@
+use crate::style::ChatFormatting;
+
+/// What happens to codes that aren't a known formatting, like `§z`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UnknownCodes {
+    /// The code is removed from the text, like clients do.
+    Drop,
+    /// The code is kept as text, e.g. for configs where `&` is also used literally.
+    Keep,
+}
+
+/// How hex colors are written in legacy text, both are read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HexFormat {
+    /// `§#FF8800`, common in plugin configs as `&#FF8800`.
+    Inline,
+    /// `§x§f§f§8§8§0§0`, sent by BungeeCord and Spigot.
+    BungeeCord,
+}
+
+/// Options for converting from and to legacy text, e.g. to use Bukkit-style `&` codes.
+#[derive(Clone, Debug)]
+pub struct LegacyOptions {
+    character: char,
+    hex: bool,
+    hex_format: HexFormat,
+    unknown_codes: UnknownCodes,
+}
+
+impl Default for LegacyOptions {
+    /// `§` codes like clients read them: without hex colors, dropping unknown codes.
+    fn default() -> Self {
+        LegacyOptions {
+            character: ChatFormatting::SECTION_SIGN,
+            hex: false,
+            hex_format: HexFormat::BungeeCord,
+            unknown_codes: UnknownCodes::Drop,
+        }
+    }
+}
+
+impl LegacyOptions {
+    pub fn new() -> Self {
+        LegacyOptions::default()
+    }
+
+    /// `&` codes as used in plugin configs, with `&#RRGGBB` hex colors.
+    pub fn ampersand() -> Self {
+        LegacyOptions::default()
+            .character('&')
+            .hex(true)
+            .hex_format(HexFormat::Inline)
+    }
+
+    pub fn get_character(&self) -> char {
+        self.character
+    }
+
+    /// Sets the character starting a code, `§` by default.
+    pub fn set_character(&mut self, character: char) {
+        self.character = character;
+    }
+
+    pub fn character(mut self, character: char) -> Self {
+        self.set_character(character);
+        self
+    }
+
+    pub fn get_hex(&self) -> bool {
+        self.hex
+    }
+
+    /// Sets whether custom colors are read and written as hex codes (see [`HexFormat`]).
+    /// Without them, custom colors are written as the nearest of the 16 colors.
+    pub fn set_hex(&mut self, hex: bool) {
+        self.hex = hex;
+    }
+
+    pub fn hex(mut self, hex: bool) -> Self {
+        self.set_hex(hex);
+        self
+    }
+
+    pub fn get_hex_format(&self) -> HexFormat {
+        self.hex_format
+    }
+
+    /// Sets how hex colors are written, [`HexFormat::BungeeCord`] by default.
+    pub fn set_hex_format(&mut self, hex_format: HexFormat) {
+        self.hex_format = hex_format;
+    }
+
+    pub fn hex_format(mut self, hex_format: HexFormat) -> Self {
+        self.set_hex_format(hex_format);
+        self
+    }
+
+    pub fn get_unknown_codes(&self) -> UnknownCodes {
+        self.unknown_codes
+    }
+
+    pub fn set_unknown_codes(&mut self, unknown_codes: UnknownCodes) {
+        self.unknown_codes = unknown_codes;
+    }
+
+    pub fn unknown_codes(mut self, unknown_codes: UnknownCodes) -> Self {
+        self.set_unknown_codes(unknown_codes);
+        self
+    }
+}
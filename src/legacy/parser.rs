@@ -0,0 +1,237 @@
+use crate::component::{ChatComponent, ComponentType, TextComponent};
+use crate::legacy::{LegacyOptions, UnknownCodes};
+use crate::style::{ChatColor, ChatFormatting, ComponentStyle};
+
+/// The color of 6 hex digits, which the parser checked already.
+fn hex_color(hex: &str) -> ChatColor {
+    let rgb = u32::from_str_radix(hex, 16).unwrap_or_default();
+    ChatColor::rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+}
+
+/// A push-based parser turning legacy text into components, for input arriving
+/// in chunks like console streams or log files.
+///
+/// Every component returned has the style of its run of text: colors reset the
+/// decorations before them (like legacy clients do), `§r` resets everything and
+/// unknown codes are dropped (see [`LegacyOptions`]). Codes split across chunks are handled.
+#[derive(Clone, Debug)]
+pub struct LegacyParser {
+    version: u32,
+    options: LegacyOptions,
+    style: ComponentStyle,
+    text: String,
+    /// The characters of the code the last chunk ended in, if any
+    pending_code: Option<String>,
+}
+
+impl LegacyParser {
+    /// Creates a parser producing components of the given version.
+    pub fn new(version: u32) -> Self {
+        LegacyParser::with_options(version, LegacyOptions::default())
+    }
+
+    /// Creates a parser reading codes as configured by `options`.
+    pub fn with_options(version: u32, options: LegacyOptions) -> Self {
+        LegacyParser {
+            version,
+            options,
+            style: ComponentStyle::with_version(version),
+            text: String::new(),
+            pending_code: None,
+        }
+    }
+
+    /// Parses the next chunk of input, returning the components completed so far.
+    pub fn feed(&mut self, input: &str) -> Vec<ChatComponent> {
+        let mut components = vec![];
+        for c in input.chars() {
+            self.read(c, &mut components);
+        }
+        self.flush(&mut components);
+        components
+    }
+
+    /// Ends the input, returning the last components. An unfinished code at the end is
+    /// handled like an unknown one.
+    pub fn finish(mut self) -> Vec<ChatComponent> {
+        let mut components = vec![];
+        if let Some(code) = self.pending_code.take() {
+            self.unknown(&code);
+        }
+        self.flush(&mut components);
+        components
+    }
+
+    /// The style of the text read next.
+    #[cfg(feature = "json")]
+    pub(crate) fn get_style(&self) -> &ComponentStyle {
+        &self.style
+    }
+
+    #[cfg(feature = "json")]
+    pub(crate) fn set_style(&mut self, style: ComponentStyle) {
+        self.style = style;
+    }
+
+    fn read(&mut self, c: char, components: &mut Vec<ChatComponent>) {
+        let mut code = match self.pending_code.take() {
+            Some(code) => code,
+            None if c == self.options.get_character() => {
+                self.pending_code = Some(String::new());
+                return;
+            }
+            None => {
+                self.text.push(c);
+                return;
+            }
+        };
+        code.push(c);
+        if self.options.get_hex() && code.starts_with('x') {
+            self.read_bungee_hex(code, components);
+            return;
+        }
+        if self.options.get_hex() && code.starts_with('#') {
+            if code.len() == 1 || (c.is_ascii_hexdigit() && code.len() < 7) {
+                self.pending_code = Some(code);
+            } else if c.is_ascii_hexdigit() {
+                self.flush(components);
+                self.style = ComponentStyle::with_version(self.version);
+                self.style.set_color(Some(hex_color(&code[1..])));
+            } else {
+                // not a hex color after all, everything after `#` is text again
+                self.unknown("#");
+                for c in code[1..].chars() {
+                    self.read(c, components);
+                }
+            }
+            return;
+        }
+        match ChatFormatting::from_code(c) {
+            Some(formatting) => {
+                self.flush(components);
+                self.apply(formatting);
+            }
+            None => self.unknown(&code),
+        }
+    }
+
+    /// Reads a code like `§x§f§f§8§8§0§0`, every digit is preceded by the code character.
+    fn read_bungee_hex(&mut self, code: String, components: &mut Vec<ChatComponent>) {
+        let length = code.chars().count();
+        let c = code.chars().last().unwrap_or_default();
+        let valid = length == 1
+            || if length.is_multiple_of(2) {
+                c == self.options.get_character()
+            } else {
+                c.is_ascii_hexdigit()
+            };
+        if !valid {
+            // not a hex color after all, everything after `x` is read again
+            self.unknown("x");
+            for c in code.chars().skip(1) {
+                self.read(c, components);
+            }
+        } else if length < 13 {
+            self.pending_code = Some(code);
+        } else {
+            let hex: String = code.chars().skip(2).step_by(2).collect();
+            self.flush(components);
+            self.style = ComponentStyle::with_version(self.version);
+            self.style.set_color(Some(hex_color(&hex)));
+        }
+    }
+
+    fn unknown(&mut self, code: &str) {
+        if self.options.get_unknown_codes() == UnknownCodes::Keep {
+            self.text.push(self.options.get_character());
+            self.text.push_str(code);
+        }
+    }
+
+    fn apply(&mut self, formatting: ChatFormatting) {
+        if formatting.is_color() || formatting == ChatFormatting::Reset {
+            self.style = ComponentStyle::with_version(self.version);
+        }
+        if formatting != ChatFormatting::Reset {
+            formatting.apply_to(&mut self.style);
+        }
+    }
+
+    fn flush(&mut self, components: &mut Vec<ChatComponent>) {
+        if !self.text.is_empty() {
+            let text = std::mem::take(&mut self.text);
+            components.push(ChatComponent::from_text(text, self.style.clone()));
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Parses legacy text formatted with `§` codes, like `§aGreen §lbold`, into components
+    /// of the given version, see [`LegacyParser`] for how codes are applied.
+    ///
+    /// Every run of text becomes a sibling of an empty root component, unless there's only one.
+    pub fn from_legacy_text(text: &str, version: u32) -> ChatComponent {
+        ChatComponent::from_legacy_text_with(text, version, &LegacyOptions::default())
+    }
+
+    /// Parses legacy text with the codes configured by `options`, e.g. `&aGreen`,
+    /// see [`ChatComponent::from_legacy_text()`].
+    pub fn from_legacy_text_with(
+        text: &str,
+        version: u32,
+        options: &LegacyOptions,
+    ) -> ChatComponent {
+        let mut parser = LegacyParser::with_options(version, options.clone());
+        let mut runs: Vec<ChatComponent> = vec![];
+        for run in parser.feed(text).into_iter().chain(parser.finish()) {
+            // a code kept at the end is returned on its own
+            match runs.last_mut() {
+                Some(last) if last.get_style() == run.get_style() => {
+                    if let (ComponentType::Text(last), ComponentType::Text(run)) =
+                        (last.get_kind_mut(), run.get_kind())
+                    {
+                        let text = format!("{}{}", last.get_text(), run.get_text());
+                        last.set_text(text);
+                    }
+                }
+                _ => runs.push(run),
+            }
+        }
+        if runs.len() == 1 {
+            return runs.remove(0);
+        }
+        let mut component = ChatComponent::from_text("", ComponentStyle::with_version(version));
+        *component.get_siblings_mut() = runs;
+        component
+    }
+
+    /// Replaces legacy codes inside the texts of this component and its children
+    /// (e.g. `{"text":"§aHi"}`, which clients still display formatted) with styles,
+    /// the codes of a text only apply to that text.
+    pub fn expand_legacy_codes(&mut self) {
+        self.walk_mut(&mut |component| {
+            let (text, version) = match component.get_kind() {
+                ComponentType::Text(text) if text.get_text().contains('§') => {
+                    (text.get_text().to_string(), component.get_version())
+                }
+                _ => return,
+            };
+            let mut parsed = ChatComponent::from_legacy_text(&text, version);
+            let mut runs = std::mem::take(parsed.get_siblings_mut());
+            if runs.is_empty() {
+                runs.push(parsed);
+            }
+            if runs.len() == 1 {
+                let run = runs.remove(0);
+                let mut style = run.get_style().clone();
+                style.inherit(component.get_style());
+                *component.get_kind_mut() = run.get_kind().clone();
+                *component.get_style_mut() = style;
+            } else {
+                *component.get_kind_mut() = ComponentType::Text(TextComponent::from_text(""));
+                let siblings = component.get_siblings_mut();
+                siblings.splice(0..0, runs);
+            }
+        });
+    }
+}
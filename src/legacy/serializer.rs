@@ -0,0 +1,185 @@
+use crate::component::{default_key_name, format_translation, ChatComponent, ComponentType};
+use crate::legacy::{HexFormat, LegacyOptions};
+use crate::style::{ChatColor, ChatFormatting, ComponentStyle};
+
+/// The formatting legacy text has at some point: a color and the enabled decorations.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct LegacyState {
+    color: Option<ChatColor>,
+    decorations: Vec<ChatFormatting>,
+}
+
+impl LegacyState {
+    fn of(style: &ComponentStyle, options: &LegacyOptions) -> Self {
+        let decorations = [
+            (style.get_obfuscated(), ChatFormatting::Obfuscated),
+            (style.get_bold(), ChatFormatting::Bold),
+            (style.get_strikethrough(), ChatFormatting::Strikethrough),
+            (style.get_underlined(), ChatFormatting::Underlined),
+            (style.get_italic(), ChatFormatting::Italic),
+        ];
+        LegacyState {
+            // `§r` is the default color, custom ones need hex codes
+            color: style.get_color().and_then(|color| match color {
+                ChatColor::Reset => None,
+                ChatColor::Rgb(..) if options.get_hex() => Some(color.clone()),
+                _ => color.to_nearest_legacy(),
+            }),
+            decorations: decorations
+                .iter()
+                .filter(|(value, _)| *value == Some(true))
+                .map(|(_, decoration)| *decoration)
+                .collect(),
+        }
+    }
+}
+
+pub(crate) struct LegacyWriter<'a> {
+    options: &'a LegacyOptions,
+    out: String,
+    state: LegacyState,
+}
+
+impl<'a> LegacyWriter<'a> {
+    /// Creates a writer whose text starts in the formatting of `style`, if any.
+    pub(crate) fn new(options: &'a LegacyOptions, style: Option<&ComponentStyle>) -> Self {
+        LegacyWriter {
+            options,
+            out: String::new(),
+            state: style.map_or_else(LegacyState::default, |style| {
+                LegacyState::of(style, options)
+            }),
+        }
+    }
+
+    #[cfg(feature = "json")]
+    pub(crate) fn options(&self) -> &'a LegacyOptions {
+        self.options
+    }
+
+    /// Returns the codes changing the current formatting to the one of `style`.
+    #[cfg(feature = "json")]
+    pub(crate) fn codes_to(&mut self, style: &ComponentStyle) -> String {
+        let start = self.out.len();
+        self.switch_to(LegacyState::of(style, self.options));
+        self.out.split_off(start)
+    }
+
+    fn push_code(&mut self, formatting: ChatFormatting) {
+        self.out.push(self.options.get_character());
+        self.out.push(formatting.get_code());
+    }
+
+    fn push_color(&mut self, color: Option<&ChatColor>) {
+        match color.map(|color| (ChatFormatting::from_color(color), color.to_rgb())) {
+            Some((Some(formatting), _)) => self.push_code(formatting),
+            Some((None, Some((red, green, blue)))) => {
+                let hex = format!("{:02x}{:02x}{:02x}", red, green, blue);
+                let character = self.options.get_character();
+                match self.options.get_hex_format() {
+                    HexFormat::Inline => {
+                        self.out.push(character);
+                        self.out.push('#');
+                        self.out.push_str(&hex.to_ascii_uppercase());
+                    }
+                    HexFormat::BungeeCord => {
+                        self.out.push(character);
+                        self.out.push('x');
+                        for digit in hex.chars() {
+                            self.out.push(character);
+                            self.out.push(digit);
+                        }
+                    }
+                }
+            }
+            _ => self.push_code(ChatFormatting::Reset),
+        }
+    }
+
+    /// Writes the codes changing the current formatting to `target`. Colors reset
+    /// every decoration, so decorations are only added on their own.
+    fn switch_to(&mut self, target: LegacyState) {
+        if target == self.state {
+            return;
+        }
+        let added = target.color == self.state.color
+            && self
+                .state
+                .decorations
+                .iter()
+                .all(|decoration| target.decorations.contains(decoration));
+        if added {
+            for decoration in &target.decorations {
+                if !self.state.decorations.contains(decoration) {
+                    self.push_code(*decoration);
+                }
+            }
+        } else {
+            self.push_color(target.color.as_ref());
+            for decoration in &target.decorations {
+                self.push_code(*decoration);
+            }
+        }
+        self.state = target;
+    }
+
+    fn write(&mut self, component: &ChatComponent, parent: Option<&ComponentStyle>) {
+        let mut style = component.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+
+        let text = match component.get_kind() {
+            ComponentType::Text(text) => text.get_text().to_string(),
+            ComponentType::Translation(translation) => {
+                // every argument starts and ends in the translation's formatting
+                let args: Vec<String> = translation
+                    .get_args()
+                    .iter()
+                    .map(|arg| {
+                        let mut writer = LegacyWriter::new(self.options, Some(&style));
+                        writer.write(arg, Some(&style));
+                        writer.switch_to(LegacyState::of(&style, self.options));
+                        writer.out
+                    })
+                    .collect();
+                format_translation(translation.get_key(), &args)
+            }
+            ComponentType::Score(score) => score.get_value().unwrap_or_default().to_string(),
+            ComponentType::Selector(selector) => selector.get_selector().to_string(),
+            ComponentType::Keybind(keybind) => default_key_name(keybind.get_keybind())
+                .unwrap_or(keybind.get_keybind())
+                .to_string(),
+            ComponentType::Nbt(_) => String::new(),
+            ComponentType::Custom(custom) => custom.plain_text(),
+        };
+        if !text.is_empty() {
+            self.switch_to(LegacyState::of(&style, self.options));
+            self.out.push_str(&text);
+        }
+        for sibling in component.get_siblings() {
+            self.write(sibling, Some(&style));
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Converts this component into legacy text formatted with `§` codes, like
+    /// `§aGreen §lbold`, e.g. for pre-1.8 clients or scoreboard and team strings.
+    ///
+    /// Translations are written with their key as pattern and events are left out.
+    /// Custom colors are written as the nearest of the 16 colors, see [`LegacyOptions`] for hex colors.
+    /// Color codes end decorations in legacy text, so decorations like obfuscation (`§k`)
+    /// are written again after them, which [`ChatComponent::from_legacy_text()`] reads back.
+    pub fn to_legacy_string(&self) -> String {
+        self.to_legacy_string_with(&LegacyOptions::default())
+    }
+
+    /// Converts this component into legacy text with the codes configured by `options`,
+    /// e.g. `&aGreen`, see [`ChatComponent::to_legacy_string()`].
+    pub fn to_legacy_string_with(&self, options: &LegacyOptions) -> String {
+        let mut writer = LegacyWriter::new(options, None);
+        writer.write(self, None);
+        writer.out
+    }
+}
@@ -2,21 +2,86 @@
 //! This crate provides Rust objects that map to the minecraft
 //! protocol raw JSON message format used for chat messages, books, titles...
 //!
-//! Serialization and Deserialization is implemented using serde. We plan on implementing legacy text soon!
+//! Serialization and Deserialization is implemented using serde, legacy `§` formatted text
+//! is supported too (see [`ChatComponent::from_legacy_text()`] and [`ChatComponent::to_legacy_string()`]).
+//!
+//! Fields of parsed JSON this crate doesn't know are dropped, proxies passing on components
+//! they didn't create can enable the `preserve_unknown` feature to write them back.
+//!
+//! Python bindings are available with the `python` feature.
 //!
 //! Please check out our [github](https://github.com/GrizzlT/MinecraftChatRust) and
 //! feel free to contribute.
 
+mod ansi;
+mod bbcode;
+pub mod bedrock;
+pub mod book;
+mod chat_type;
+#[cfg(feature = "tokio-codec")]
+mod codec;
+#[cfg(feature = "json")]
+pub mod commands;
 mod component;
+mod discord;
+pub mod effects;
+#[cfg(any(feature = "cli", feature = "python"))]
+#[doc(hidden)]
+pub mod formats;
+mod html;
+mod intern;
+mod irc;
+pub mod layout;
+mod legacy;
+#[cfg(feature = "minimessage")]
+mod minimessage;
+#[cfg(feature = "serde")]
+mod nbt;
+mod pool;
+pub mod presets;
+#[cfg(feature = "json")]
+mod protocol;
+#[cfg(feature = "python")]
+mod python;
+mod slack;
+mod status;
 mod style;
+mod team;
+mod text;
 
 mod tests;
 
+pub use ansi::{AnsiDisplay, ColorDepth};
+pub use chat_type::{ChatDecoration, ChatType, ChatTypeParameter};
+#[cfg(feature = "tokio-codec")]
+pub use codec::{ChatCodec, CodecErr};
+#[cfg(feature = "preserve_order")]
+pub use component::FieldOrder;
+pub use component::{
+    default_key_name, ChatComponent, ComponentPath, ComponentStats, ComponentType, CustomComponent,
+    IntoComponentArg, Keybind, KeybindComponent, LintKind, LintWarning, NbtComponent, NbtSource,
+    PathSegment, ScoreComponent, Selector, SelectorComponent, SelectorTarget, TextComponent,
+    TranslationComponent, UnknownKeybindErr,
+};
+#[cfg(feature = "json")]
 pub use component::{
-    ChatComponent, ComponentType, KeybindComponent, ScoreComponent, SelectorComponent,
-    TextComponent, TranslationComponent,
+    register_custom_component, ComponentJsonErr, ComponentValueErr, CustomComponentParser,
+    UnknownComponent,
 };
+#[cfg(feature = "serde")]
+pub use component::{BorrowedComponent, BorrowedKind};
+pub use html::{HtmlBackground, HtmlStyleMode, HtmlTheme, ObfuscatedMarkup};
+pub use intern::Interner;
+pub use legacy::{HexFormat, LegacyOptions, LegacyParser, UnknownCodes};
+#[cfg(feature = "nbt")]
+pub use nbt::{NbtErr, Sign, SignErr, SignText, SIGN_LINE_WIDTH};
+pub use pool::{ComponentPool, PooledComponent};
+#[cfg(feature = "json")]
+pub use protocol::PacketErr;
+pub use slack::{SlackFallback, SlackOptions};
+pub use status::{StatusPlayer, StatusPlayers, StatusResponse, StatusVersion};
 pub use style::{
-    ChatColor, ClickEvent, ComponentStyle, HoverEvent, VERSION_1_15, VERSION_1_16, VERSION_1_7,
-    VERSION_1_8,
+    ChatColor, ChatFormatting, ClickEvent, ColorParseErr, ComponentStyle, EntityTooltip,
+    HoverEvent, ShadowColor, VERSION_1_12, VERSION_1_15, VERSION_1_16, VERSION_1_7, VERSION_1_8,
 };
+pub use team::TeamFormat;
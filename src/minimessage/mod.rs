@@ -0,0 +1,42 @@
+//! [MiniMessage](https://docs.advntr.dev/minimessage/format.html), the markup used by
+//! Paper plugins: `<red>Hello <bold>world</bold>`.
+
+mod parser;
+mod serializer;
+
+use crate::style::{ChatColor, ChatFormatting};
+
+/// Looks up a color argument: a color name (`red`, `dark_grey`) or `#RRGGBB`.
+fn parse_color(name: &str) -> Option<ChatColor> {
+    if let Some(hex) = name.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let rgb = u32::from_str_radix(hex, 16).ok()?;
+        return Some(ChatColor::rgb(
+            (rgb >> 16) as u8,
+            (rgb >> 8) as u8,
+            rgb as u8,
+        ));
+    }
+    let name = match name {
+        "grey" => "gray",
+        "dark_grey" => "dark_gray",
+        name => name,
+    };
+    ChatFormatting::from_name(name)
+        .filter(ChatFormatting::is_color)
+        .and_then(|formatting| formatting.to_color())
+}
+
+/// Looks up a decoration tag by its name or one of its aliases (`b`, `em`...).
+fn parse_decoration(name: &str) -> Option<ChatFormatting> {
+    Some(match name {
+        "bold" | "b" => ChatFormatting::Bold,
+        "italic" | "i" | "em" => ChatFormatting::Italic,
+        "underlined" | "u" => ChatFormatting::Underlined,
+        "strikethrough" | "st" => ChatFormatting::Strikethrough,
+        "obfuscated" | "obf" => ChatFormatting::Obfuscated,
+        _ => return None,
+    })
+}
@@ -0,0 +1,353 @@
+use crate::component::{ChatComponent, ComponentType, TranslationComponent};
+use crate::effects;
+use crate::minimessage::{parse_color, parse_decoration};
+use crate::style::{ChatColor, ChatFormatting, ClickEvent, ComponentStyle, HoverEvent};
+
+/// Colors applied to the whole content of a tag once it's closed.
+enum Effect {
+    None,
+    Gradient(Vec<ChatColor>),
+    Rainbow,
+}
+
+/// A tag that is still open, collecting the components inside of it.
+struct Frame {
+    /// The name closing tags are matched against, e.g. `color` for `<red>`
+    name: String,
+    style: ComponentStyle,
+    effect: Effect,
+    children: Vec<ChatComponent>,
+}
+
+struct Parser {
+    version: u32,
+    frames: Vec<Frame>,
+    text: String,
+}
+
+/// Splits the inside of a tag at every `:` outside of quotes, removing the quotes.
+fn split_args(tag: &str) -> Vec<String> {
+    let mut args = vec![String::new()];
+    let mut quote = None;
+    let mut chars = tag.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), '\\') => match chars.next() {
+                Some(next) if next == q || next == '\\' => args.last_mut().unwrap().push(next),
+                Some(next) => {
+                    let arg = args.last_mut().unwrap();
+                    arg.push('\\');
+                    arg.push(next);
+                }
+                None => args.last_mut().unwrap().push('\\'),
+            },
+            (Some(q), c) if c == q => quote = None,
+            (None, '\'' | '"') => quote = Some(c),
+            (None, ':') => args.push(String::new()),
+            (_, c) => args.last_mut().unwrap().push(c),
+        }
+    }
+    args
+}
+
+/// Finds the end of a tag starting after a `<`, skipping `>` inside of quotes.
+fn tag_end(input: &str) -> Option<usize> {
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in input.char_indices() {
+        match (quote, c) {
+            _ if escaped => escaped = false,
+            (Some(_), '\\') => escaped = true,
+            (Some(q), c) if c == q => quote = None,
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '>') => return Some(i),
+            (None, '<') => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn set_decoration(style: &mut ComponentStyle, decoration: ChatFormatting, value: bool) {
+    match decoration {
+        ChatFormatting::Bold => style.set_bold(value),
+        ChatFormatting::Italic => style.set_italic(value),
+        ChatFormatting::Underlined => style.set_underlined(value),
+        ChatFormatting::Strikethrough => style.set_strikethrough(value),
+        ChatFormatting::Obfuscated => style.set_obfuscated(value),
+        _ => {}
+    }
+}
+
+/// The name a closing tag closes, so `</b>` closes `<bold>` and `</red>` closes `<color:red>`.
+fn close_name(name: &str) -> String {
+    let name = name.to_ascii_lowercase();
+    let name = name.strip_prefix('!').unwrap_or(&name);
+    if let Some(decoration) = parse_decoration(name) {
+        return decoration.get_name().to_string();
+    }
+    match name {
+        "color" | "colour" | "c" => "color".to_string(),
+        name if parse_color(name).is_some() => "color".to_string(),
+        "insertion" => "insert".to_string(),
+        name => name.to_string(),
+    }
+}
+
+impl Parser {
+    fn style(&self) -> ComponentStyle {
+        ComponentStyle::with_version(self.version)
+    }
+
+    fn flush(&mut self) {
+        if !self.text.is_empty() {
+            let text = ChatComponent::from_text(std::mem::take(&mut self.text), self.style());
+            self.push(text);
+        }
+    }
+
+    fn push(&mut self, component: ChatComponent) {
+        self.frames
+            .last_mut()
+            .expect("the root frame is never closed")
+            .children
+            .push(component);
+    }
+
+    fn open(&mut self, name: &str, style: ComponentStyle, effect: Effect) {
+        self.flush();
+        self.frames.push(Frame {
+            name: close_name(name),
+            style,
+            effect,
+            children: vec![],
+        });
+    }
+
+    /// Closes the innermost open tag called `name` and every tag opened inside of it.
+    fn close(&mut self, name: &str) {
+        let name = close_name(name);
+        let index = match self.frames.iter().rposition(|frame| frame.name == name) {
+            Some(index) if index > 0 => index,
+            _ => return,
+        };
+        self.flush();
+        while self.frames.len() > index {
+            self.close_last();
+        }
+    }
+
+    fn close_last(&mut self) {
+        let frame = self.frames.pop().expect("closing a frame needs a frame");
+        let mut component = ChatComponent::from_text("", frame.style);
+        *component.get_siblings_mut() = frame.children;
+        match &frame.effect {
+            Effect::None => {}
+            Effect::Gradient(colors) => effects::apply_gradient(&mut component, colors),
+            Effect::Rainbow => effects::apply_rainbow(&mut component, 0.0),
+        }
+        self.push(component);
+    }
+
+    /// Handles the inside of a tag, returns whether it is a tag this parser knows.
+    fn tag(&mut self, tag: &str) -> bool {
+        if let Some(name) = tag.strip_prefix('/') {
+            self.close(split_args(name).first().map_or("", String::as_str));
+            return true;
+        }
+        let tag = tag.strip_suffix('/').unwrap_or(tag);
+        let args = split_args(tag);
+        let name = args[0].to_ascii_lowercase();
+        let arg = |index: usize| args.get(index).map(String::as_str);
+        // the rest of the arguments, for values like urls that contain `:` themselves
+        let rest = |index: usize| {
+            args.get(index..)
+                .filter(|args| !args.is_empty())
+                .map(|args| args.join(":"))
+        };
+
+        if let Some(color) = parse_color(&name) {
+            let style = self.style().color(Some(color));
+            self.open(&name, style, Effect::None);
+            return true;
+        }
+        let negated = name.strip_prefix('!');
+        if let Some(decoration) = parse_decoration(negated.unwrap_or(&name)) {
+            let value = negated.is_none() && arg(1) != Some("false");
+            let mut style = self.style();
+            set_decoration(&mut style, decoration, value);
+            self.open(&name, style, Effect::None);
+            return true;
+        }
+
+        match name.as_str() {
+            "color" | "colour" | "c" => match arg(1).and_then(parse_color) {
+                Some(color) => {
+                    let style = self.style().color(Some(color));
+                    self.open(&name, style, Effect::None);
+                }
+                None => return false,
+            },
+            "click" => {
+                let value = match rest(2) {
+                    Some(value) => value,
+                    None => return false,
+                };
+                let event = match arg(1) {
+                    Some("open_url") => ClickEvent::url(value),
+                    Some("run_command") => ClickEvent::run_command(value),
+                    Some("suggest_command") => ClickEvent::suggest_command(value),
+                    Some("copy_to_clipboard") => ClickEvent::clipboard(value),
+                    Some("change_page") => match value.parse::<u32>() {
+                        Ok(page) => ClickEvent::page(page),
+                        Err(_) => return false,
+                    },
+                    _ => return false,
+                };
+                let style = self.style().click_event(Some(event));
+                self.open(&name, style, Effect::None);
+            }
+            "hover" => match (arg(1), rest(2)) {
+                (Some("show_text"), Some(text)) => {
+                    let text = ChatComponent::from_minimessage(&text, self.version);
+                    let style = self
+                        .style()
+                        .hover_event(Some(HoverEvent::ShowText(Box::new(text))));
+                    self.open(&name, style, Effect::None);
+                }
+                _ => return false,
+            },
+            "insert" | "insertion" => match arg(1) {
+                Some(insertion) => {
+                    let style = self.style().insertion(Some(insertion));
+                    self.open(&name, style, Effect::None);
+                }
+                None => return false,
+            },
+            "font" => match rest(1) {
+                Some(font) => {
+                    let style = self.style().font(Some(font));
+                    self.open(&name, style, Effect::None);
+                }
+                None => return false,
+            },
+            "gradient" => {
+                // numbers are the phase, which isn't supported
+                let mut colors: Vec<ChatColor> = args[1..]
+                    .iter()
+                    .filter_map(|arg| parse_color(arg))
+                    .collect();
+                if colors.is_empty() {
+                    colors = vec![ChatColor::White, ChatColor::Black];
+                }
+                let style = self.style();
+                self.open(&name, style, Effect::Gradient(colors));
+            }
+            "rainbow" => {
+                let style = self.style();
+                self.open(&name, style, Effect::Rainbow);
+            }
+            "reset" => {
+                self.flush();
+                while self.frames.len() > 1 {
+                    self.close_last();
+                }
+            }
+            "newline" | "br" => self.text.push('\n'),
+            "key" => match arg(1) {
+                Some(key) => {
+                    self.flush();
+                    self.push(ChatComponent::from_keybind(key, self.style()));
+                }
+                None => return false,
+            },
+            "lang" | "tr" | "translate" => match arg(1) {
+                Some(key) => {
+                    let mut translation = TranslationComponent::from_key(key);
+                    for arg in &args[2..] {
+                        translation.add_arg(ChatComponent::from_minimessage(arg, self.version));
+                    }
+                    self.flush();
+                    let style = self.style();
+                    self.push(ChatComponent::from_component(
+                        ComponentType::Translation(translation),
+                        style,
+                    ));
+                }
+                None => return false,
+            },
+            "selector" | "sel" => match arg(1) {
+                Some(selector) => {
+                    self.flush();
+                    self.push(ChatComponent::from_selector(selector, self.style()));
+                }
+                None => return false,
+            },
+            "score" => match (arg(1), arg(2)) {
+                (Some(name), Some(objective)) => {
+                    self.flush();
+                    self.push(ChatComponent::from_score(name, objective, self.style()));
+                }
+                _ => return false,
+            },
+            _ => return false,
+        }
+        true
+    }
+
+    fn parse(&mut self, input: &str) {
+        let mut rest = input;
+        while let Some(c) = rest.chars().next() {
+            rest = &rest[c.len_utf8()..];
+            match c {
+                '\\' => match rest.chars().next() {
+                    Some(next @ ('<' | '\\')) => {
+                        self.text.push(next);
+                        rest = &rest[1..];
+                    }
+                    _ => self.text.push('\\'),
+                },
+                '<' => match tag_end(rest) {
+                    Some(end) if self.tag(&rest[..end]) => rest = &rest[end + 1..],
+                    // not a tag, written as it is
+                    _ => self.text.push('<'),
+                },
+                c => self.text.push(c),
+            }
+        }
+        self.flush();
+    }
+}
+
+impl ChatComponent {
+    /// Parses [MiniMessage](https://docs.advntr.dev/minimessage/format.html) markup like
+    /// `<red>Hello <bold>world</bold>` into components of the given version.
+    ///
+    /// Colors, decorations, `click`, `hover` (`show_text`), `insert`, `font`, `gradient`,
+    /// `rainbow`, `reset`, `newline`, `key`, `lang`, `selector` and `score` are supported.
+    /// Like MiniMessage, unknown or invalid tags are kept as text and open tags are
+    /// closed at the end.
+    pub fn from_minimessage(input: &str, version: u32) -> ChatComponent {
+        let mut parser = Parser {
+            version,
+            frames: vec![Frame {
+                name: String::new(),
+                style: ComponentStyle::with_version(version),
+                effect: Effect::None,
+                children: vec![],
+            }],
+            text: String::new(),
+        };
+        parser.parse(input);
+        while parser.frames.len() > 1 {
+            parser.close_last();
+        }
+        let mut root = parser.frames.pop().expect("the root frame is never closed");
+        if root.children.len() == 1 {
+            return root.children.remove(0);
+        }
+        let mut component = ChatComponent::from_text("", root.style);
+        *component.get_siblings_mut() = root.children;
+        component
+    }
+}
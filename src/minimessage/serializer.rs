@@ -0,0 +1,175 @@
+use crate::component::{ChatComponent, ComponentType};
+use crate::style::{ChatColor, ChatFormatting, ClickEvent, ComponentStyle, HoverEvent};
+
+/// Escapes the characters MiniMessage reads as markup.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('<', "\\<")
+}
+
+/// Quotes a tag argument if it contains characters with a meaning inside of tags.
+fn quote(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains(|c| ":'\"<>\\ ".contains(c)) {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn color_tag(color: &ChatColor) -> Option<String> {
+    match ChatFormatting::from_color(color) {
+        Some(ChatFormatting::Reset) => None,
+        Some(formatting) => Some(formatting.get_name().to_string()),
+        None => color
+            .to_rgb()
+            .map(|(red, green, blue)| format!("#{:02x}{:02x}{:02x}", red, green, blue)),
+    }
+}
+
+fn click_tag(event: &ClickEvent) -> String {
+    let (action, value) = match event {
+        ClickEvent::OpenUrl(url) => ("open_url", url.clone()),
+        ClickEvent::RunCommand(command) => ("run_command", command.clone()),
+        ClickEvent::SuggestCommand(command) => ("suggest_command", command.clone()),
+        ClickEvent::ChangePage(page) => ("change_page", page.to_string()),
+        ClickEvent::CopyToClipBoard(text) => ("copy_to_clipboard", text.clone()),
+    };
+    format!("click:{}:{}", action, quote(&value))
+}
+
+/// The tags opening the parts of `style` that differ from what it inherits, each
+/// paired with the name closing it.
+fn open_tags(style: &ComponentStyle, parent: &ComponentStyle) -> Vec<(String, String)> {
+    let mut tags = vec![];
+    if let Some(tag) = style
+        .get_color()
+        .filter(|color| parent.get_color() != Some(color))
+        .and_then(color_tag)
+    {
+        tags.push((tag.clone(), tag));
+    }
+    let decorations = [
+        (style.get_bold(), parent.get_bold(), "bold"),
+        (style.get_italic(), parent.get_italic(), "italic"),
+        (
+            style.get_underlined(),
+            parent.get_underlined(),
+            "underlined",
+        ),
+        (
+            style.get_strikethrough(),
+            parent.get_strikethrough(),
+            "strikethrough",
+        ),
+        (
+            style.get_obfuscated(),
+            parent.get_obfuscated(),
+            "obfuscated",
+        ),
+    ];
+    for (value, inherited, name) in decorations.iter() {
+        match value {
+            Some(value) if *inherited == Some(*value) => {}
+            Some(true) => tags.push((name.to_string(), name.to_string())),
+            Some(false) => tags.push((format!("!{}", name), format!("!{}", name))),
+            None => {}
+        }
+    }
+    if let Some(font) = style
+        .get_font()
+        .filter(|font| parent.get_font() != Some(font))
+    {
+        tags.push((format!("font:{}", quote(font)), "font".to_string()));
+    }
+    if let Some(insertion) = style
+        .get_insertion()
+        .filter(|insertion| parent.get_insertion() != Some(insertion))
+    {
+        tags.push((format!("insert:{}", quote(insertion)), "insert".to_string()));
+    }
+    if let Some(event) = style
+        .get_click_event()
+        .filter(|event| parent.get_click_event() != Some(event))
+    {
+        tags.push((click_tag(event), "click".to_string()));
+    }
+    if let Some(HoverEvent::ShowText(text)) = style
+        .get_hover_event()
+        .filter(|event| parent.get_hover_event() != Some(event))
+    {
+        let tag = format!("hover:show_text:{}", quote(&text.to_minimessage()));
+        tags.push((tag, "hover".to_string()));
+    }
+    tags
+}
+
+struct Writer {
+    out: String,
+    /// Where the output ends without the closing tags at its end,
+    /// which MiniMessage doesn't need
+    content_end: usize,
+}
+
+impl Writer {
+    fn push(&mut self, text: &str) {
+        self.out.push_str(text);
+        self.content_end = self.out.len();
+    }
+
+    fn write(&mut self, component: &ChatComponent, parent: &ComponentStyle) {
+        let mut style = component.get_style().clone();
+        style.inherit(parent);
+
+        let tags = open_tags(component.get_style(), parent);
+        for (tag, _) in &tags {
+            self.push(&format!("<{}>", tag));
+        }
+        match component.get_kind() {
+            ComponentType::Text(text) => self.push(&escape(text.get_text())),
+            ComponentType::Translation(translation) => {
+                let mut tag = format!("<lang:{}", quote(translation.get_key()));
+                for arg in translation.get_args() {
+                    tag.push(':');
+                    tag.push_str(&quote(&arg.to_minimessage()));
+                }
+                tag.push('>');
+                self.push(&tag);
+            }
+            ComponentType::Score(score) => self.push(&format!(
+                "<score:{}:{}>",
+                quote(score.get_name()),
+                quote(score.get_objective())
+            )),
+            ComponentType::Selector(selector) => {
+                self.push(&format!("<selector:{}>", quote(selector.get_selector())))
+            }
+            ComponentType::Keybind(keybind) => {
+                self.push(&format!("<key:{}>", quote(keybind.get_keybind())))
+            }
+            // MiniMessage has no tag for NBT components
+            ComponentType::Nbt(_) => {}
+            ComponentType::Custom(custom) => self.push(&escape(&custom.plain_text())),
+        }
+        for sibling in component.get_siblings() {
+            self.write(sibling, &style);
+        }
+        for (_, name) in tags.iter().rev() {
+            self.out.push_str(&format!("</{}>", name));
+        }
+    }
+}
+
+impl ChatComponent {
+    /// Converts this component into [MiniMessage](https://docs.advntr.dev/minimessage/format.html)
+    /// markup, see [`ChatComponent::from_minimessage()`].
+    ///
+    /// Only styles that differ from the inherited ones get a tag, tags are closed where
+    /// their component ends (except at the end of the markup). Item and entity hovers are left out.
+    pub fn to_minimessage(&self) -> String {
+        let mut writer = Writer {
+            out: String::new(),
+            content_end: 0,
+        };
+        writer.write(self, &ComponentStyle::with_version(self.get_version()));
+        writer.out.truncate(writer.content_end);
+        writer.out
+    }
+}
@@ -0,0 +1,268 @@
+//! Components as NBT, the format they are sent in since 1.20.3, and as SNBT.
+//!
+//! Writing SNBT only needs the `json` feature (for commands since 1.21.5), the SNBT
+//! parser is also used for the entity hovers of older versions. Everything else needs
+//! the `nbt` feature.
+
+#[cfg(feature = "nbt")]
+mod network;
+mod parser;
+#[cfg(feature = "nbt")]
+mod sign;
+#[cfg(feature = "json")]
+mod snbt;
+
+pub(crate) use parser::parse_snbt;
+#[cfg(feature = "nbt")]
+pub use sign::{Sign, SignErr, SignText, SIGN_LINE_WIDTH};
+#[cfg(feature = "nbt")]
+pub(crate) use snbt::write_snbt;
+
+#[cfg(feature = "json")]
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "json")]
+use serde_json::Value;
+#[cfg(feature = "nbt")]
+use serde_json::{Map, Number};
+
+#[cfg(feature = "json")]
+use crate::component::ChatComponent;
+
+/// The error returned when NBT can't be read as a component.
+#[derive(Clone, Debug, PartialEq, Eq)]
+// only the SNBT parser is used without the `nbt` feature
+#[cfg_attr(not(feature = "nbt"), allow(dead_code))]
+pub enum NbtErr {
+    /// The input ended before the NBT was complete
+    UnexpectedEnd,
+    /// A tag type that doesn't exist
+    UnknownTag(u8),
+    /// A string that isn't valid (modified) UTF-8
+    InvalidString,
+    /// The NBT is nested deeper than Minecraft allows
+    TooDeep,
+    /// The NBT is valid, but not a component
+    InvalidComponent(String),
+    /// SNBT with a syntax error, at the given character
+    InvalidSnbt(usize, String),
+}
+
+impl Display for NbtErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NbtErr::UnexpectedEnd => write!(f, "Unexpected end of NBT"),
+            NbtErr::UnknownTag(id) => write!(f, "{} is not a valid tag type!", id),
+            NbtErr::InvalidString => write!(f, "Invalid string in NBT"),
+            NbtErr::TooDeep => write!(f, "NBT is nested too deep"),
+            NbtErr::InvalidComponent(err) => write!(f, "Invalid component: {}", err),
+            NbtErr::InvalidSnbt(position, err) => {
+                write!(f, "Invalid SNBT at character {}: {}", position, err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NbtErr {}
+
+/// How deep Minecraft nests NBT at most.
+const MAX_DEPTH: usize = 512;
+
+/// An NBT tag, compounds keep the order of their entries.
+#[derive(Clone, Debug, PartialEq)]
+// only the SNBT parser and writer are used without the `nbt` feature
+#[cfg_attr(not(feature = "nbt"), allow(dead_code))]
+pub(crate) enum Tag {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(Vec<i8>),
+    String(String),
+    List(Vec<Tag>),
+    Compound(Vec<(String, Tag)>),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
+}
+
+impl Tag {
+    #[cfg(feature = "json")]
+    pub(crate) fn id(&self) -> u8 {
+        match self {
+            Tag::Byte(_) => 1,
+            Tag::Short(_) => 2,
+            Tag::Int(_) => 3,
+            Tag::Long(_) => 4,
+            Tag::Float(_) => 5,
+            Tag::Double(_) => 6,
+            Tag::ByteArray(_) => 7,
+            Tag::String(_) => 8,
+            Tag::List(_) => 9,
+            Tag::Compound(_) => 10,
+            Tag::IntArray(_) => 11,
+            Tag::LongArray(_) => 12,
+        }
+    }
+
+    /// Converts component JSON into NBT like Minecraft does: booleans become bytes
+    /// and lists mixing types wrap every element in a compound with an empty key.
+    #[cfg(feature = "json")]
+    pub(crate) fn from_json(value: &Value) -> Option<Tag> {
+        Some(match value {
+            Value::Null => return None,
+            Value::Bool(value) => Tag::Byte(*value as i8),
+            Value::Number(number) => match (number.as_i64(), number.as_f64()) {
+                (Some(int), _) if i32::try_from(int).is_ok() => Tag::Int(int as i32),
+                (Some(long), _) => Tag::Long(long),
+                (None, Some(double)) => Tag::Double(double),
+                (None, None) => return None,
+            },
+            Value::String(string) => Tag::String(string.clone()),
+            Value::Array(values) => {
+                let tags: Vec<Tag> = values.iter().filter_map(Tag::from_json).collect();
+                let mixed = tags.windows(2).any(|pair| pair[0].id() != pair[1].id());
+                if mixed {
+                    Tag::List(
+                        tags.into_iter()
+                            .map(|tag| match tag {
+                                Tag::Compound(entries) => Tag::Compound(entries),
+                                tag => Tag::Compound(vec![(String::new(), tag)]),
+                            })
+                            .collect(),
+                    )
+                } else {
+                    Tag::List(tags)
+                }
+            }
+            Value::Object(map) => Tag::Compound(
+                map.iter()
+                    .filter_map(|(key, value)| Some((key.clone(), Tag::from_json(value)?)))
+                    .collect(),
+            ),
+        })
+    }
+
+    /// Converts NBT into component JSON, bytes of 0 and 1 are read as booleans.
+    #[cfg(feature = "nbt")]
+    pub(crate) fn to_json(&self) -> Value {
+        let numbers = |numbers: Vec<Value>| Value::Array(numbers);
+        match self {
+            Tag::Byte(0) => Value::Bool(false),
+            Tag::Byte(1) => Value::Bool(true),
+            Tag::Byte(value) => Value::from(*value),
+            Tag::Short(value) => Value::from(*value),
+            Tag::Int(value) => Value::from(*value),
+            Tag::Long(value) => Value::from(*value),
+            Tag::Float(value) => Number::from_f64(*value as f64).map_or(Value::Null, Value::Number),
+            Tag::Double(value) => Number::from_f64(*value).map_or(Value::Null, Value::Number),
+            Tag::ByteArray(values) => numbers(values.iter().map(|v| Value::from(*v)).collect()),
+            Tag::String(value) => Value::String(value.clone()),
+            Tag::List(tags) => Value::Array(tags.iter().map(Tag::to_json).collect()),
+            Tag::Compound(entries) => match entries.as_slice() {
+                // an element of a list mixing types
+                [(key, tag)] if key.is_empty() => tag.to_json(),
+                entries => Value::Object(
+                    entries
+                        .iter()
+                        .map(|(key, tag)| (key.clone(), tag.to_json()))
+                        .collect(),
+                ),
+            },
+            Tag::IntArray(values) => numbers(values.iter().map(|v| Value::from(*v)).collect()),
+            Tag::LongArray(values) => numbers(values.iter().map(|v| Value::from(*v)).collect()),
+        }
+    }
+}
+
+/// Turns the shorthands NBT allows where components are expected (strings,
+/// numbers and booleans) into text components.
+#[cfg(feature = "nbt")]
+fn expand_shorthands(value: Value) -> Value {
+    match value {
+        Value::String(text) => text_object(text),
+        Value::Bool(value) => text_object(value.to_string()),
+        Value::Number(number) => text_object(number.to_string()),
+        Value::Array(values) => {
+            // a list is its first element with the rest as siblings
+            let mut values = values.into_iter().map(expand_shorthands);
+            let mut first = values.next().unwrap_or_else(|| text_object(String::new()));
+            let rest: Vec<Value> = values.collect();
+            if !rest.is_empty() {
+                if let Value::Object(first) = &mut first {
+                    let extra = first.entry("extra").or_insert_with(|| Value::Array(vec![]));
+                    if let Value::Array(extra) = extra {
+                        extra.extend(rest);
+                    }
+                }
+            }
+            first
+        }
+        Value::Object(mut map) => {
+            for key in ["extra", "with"] {
+                if let Some(Value::Array(values)) = map.remove(key) {
+                    let values = values.into_iter().map(expand_shorthands).collect();
+                    map.insert(key.to_string(), Value::Array(values));
+                }
+            }
+            if let Some(separator) = map.remove("separator") {
+                map.insert("separator".to_string(), expand_shorthands(separator));
+            }
+            for key in ["hoverEvent", "hover_event"] {
+                if let Some(Value::Object(hover)) = map.get_mut(key) {
+                    match hover.get("action").and_then(Value::as_str) {
+                        Some("show_text") => {
+                            for field in ["contents", "value"] {
+                                if let Some(contents) = hover.remove(field) {
+                                    hover.insert(field.to_string(), expand_shorthands(contents));
+                                }
+                            }
+                        }
+                        Some("show_entity") => {
+                            if let Some(Value::Object(entity)) = hover.get_mut("contents") {
+                                if let Some(name) = entity.remove("name") {
+                                    entity.insert("name".to_string(), expand_shorthands(name));
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Value::Object(map)
+        }
+        Value::Null => Value::Null,
+    }
+}
+
+#[cfg(feature = "nbt")]
+fn text_object(text: String) -> Value {
+    let mut map = Map::new();
+    map.insert("text".to_string(), Value::String(text));
+    Value::Object(map)
+}
+
+#[cfg(feature = "json")]
+impl ChatComponent {
+    /// The NBT of this component, a plain text without any style is sent as a string.
+    pub(crate) fn to_nbt_tag(&self) -> Tag {
+        let json = serde_json::to_value(self).expect("components always serialize to JSON");
+        match json {
+            Value::Object(map)
+                if map.len() == 1 && map.get("text").is_some_and(Value::is_string) =>
+            {
+                Tag::String(map["text"].as_str().unwrap_or_default().to_string())
+            }
+            json => Tag::from_json(&json).unwrap_or_else(|| Tag::String(String::new())),
+        }
+    }
+
+    /// Reads a component from NBT, strings and lists are read like Minecraft does.
+    #[cfg(feature = "nbt")]
+    pub(crate) fn from_nbt_tag(tag: &Tag) -> Result<ChatComponent, NbtErr> {
+        serde_json::from_value(expand_shorthands(tag.to_json()))
+            .map_err(|err| NbtErr::InvalidComponent(err.to_string()))
+    }
+}
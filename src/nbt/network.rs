@@ -0,0 +1,218 @@
+use crate::component::ChatComponent;
+use crate::nbt::{NbtErr, Tag, MAX_DEPTH};
+
+/// Encodes `string` as Java's modified UTF-8: `\0` takes two bytes and characters
+/// outside of the BMP are written as two surrogates.
+fn encode_mutf8(string: &str, out: &mut Vec<u8>) {
+    let mut bytes = Vec::with_capacity(string.len());
+    for unit in string.encode_utf16() {
+        match unit {
+            1..=0x7F => bytes.push(unit as u8),
+            0 | 0x80..=0x7FF => {
+                bytes.push(0xC0 | (unit >> 6) as u8);
+                bytes.push(0x80 | (unit & 0x3F) as u8);
+            }
+            _ => {
+                bytes.push(0xE0 | (unit >> 12) as u8);
+                bytes.push(0x80 | ((unit >> 6) & 0x3F) as u8);
+                bytes.push(0x80 | (unit & 0x3F) as u8);
+            }
+        }
+    }
+    // longer strings can't be sent, Minecraft cuts them off too
+    bytes.truncate(u16::MAX as usize);
+    out.extend_from_slice(&(bytes.len() as u16).to_be_bytes());
+    out.extend_from_slice(&bytes);
+}
+
+fn decode_mutf8(bytes: &[u8]) -> Result<String, NbtErr> {
+    let mut units = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    let continuation = |index: usize| match bytes.get(index) {
+        Some(byte) if byte & 0xC0 == 0x80 => Ok((byte & 0x3F) as u16),
+        _ => Err(NbtErr::InvalidString),
+    };
+    while i < bytes.len() {
+        let byte = bytes[i];
+        let (unit, length) = match byte {
+            0x01..=0x7F => (byte as u16, 1),
+            0xC0..=0xDF => (((byte & 0x1F) as u16) << 6 | continuation(i + 1)?, 2),
+            0xE0..=0xEF => (
+                ((byte & 0x0F) as u16) << 12 | continuation(i + 1)? << 6 | continuation(i + 2)?,
+                3,
+            ),
+            _ => return Err(NbtErr::InvalidString),
+        };
+        units.push(unit);
+        i += length;
+    }
+    String::from_utf16(&units).map_err(|_| NbtErr::InvalidString)
+}
+
+fn write_payload(tag: &Tag, out: &mut Vec<u8>) {
+    match tag {
+        Tag::Byte(value) => out.push(*value as u8),
+        Tag::Short(value) => out.extend_from_slice(&value.to_be_bytes()),
+        Tag::Int(value) => out.extend_from_slice(&value.to_be_bytes()),
+        Tag::Long(value) => out.extend_from_slice(&value.to_be_bytes()),
+        Tag::Float(value) => out.extend_from_slice(&value.to_be_bytes()),
+        Tag::Double(value) => out.extend_from_slice(&value.to_be_bytes()),
+        Tag::ByteArray(values) => {
+            out.extend_from_slice(&(values.len() as i32).to_be_bytes());
+            out.extend(values.iter().map(|value| *value as u8));
+        }
+        Tag::String(value) => encode_mutf8(value, out),
+        Tag::List(tags) => {
+            out.push(tags.first().map_or(0, Tag::id));
+            out.extend_from_slice(&(tags.len() as i32).to_be_bytes());
+            for tag in tags {
+                write_payload(tag, out);
+            }
+        }
+        Tag::Compound(entries) => {
+            for (key, tag) in entries {
+                out.push(tag.id());
+                encode_mutf8(key, out);
+                write_payload(tag, out);
+            }
+            out.push(0);
+        }
+        Tag::IntArray(values) => {
+            out.extend_from_slice(&(values.len() as i32).to_be_bytes());
+            for value in values {
+                out.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+        Tag::LongArray(values) => {
+            out.extend_from_slice(&(values.len() as i32).to_be_bytes());
+            for value in values {
+                out.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+    }
+}
+
+/// Encodes `tag` as network NBT, a root tag without name.
+pub(crate) fn write_network_nbt(tag: &Tag) -> Vec<u8> {
+    let mut out = vec![tag.id()];
+    write_payload(tag, &mut out);
+    out
+}
+
+struct Reader<'a, 'b> {
+    input: &'a mut &'b [u8],
+}
+
+impl Reader<'_, '_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], NbtErr> {
+        if self.input.len() < N {
+            return Err(NbtErr::UnexpectedEnd);
+        }
+        let (bytes, rest) = self.input.split_at(N);
+        *self.input = rest;
+        let mut array = [0; N];
+        array.copy_from_slice(bytes);
+        Ok(array)
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&[u8], NbtErr> {
+        if self.input.len() < length {
+            return Err(NbtErr::UnexpectedEnd);
+        }
+        let input: &[u8] = self.input;
+        let (bytes, rest) = input.split_at(length);
+        *self.input = rest;
+        Ok(bytes)
+    }
+
+    /// Reads the length of an array or list, which can't be longer than the input left.
+    fn length(&mut self, element_size: usize) -> Result<usize, NbtErr> {
+        let length = i32::from_be_bytes(self.take()?).max(0) as usize;
+        if length.saturating_mul(element_size) > self.input.len() {
+            return Err(NbtErr::UnexpectedEnd);
+        }
+        Ok(length)
+    }
+
+    fn string(&mut self) -> Result<String, NbtErr> {
+        let length = u16::from_be_bytes(self.take()?) as usize;
+        decode_mutf8(self.bytes(length)?)
+    }
+
+    fn payload(&mut self, id: u8, depth: usize) -> Result<Tag, NbtErr> {
+        if depth > MAX_DEPTH {
+            return Err(NbtErr::TooDeep);
+        }
+        Ok(match id {
+            1 => Tag::Byte(i8::from_be_bytes(self.take()?)),
+            2 => Tag::Short(i16::from_be_bytes(self.take()?)),
+            3 => Tag::Int(i32::from_be_bytes(self.take()?)),
+            4 => Tag::Long(i64::from_be_bytes(self.take()?)),
+            5 => Tag::Float(f32::from_be_bytes(self.take()?)),
+            6 => Tag::Double(f64::from_be_bytes(self.take()?)),
+            7 => {
+                let length = self.length(1)?;
+                Tag::ByteArray(self.bytes(length)?.iter().map(|b| *b as i8).collect())
+            }
+            8 => Tag::String(self.string()?),
+            9 => {
+                let element = u8::from_be_bytes(self.take()?);
+                let length = self.length(1)?;
+                if element == 0 {
+                    // empty lists are sent with the end tag as element type
+                    Tag::List(vec![])
+                } else {
+                    let tags = (0..length)
+                        .map(|_| self.payload(element, depth + 1))
+                        .collect::<Result<_, _>>()?;
+                    Tag::List(tags)
+                }
+            }
+            10 => {
+                let mut entries = vec![];
+                loop {
+                    let id = u8::from_be_bytes(self.take()?);
+                    if id == 0 {
+                        break Tag::Compound(entries);
+                    }
+                    let key = self.string()?;
+                    entries.push((key, self.payload(id, depth + 1)?));
+                }
+            }
+            11 => {
+                let length = self.length(4)?;
+                let values = (0..length)
+                    .map(|_| self.take().map(i32::from_be_bytes))
+                    .collect::<Result<_, _>>()?;
+                Tag::IntArray(values)
+            }
+            12 => {
+                let length = self.length(8)?;
+                let values = (0..length)
+                    .map(|_| self.take().map(i64::from_be_bytes))
+                    .collect::<Result<_, _>>()?;
+                Tag::LongArray(values)
+            }
+            id => return Err(NbtErr::UnknownTag(id)),
+        })
+    }
+}
+
+impl ChatComponent {
+    /// Encodes this component as the network NBT sent since 1.20.3 (a nameless root tag),
+    /// plain text without any style is sent as a string tag like Minecraft does.
+    pub fn to_network_nbt(&self) -> Vec<u8> {
+        write_network_nbt(&self.to_nbt_tag())
+    }
+
+    /// Reads a component sent as network NBT from the start of `input`, which is advanced
+    /// past it so the rest of a packet can be read.
+    ///
+    /// Compounds, strings and lists (the first element with the rest as siblings) are read.
+    pub fn from_network_nbt(input: &mut &[u8]) -> Result<ChatComponent, NbtErr> {
+        let mut reader = Reader { input };
+        let id = u8::from_be_bytes(reader.take()?);
+        let tag = reader.payload(id, 0)?;
+        ChatComponent::from_nbt_tag(&tag)
+    }
+}
@@ -0,0 +1,227 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::nbt::{NbtErr, Tag, MAX_DEPTH};
+
+/// Characters of unquoted strings and keys.
+fn is_plain_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "_-.+".contains(c)
+}
+
+struct SnbtParser<'a> {
+    chars: Peekable<Chars<'a>>,
+    position: usize,
+}
+
+impl SnbtParser<'_> {
+    fn error<T, E: Into<String>>(&self, message: E) -> Result<T, NbtErr> {
+        Err(NbtErr::InvalidSnbt(self.position, message.into()))
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        self.position += 1;
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), NbtErr> {
+        self.skip_whitespace();
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => self.error(format!("expected '{}' but found '{}'", expected, c)),
+            None => self.error(format!("expected '{}'", expected)),
+        }
+    }
+
+    fn quoted(&mut self, quote: char) -> Result<String, NbtErr> {
+        let mut string = String::new();
+        loop {
+            match self.next() {
+                None => return self.error("unclosed string"),
+                Some(c) if c == quote => return Ok(string),
+                Some('\\') => {
+                    let escaped = match self.next() {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('r') => '\r',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('s') => ' ',
+                        Some(c @ ('x' | 'u' | 'U')) => {
+                            let length = match c {
+                                'x' => 2,
+                                'u' => 4,
+                                _ => 8,
+                            };
+                            let hex: String = (0..length).filter_map(|_| self.next()).collect();
+                            match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                                Some(c) if hex.len() == length => c,
+                                _ => return self.error(format!("invalid escape \\{}{}", c, hex)),
+                            }
+                        }
+                        Some(c @ ('\\' | '"' | '\'')) => c,
+                        Some(c) => return self.error(format!("invalid escape \\{}", c)),
+                        None => return self.error("unclosed string"),
+                    };
+                    string.push(escaped);
+                }
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    fn plain(&mut self) -> String {
+        let mut string = String::new();
+        while let Some(c) = self.chars.peek().copied().filter(|c| is_plain_char(*c)) {
+            string.push(c);
+            self.next();
+        }
+        string
+    }
+
+    fn key(&mut self) -> Result<String, NbtErr> {
+        self.skip_whitespace();
+        match self.chars.peek().copied() {
+            Some(quote @ ('"' | '\'')) => {
+                self.next();
+                self.quoted(quote)
+            }
+            _ => match self.plain() {
+                key if key.is_empty() => self.error("expected a key"),
+                key => Ok(key),
+            },
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Tag, NbtErr> {
+        if depth > MAX_DEPTH {
+            return Err(NbtErr::TooDeep);
+        }
+        self.skip_whitespace();
+        match self.chars.peek().copied() {
+            Some('{') => {
+                self.next();
+                let mut entries = vec![];
+                self.skip_whitespace();
+                if self.chars.peek() == Some(&'}') {
+                    self.next();
+                    return Ok(Tag::Compound(entries));
+                }
+                loop {
+                    let key = self.key()?;
+                    self.expect(':')?;
+                    entries.push((key, self.value(depth + 1)?));
+                    self.skip_whitespace();
+                    match self.next() {
+                        Some(',') => {}
+                        Some('}') => return Ok(Tag::Compound(entries)),
+                        _ => return self.error("expected ',' or '}'"),
+                    }
+                }
+            }
+            Some('[') => {
+                self.next();
+                let mut array = self.chars.clone();
+                let prefix = match (array.next(), array.next()) {
+                    (Some(kind @ ('B' | 'I' | 'L')), Some(';')) => {
+                        self.next();
+                        self.next();
+                        Some(kind)
+                    }
+                    _ => None,
+                };
+                let mut tags = vec![];
+                self.skip_whitespace();
+                if self.chars.peek() == Some(&']') {
+                    self.next();
+                } else {
+                    loop {
+                        tags.push(self.value(depth + 1)?);
+                        self.skip_whitespace();
+                        match self.next() {
+                            Some(',') => {}
+                            Some(']') => break,
+                            _ => return self.error("expected ',' or ']'"),
+                        }
+                    }
+                }
+                let number = |tag: &Tag| match tag {
+                    Tag::Byte(value) => Some(*value as i64),
+                    Tag::Short(value) => Some(*value as i64),
+                    Tag::Int(value) => Some(*value as i64),
+                    Tag::Long(value) => Some(*value),
+                    _ => None,
+                };
+                let numbers: Option<Vec<i64>> = tags.iter().map(number).collect();
+                Ok(match (prefix, numbers) {
+                    (None, _) => Tag::List(tags),
+                    (Some('B'), Some(numbers)) => {
+                        Tag::ByteArray(numbers.into_iter().map(|n| n as i8).collect())
+                    }
+                    (Some('I'), Some(numbers)) => {
+                        Tag::IntArray(numbers.into_iter().map(|n| n as i32).collect())
+                    }
+                    (Some(_), Some(numbers)) => Tag::LongArray(numbers),
+                    (Some(_), None) => return self.error("arrays can only contain numbers"),
+                })
+            }
+            Some(quote @ ('"' | '\'')) => {
+                self.next();
+                Ok(Tag::String(self.quoted(quote)?))
+            }
+            Some(_) => {
+                let plain = self.plain();
+                if plain.is_empty() {
+                    return self.error("expected a value");
+                }
+                Ok(parse_plain(plain))
+            }
+            None => self.error("expected a value"),
+        }
+    }
+}
+
+/// Reads an unquoted value: booleans, numbers with their type suffix or a string.
+fn parse_plain(plain: String) -> Tag {
+    match plain.as_str() {
+        "true" => return Tag::Byte(1),
+        "false" => return Tag::Byte(0),
+        _ => {}
+    }
+    let (number, suffix) = match plain.char_indices().last() {
+        Some((i, c)) if c.is_ascii_alphabetic() => (&plain[..i], Some(c.to_ascii_lowercase())),
+        _ => (plain.as_str(), None),
+    };
+    let number = number.replace('_', "");
+    let tag = match suffix {
+        Some('b') => number.parse().ok().map(Tag::Byte),
+        Some('s') => number.parse().ok().map(Tag::Short),
+        Some('l') => number.parse().ok().map(Tag::Long),
+        Some('f') => number.parse().ok().map(Tag::Float),
+        Some('d') => number.parse().ok().map(Tag::Double),
+        Some(_) => None,
+        None if number.contains(['.', 'e', 'E']) => number.parse().ok().map(Tag::Double),
+        None => number.parse().ok().map(Tag::Int),
+    };
+    tag.unwrap_or(Tag::String(plain))
+}
+
+/// Parses a single SNBT value, like `{type:"minecraft:pig",id:"..."}`.
+pub(crate) fn parse_snbt(input: &str) -> Result<Tag, NbtErr> {
+    let mut parser = SnbtParser {
+        chars: input.chars().peekable(),
+        position: 0,
+    };
+    let tag = parser.value(0)?;
+    parser.skip_whitespace();
+    if parser.chars.peek().is_some() {
+        return parser.error("unexpected trailing input");
+    }
+    Ok(tag)
+}
@@ -0,0 +1,231 @@
+use std::fmt::{Display, Formatter};
+
+use crate::component::ChatComponent;
+use crate::layout;
+use crate::nbt::network::write_network_nbt;
+use crate::nbt::{write_snbt, Tag};
+use crate::style::ComponentStyle;
+
+/// The width (in pixels) a line of a sign can have.
+pub const SIGN_LINE_WIDTH: u32 = 90;
+
+/// The first version (1.17) with glowing sign text.
+const VERSION_1_17: u32 = 755;
+/// The first version (1.20) with text on both sides of a sign.
+const VERSION_1_20: u32 = 763;
+/// The first version (1.21.5) storing the lines as components instead of JSON strings.
+const VERSION_1_21_5: u32 = 770;
+
+/// The error returned when lines don't fit on a sign.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignErr {
+    /// More than four lines, with the number of lines given
+    TooManyLines(usize),
+    /// The line at the given index is wider than [`SIGN_LINE_WIDTH`], with its width
+    LineTooWide(usize, u32),
+}
+
+impl Display for SignErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SignErr::TooManyLines(lines) => write!(f, "A sign has 4 lines, not {}", lines),
+            SignErr::LineTooWide(line, width) => write!(
+                f,
+                "Line {} is {} pixels wide, signs fit {}",
+                line + 1,
+                width,
+                SIGN_LINE_WIDTH
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SignErr {}
+
+/// The text on one side of a sign: four lines, their dye color and whether they glow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignText {
+    lines: Vec<ChatComponent>,
+    color: String,
+    glowing: bool,
+}
+
+impl SignText {
+    /// Creates the text of a sign side from up to four lines, missing lines are empty.
+    ///
+    /// Lines wider than [`SIGN_LINE_WIDTH`] (measured with the default font,
+    /// see [`layout::width()`]) are cut off by the client and rejected.
+    pub fn new(lines: Vec<ChatComponent>) -> Result<Self, SignErr> {
+        if lines.len() > 4 {
+            return Err(SignErr::TooManyLines(lines.len()));
+        }
+        for (i, line) in lines.iter().enumerate() {
+            let width = layout::width(line);
+            if width > SIGN_LINE_WIDTH {
+                return Err(SignErr::LineTooWide(i, width));
+            }
+        }
+        Ok(SignText {
+            lines,
+            color: "black".to_string(),
+            glowing: false,
+        })
+    }
+
+    pub fn get_lines(&self) -> &Vec<ChatComponent> {
+        &self.lines
+    }
+
+    pub fn get_color(&self) -> &str {
+        &self.color
+    }
+
+    /// Sets the dye color of the text, like `light_blue`.
+    pub fn set_color<T: Into<String>>(&mut self, color: T) {
+        self.color = color.into();
+    }
+
+    pub fn color<T: Into<String>>(mut self, color: T) -> Self {
+        self.set_color(color);
+        self
+    }
+
+    pub fn is_glowing(&self) -> bool {
+        self.glowing
+    }
+
+    /// Sets whether the text glows like after using a glow ink sac, since 1.17.
+    pub fn set_glowing(&mut self, glowing: bool) {
+        self.glowing = glowing;
+    }
+
+    pub fn glowing(mut self, glowing: bool) -> Self {
+        self.set_glowing(glowing);
+        self
+    }
+
+    /// The four lines as NBT, JSON strings before 1.21.5.
+    fn messages(&self, version: u32) -> Vec<Tag> {
+        let empty = ChatComponent::from_text("", ComponentStyle::with_version(version));
+        (0..4)
+            .map(|i| {
+                let mut line = self.lines.get(i).unwrap_or(&empty).clone();
+                line.downgrade(version);
+                if version >= VERSION_1_21_5 {
+                    line.to_modern_nbt_tag()
+                } else {
+                    Tag::String(
+                        serde_json::to_string(&line).expect("components always serialize to JSON"),
+                    )
+                }
+            })
+            .collect()
+    }
+
+    fn to_tag(&self, version: u32) -> Tag {
+        Tag::Compound(vec![
+            ("messages".to_string(), Tag::List(self.messages(version))),
+            ("color".to_string(), Tag::String(self.color.clone())),
+            (
+                "has_glowing_text".to_string(),
+                Tag::Byte(self.glowing as i8),
+            ),
+        ])
+    }
+}
+
+/// The block entity data of a sign, e.g. for `/setblock`, structures or world editors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sign {
+    front: SignText,
+    back: Option<SignText>,
+    waxed: bool,
+}
+
+impl Sign {
+    pub fn new(front: SignText) -> Self {
+        Sign {
+            front,
+            back: None,
+            waxed: false,
+        }
+    }
+
+    pub fn get_front(&self) -> &SignText {
+        &self.front
+    }
+
+    pub fn get_back(&self) -> Option<&SignText> {
+        self.back.as_ref()
+    }
+
+    /// Sets the text on the back, since 1.20. Older versions only have a front.
+    pub fn set_back(&mut self, back: Option<SignText>) {
+        self.back = back;
+    }
+
+    pub fn back(mut self, back: Option<SignText>) -> Self {
+        self.set_back(back);
+        self
+    }
+
+    pub fn is_waxed(&self) -> bool {
+        self.waxed
+    }
+
+    /// Sets whether players can't edit the sign anymore, since 1.20.
+    pub fn set_waxed(&mut self, waxed: bool) {
+        self.waxed = waxed;
+    }
+
+    pub fn waxed(mut self, waxed: bool) -> Self {
+        self.set_waxed(waxed);
+        self
+    }
+
+    /// The sign as NBT of the given version: `front_text`, `back_text` and `is_waxed`
+    /// since 1.20, `Text1` to `Text4` (with `Color` and `GlowingText`) before.
+    fn to_tag(&self, version: u32) -> Tag {
+        if version >= VERSION_1_20 {
+            let empty = SignText {
+                lines: vec![],
+                color: "black".to_string(),
+                glowing: false,
+            };
+            return Tag::Compound(vec![
+                ("front_text".to_string(), self.front.to_tag(version)),
+                (
+                    "back_text".to_string(),
+                    self.back.as_ref().unwrap_or(&empty).to_tag(version),
+                ),
+                ("is_waxed".to_string(), Tag::Byte(self.waxed as i8)),
+            ]);
+        }
+        let mut entries: Vec<(String, Tag)> = self
+            .front
+            .messages(version)
+            .into_iter()
+            .enumerate()
+            .map(|(i, line)| (format!("Text{}", i + 1), line))
+            .collect();
+        entries.push(("Color".to_string(), Tag::String(self.front.color.clone())));
+        if version >= VERSION_1_17 {
+            entries.push((
+                "GlowingText".to_string(),
+                Tag::Byte(self.front.glowing as i8),
+            ));
+        }
+        Tag::Compound(entries)
+    }
+
+    /// The block entity data as SNBT for a client of the given version.
+    pub fn to_snbt(&self, version: u32) -> String {
+        write_snbt(&self.to_tag(version))
+    }
+
+    /// The block entity data as network NBT for a client of the given version,
+    /// as sent in block entity data packets.
+    pub fn to_network_nbt(&self, version: u32) -> Vec<u8> {
+        write_network_nbt(&self.to_tag(version))
+    }
+}
@@ -0,0 +1,232 @@
+use serde_json::Value;
+
+use crate::component::ChatComponent;
+use crate::nbt::Tag;
+#[cfg(feature = "nbt")]
+use crate::nbt::{parse_snbt, NbtErr};
+
+/// Quotes a string with the quote it doesn't contain (preferring `"`) and escapes
+/// backslashes, the quote and control characters.
+fn quote(string: &str) -> String {
+    let quote = if string.contains('"') && !string.contains('\'') {
+        '\''
+    } else {
+        '"'
+    };
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push(quote);
+    for c in string.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c == quote => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push(quote);
+    quoted
+}
+
+/// Keys only need quotes if they contain other characters than these.
+fn is_plain_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_-.+".contains(c))
+}
+
+fn join<T, F: Fn(&T) -> String>(values: &[T], prefix: &str, format: F) -> String {
+    let values: Vec<String> = values.iter().map(format).collect();
+    format!("[{}{}]", prefix, values.join(","))
+}
+
+pub(crate) fn write_snbt(tag: &Tag) -> String {
+    match tag {
+        Tag::Byte(value) => format!("{}b", value),
+        Tag::Short(value) => format!("{}s", value),
+        Tag::Int(value) => value.to_string(),
+        Tag::Long(value) => format!("{}L", value),
+        Tag::Float(value) => format!("{}f", value),
+        Tag::Double(value) => format!("{}d", value),
+        Tag::ByteArray(values) => join(values, "B;", |value| format!("{}b", value)),
+        Tag::String(value) => quote(value),
+        // SNBT lists may mix types, so the compounds wrapping them aren't needed
+        Tag::List(tags) => join(tags, "", |tag| match tag {
+            Tag::Compound(entries) if entries.len() == 1 && entries[0].0.is_empty() => {
+                write_snbt(&entries[0].1)
+            }
+            tag => write_snbt(tag),
+        }),
+        Tag::Compound(entries) => {
+            let entries: Vec<String> = entries
+                .iter()
+                .map(|(key, tag)| {
+                    let key = if is_plain_key(key) {
+                        key.clone()
+                    } else {
+                        quote(key)
+                    };
+                    format!("{}:{}", key, write_snbt(tag))
+                })
+                .collect();
+            format!("{{{}}}", entries.join(","))
+        }
+        Tag::IntArray(values) => join(values, "I;", i32::to_string),
+        Tag::LongArray(values) => join(values, "L;", |value| format!("{}L", value)),
+    }
+}
+
+/// Rewrites the events of component JSON into their 1.21.5 form, e.g. `clickEvent`
+/// with a `value` becomes `click_event` with a `url`.
+fn modernize_events(value: &mut Value) {
+    let map = match value {
+        Value::Object(map) => map,
+        Value::Array(values) => {
+            values.iter_mut().for_each(modernize_events);
+            return;
+        }
+        _ => return,
+    };
+    for key in ["extra", "with"] {
+        if let Some(values) = map.get_mut(key) {
+            modernize_events(values);
+        }
+    }
+    if let Some(Value::Object(mut event)) = map.remove("clickEvent") {
+        let field = match event.get("action").and_then(Value::as_str) {
+            Some("open_url") => "url",
+            Some("run_command") | Some("suggest_command") => "command",
+            Some("change_page") => "page",
+            _ => "value",
+        };
+        if let Some(value) = event.remove("value") {
+            let value = match (field, value) {
+                ("page", Value::String(page)) => {
+                    page.parse::<i32>().map_or(Value::String(page), Value::from)
+                }
+                (_, value) => value,
+            };
+            event.insert(field.to_string(), value);
+        }
+        map.insert("click_event".to_string(), Value::Object(event));
+    }
+    if let Some(Value::Object(mut event)) = map.remove("hoverEvent") {
+        match event.get("action").and_then(Value::as_str) {
+            Some("show_text") => {
+                if let Some(text) = event.get_mut("value") {
+                    modernize_events(text);
+                }
+            }
+            Some("show_entity") => {
+                if let Some(Value::Object(mut entity)) = event.remove("contents") {
+                    if let Some(uuid) = entity.remove("id") {
+                        event.insert("uuid".to_string(), uuid);
+                    }
+                    if let Some(kind) = entity.remove("type") {
+                        event.insert("id".to_string(), kind);
+                    }
+                    if let Some(mut name) = entity.remove("name") {
+                        modernize_events(&mut name);
+                        event.insert("name".to_string(), name);
+                    }
+                }
+            }
+            _ => {}
+        }
+        map.insert("hover_event".to_string(), Value::Object(event));
+    }
+}
+
+/// Rewrites the 1.21.5 events of component JSON into the form this crate reads,
+/// the opposite of [`modernize_events()`]. Events already in the old form are kept.
+#[cfg(feature = "nbt")]
+fn legacy_events(value: &mut Value) {
+    let map = match value {
+        Value::Object(map) => map,
+        Value::Array(values) => {
+            values.iter_mut().for_each(legacy_events);
+            return;
+        }
+        _ => return,
+    };
+    for key in ["extra", "with"] {
+        if let Some(values) = map.get_mut(key) {
+            legacy_events(values);
+        }
+    }
+    if let Some(Value::Object(mut event)) = map.remove("click_event") {
+        for field in ["url", "command", "page"] {
+            if let Some(value) = event.remove(field) {
+                event.insert("value".to_string(), value);
+            }
+        }
+        map.insert("clickEvent".to_string(), Value::Object(event));
+    }
+    if let Some(Value::Object(mut event)) = map.remove("hover_event") {
+        match event.get("action").and_then(Value::as_str) {
+            Some("show_text") => {
+                if let Some(text) = event.get_mut("value") {
+                    legacy_events(text);
+                }
+            }
+            Some("show_entity") => {
+                let mut entity = serde_json::Map::new();
+                if let Some(kind) = event.remove("id") {
+                    entity.insert("type".to_string(), kind);
+                }
+                if let Some(uuid) = event.remove("uuid") {
+                    entity.insert("id".to_string(), uuid);
+                }
+                if let Some(mut name) = event.remove("name") {
+                    legacy_events(&mut name);
+                    entity.insert("name".to_string(), name);
+                }
+                event.insert("contents".to_string(), Value::Object(entity));
+            }
+            _ => {}
+        }
+        map.insert("hoverEvent".to_string(), Value::Object(event));
+    }
+}
+
+impl ChatComponent {
+    /// Parses component SNBT, like the text arguments of commands and the `custom_name`
+    /// of items since 1.21.5, see [`ChatComponent::to_snbt()`].
+    ///
+    /// Unquoted keys and strings, both quotes, mixed lists and the shorthands of
+    /// components (strings and lists) are supported. Events may be in their 1.21.5 form
+    /// (`click_event`) or the older one (`clickEvent`).
+    #[cfg(feature = "nbt")]
+    pub fn from_snbt(input: &str) -> Result<ChatComponent, NbtErr> {
+        let mut json = parse_snbt(input)?.to_json();
+        legacy_events(&mut json);
+        serde_json::from_value(super::expand_shorthands(json))
+            .map_err(|err| NbtErr::InvalidComponent(err.to_string()))
+    }
+
+    /// Converts this component into the SNBT used by commands and data packs since 1.21.5,
+    /// like `{text:"hi",color:"red",extra:[...]}`. Events are written in their 1.21.5 form
+    /// (`click_event` and `hover_event`), plain text without any style as a string.
+    pub fn to_snbt(&self) -> String {
+        write_snbt(&self.to_modern_nbt_tag())
+    }
+
+    /// The NBT of this component with its events in their 1.21.5 form.
+    pub(crate) fn to_modern_nbt_tag(&self) -> Tag {
+        match self.to_nbt_tag() {
+            Tag::String(text) => Tag::String(text),
+            _ => {
+                let mut json =
+                    serde_json::to_value(self).expect("components always serialize to JSON");
+                modernize_events(&mut json);
+                Tag::from_json(&json).unwrap_or_else(|| Tag::String(String::new()))
+            }
+        }
+    }
+}
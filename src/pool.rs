@@ -0,0 +1,92 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::sync::Arc;
+
+use crate::component::ChatComponent;
+
+/// A component shared by a [`ComponentPool`], together with its serialized form.
+#[derive(Debug)]
+pub struct PooledComponent {
+    component: ChatComponent,
+    #[cfg(feature = "json")]
+    json: Box<str>,
+}
+
+impl PooledComponent {
+    pub fn get_component(&self) -> &ChatComponent {
+        &self.component
+    }
+
+    /// The JSON of the component, serialized once when it was added to the pool.
+    #[cfg(feature = "json")]
+    pub fn get_json(&self) -> &str {
+        &self.json
+    }
+
+    /// The bytes of [`PooledComponent::get_json()`], ready to be written into packets.
+    #[cfg(feature = "json")]
+    pub fn get_bytes(&self) -> &[u8] {
+        self.json.as_bytes()
+    }
+}
+
+/// A registry deduplicating whole components, e.g. join, quit and announcement
+/// messages broadcast to every player.
+///
+/// Equal components written the same way share one [`PooledComponent`], so they are
+/// stored and serialized only once no matter how many players they are sent to.
+#[derive(Debug, Default)]
+pub struct ComponentPool {
+    /// The entries by the hash of their component
+    components: HashMap<u64, Vec<Arc<PooledComponent>>>,
+    hasher: RandomState,
+}
+
+impl ComponentPool {
+    pub fn new() -> Self {
+        ComponentPool::default()
+    }
+
+    /// Returns the shared copy of `component`, adding it to the pool if needed.
+    pub fn insert(&mut self, component: ChatComponent) -> Arc<PooledComponent> {
+        let entries = self
+            .components
+            .entry(self.hasher.hash_one(&component))
+            .or_default();
+        if let Some(pooled) = entries
+            .iter()
+            .find(|pooled| pooled.component.same_form(&component))
+        {
+            return pooled.clone();
+        }
+        #[cfg(feature = "json")]
+        let json = serde_json::to_string(&component)
+            .expect("components always serialize to JSON")
+            .into_boxed_str();
+        let pooled = Arc::new(PooledComponent {
+            component,
+            #[cfg(feature = "json")]
+            json,
+        });
+        entries.push(pooled.clone());
+        pooled
+    }
+
+    /// The amount of distinct components in this pool.
+    pub fn len(&self) -> usize {
+        self.components.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Removes every component that isn't held by anyone else anymore.
+    pub fn shrink(&mut self) {
+        self.components.retain(|_, entries| {
+            entries.retain(|pooled| Arc::strong_count(pooled) > 1);
+            !entries.is_empty()
+        });
+    }
+}
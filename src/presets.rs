@@ -0,0 +1,178 @@
+//! Builders for messages vanilla servers send, so they don't have to be assembled by hand.
+
+use crate::component::{ChatComponent, ComponentType, TranslationComponent};
+use crate::style::{ChatColor, ClickEvent, ComponentStyle, HoverEvent};
+
+/// The frame of an advancement, deciding its color and announcement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AdvancementFrame {
+    Task,
+    Goal,
+    Challenge,
+}
+
+impl AdvancementFrame {
+    pub fn get_color(&self) -> ChatColor {
+        match self {
+            AdvancementFrame::Task | AdvancementFrame::Goal => ChatColor::Green,
+            AdvancementFrame::Challenge => ChatColor::Purple,
+        }
+    }
+
+    /// The translation key of the chat announcement.
+    pub fn get_key(&self) -> &'static str {
+        match self {
+            AdvancementFrame::Task => "chat.type.advancement.task",
+            AdvancementFrame::Goal => "chat.type.advancement.goal",
+            AdvancementFrame::Challenge => "chat.type.advancement.challenge",
+        }
+    }
+}
+
+/// Builds the announcement of `player` completing an advancement, like
+/// `Steve has made the advancement [Stone Age]` where hovering the
+/// advancement shows its title and description.
+///
+/// The components are built with the version of `player`'s style.
+pub fn advancement(
+    player: ChatComponent,
+    title: ChatComponent,
+    description: ChatComponent,
+    frame: AdvancementFrame,
+) -> ChatComponent {
+    let version = player.get_version();
+
+    let mut hover = ChatComponent::from_text(
+        "",
+        ComponentStyle::with_version(version).color(Some(frame.get_color())),
+    );
+    hover.get_siblings_mut().push(title.clone());
+    hover.get_siblings_mut().push(ChatComponent::from_text(
+        "\n",
+        ComponentStyle::with_version(version),
+    ));
+    hover.get_siblings_mut().push(description);
+
+    let mut name = ChatComponent::from_text(
+        "[",
+        ComponentStyle::with_version(version)
+            .color(Some(frame.get_color()))
+            .hover_event(Some(HoverEvent::ShowText(Box::new(hover)))),
+    );
+    name.get_siblings_mut().push(title);
+    name.get_siblings_mut().push(ChatComponent::from_text(
+        "]",
+        ComponentStyle::with_version(version),
+    ));
+
+    ChatComponent::from_component(
+        ComponentType::Translation(
+            TranslationComponent::from_key(frame.get_key())
+                .argument(player)
+                .argument(name),
+        ),
+        ComponentStyle::with_version(version),
+    )
+}
+
+/// Prepares a line of item lore: clients render lore purple and italic by default,
+/// so lines that don't set these themselves are made white and non-italic.
+///
+/// Only the line's own style is changed, its children keep inheriting from it.
+pub fn lore_line(mut line: ChatComponent) -> ChatComponent {
+    if line.get_italic().is_none() {
+        line.set_italic(false);
+    }
+    line.set_color_if_absent(ChatColor::White);
+    line
+}
+
+/// Prepares every line of item lore, see [`lore_line()`].
+pub fn lore<I: IntoIterator<Item = ChatComponent>>(lines: I) -> Vec<ChatComponent> {
+    lines.into_iter().map(lore_line).collect()
+}
+
+/// Builds a button like `[Accept]` running `command` when clicked, hovering it shows `hint`.
+fn button(
+    label: &str,
+    color: ChatColor,
+    command: String,
+    hint: &str,
+    version: u32,
+) -> ChatComponent {
+    let hint = ChatComponent::from_text(
+        hint,
+        ComponentStyle::with_version(version).color(Some(color.clone())),
+    );
+    ChatComponent::from_text(
+        format!("[{}]", label),
+        ComponentStyle::with_version(version)
+            .color(Some(color))
+            .bold(true)
+            .click_event(Some(ClickEvent::run_command(command)))
+            .hover_event(Some(HoverEvent::ShowText(Box::new(hint)))),
+    )
+}
+
+/// Builds a question followed by `[Accept]` and `[Decline]` buttons running
+/// `yes_command` and `no_command`, e.g. for teleport requests or purchases.
+///
+/// The components are built with the version of `question`'s style.
+pub fn confirm_prompt<T: Into<String>, U: Into<String>>(
+    question: ChatComponent,
+    yes_command: T,
+    no_command: U,
+) -> ChatComponent {
+    let version = question.get_version();
+    let space = || ChatComponent::from_text(" ", ComponentStyle::with_version(version));
+
+    let mut prompt = ChatComponent::from_text("", ComponentStyle::with_version(version));
+    prompt.get_siblings_mut().extend(vec![
+        question,
+        space(),
+        button(
+            "Accept",
+            ChatColor::Green,
+            yes_command.into(),
+            "Click to accept",
+            version,
+        ),
+        space(),
+        button(
+            "Decline",
+            ChatColor::Red,
+            no_command.into(),
+            "Click to decline",
+            version,
+        ),
+    ]);
+    prompt
+}
+
+/// Joins `components` like vanilla lists the entities a selector matches,
+/// e.g. `A, B and C` with `", "` and `" and "` as separators.
+///
+/// Vanilla uses a gray `", "` for every separator. An empty list becomes an empty
+/// component built with the version of `separator`'s style.
+pub fn format_list<I: IntoIterator<Item = ChatComponent>>(
+    components: I,
+    separator: ChatComponent,
+    last_separator: ChatComponent,
+) -> ChatComponent {
+    let mut components: Vec<ChatComponent> = components.into_iter().collect();
+    if components.len() == 1 {
+        return components.remove(0);
+    }
+    let mut list =
+        ChatComponent::from_text("", ComponentStyle::with_version(separator.get_version()));
+    let count = components.len();
+    for (i, component) in components.into_iter().enumerate() {
+        if i + 1 == count && i > 0 {
+            list.get_siblings_mut().push(last_separator.clone());
+        } else if i > 0 {
+            list.get_siblings_mut().push(separator.clone());
+        }
+        list.get_siblings_mut().push(component);
+    }
+    list
+}
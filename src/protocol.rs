@@ -0,0 +1,140 @@
+//! Writing and reading components the way packets send them.
+
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "bytes")]
+use bytes::BufMut;
+
+use crate::component::{ChatComponent, ComponentJsonErr};
+#[cfg(feature = "nbt")]
+use crate::nbt::NbtErr;
+
+/// The error returned when a component can't be read from packet data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PacketErr {
+    /// The input ends before the component does, more data has to be read first
+    Incomplete,
+    /// A VarInt longer than 5 bytes or a negative length
+    InvalidLength,
+    /// A JSON string that isn't valid UTF-8
+    InvalidString,
+    /// The JSON isn't a valid component
+    Json(ComponentJsonErr),
+    /// The NBT isn't a valid component
+    #[cfg(feature = "nbt")]
+    Nbt(NbtErr),
+}
+
+impl Display for PacketErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PacketErr::Incomplete => write!(f, "The packet ends before the component"),
+            PacketErr::InvalidLength => write!(f, "Invalid string length"),
+            PacketErr::InvalidString => write!(f, "Invalid UTF-8 in string"),
+            PacketErr::Json(err) => err.fmt(f),
+            #[cfg(feature = "nbt")]
+            PacketErr::Nbt(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for PacketErr {}
+
+/// The version number of the Minecraft protocol for 1.20.3, the first sending
+/// components as network NBT.
+#[cfg(feature = "nbt")]
+const VERSION_1_20_3: u32 = 765;
+
+/// Writes `value` as a VarInt, 7 bits per byte with the highest bit set on all but the last.
+fn write_varint(value: i32, out: &mut Vec<u8>) {
+    let mut value = value as u32;
+    loop {
+        if value & !0x7F == 0 {
+            out.push(value as u8);
+            return;
+        }
+        out.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+}
+
+fn read_varint(input: &mut &[u8]) -> Result<i32, PacketErr> {
+    let mut value = 0u32;
+    for i in 0..5 {
+        let byte = *input.get(i).ok_or(PacketErr::Incomplete)?;
+        value |= ((byte & 0x7F) as u32) << (7 * i);
+        if byte & 0x80 == 0 {
+            *input = &input[i + 1..];
+            return Ok(value as i32);
+        }
+    }
+    Err(PacketErr::InvalidLength)
+}
+
+impl ChatComponent {
+    /// Appends this component to `out` as a protocol string of its JSON: the length
+    /// in bytes as a VarInt followed by the UTF-8, which packets used before 1.20.3.
+    pub fn write_packet_json(&self, out: &mut Vec<u8>) {
+        let json = serde_json::to_string(self).expect("components always serialize to JSON");
+        write_varint(json.len() as i32, out);
+        out.extend_from_slice(json.as_bytes());
+    }
+
+    /// Appends this component to `out` the way packets of `version` send it, as
+    /// network NBT since 1.20.3 and as a JSON string before, see
+    /// [`ChatComponent::to_network_nbt()`] and [`ChatComponent::write_packet_json()`].
+    #[cfg(feature = "nbt")]
+    pub fn write_packet(&self, version: u32, out: &mut Vec<u8>) {
+        if version >= VERSION_1_20_3 {
+            out.extend_from_slice(&self.to_network_nbt());
+        } else {
+            self.write_packet_json(out);
+        }
+    }
+
+    /// Writes this component into `buf` the way packets of `version` send it,
+    /// like [`ChatComponent::write_packet()`] does into a [`Vec`].
+    #[cfg(feature = "bytes")]
+    pub fn write_to_buf<B: BufMut>(&self, version: u32, buf: &mut B) {
+        let mut out = vec![];
+        self.write_packet(version, &mut out);
+        buf.put_slice(&out);
+    }
+
+    /// Reads a component sent as a JSON protocol string from the start of `input`,
+    /// see [`ChatComponent::write_packet_json()`].
+    ///
+    /// `input` is only advanced past the component if it could be read, so
+    /// [`PacketErr::Incomplete`] can be retried once more data arrived, e.g. by a
+    /// codec framing components from a stream.
+    pub fn read_packet_json(input: &mut &[u8]) -> Result<ChatComponent, PacketErr> {
+        let mut rest = *input;
+        let length =
+            usize::try_from(read_varint(&mut rest)?).map_err(|_| PacketErr::InvalidLength)?;
+        if rest.len() < length {
+            return Err(PacketErr::Incomplete);
+        }
+        let (json, rest) = rest.split_at(length);
+        let json = std::str::from_utf8(json).map_err(|_| PacketErr::InvalidString)?;
+        let component = ChatComponent::from_json(json).map_err(PacketErr::Json)?;
+        *input = rest;
+        Ok(component)
+    }
+
+    /// Reads a component sent by packets of `version` from the start of `input`, see
+    /// [`ChatComponent::write_packet()`] and [`ChatComponent::read_packet_json()`].
+    #[cfg(feature = "nbt")]
+    pub fn read_packet(version: u32, input: &mut &[u8]) -> Result<ChatComponent, PacketErr> {
+        if version < VERSION_1_20_3 {
+            return ChatComponent::read_packet_json(input);
+        }
+        let mut rest = *input;
+        let component = ChatComponent::from_network_nbt(&mut rest).map_err(|err| match err {
+            NbtErr::UnexpectedEnd => PacketErr::Incomplete,
+            err => PacketErr::Nbt(err),
+        })?;
+        *input = rest;
+        Ok(component)
+    }
+}
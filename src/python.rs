@@ -0,0 +1,87 @@
+//! Python bindings, enabled with the `python` feature.
+//!
+//! The exposed functions work on strings only, so scripts can pass in
+//! whatever they read from logs or packets and get the crate's output back.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::component::ChatComponent;
+use crate::formats;
+use crate::style::{ChatColor, ComponentStyle, VERSION_1_16};
+
+fn read(input: &str, format: &str) -> PyResult<ChatComponent> {
+    formats::read(input, format).map_err(PyValueError::new_err)
+}
+
+fn write(component: &ChatComponent, format: &str) -> PyResult<String> {
+    formats::write(component, format).map_err(PyValueError::new_err)
+}
+
+/// Parses a raw JSON message and serializes it again for the given protocol version.
+#[pyfunction]
+#[pyo3(signature = (json, version = VERSION_1_16))]
+fn parse(json: &str, version: u32) -> PyResult<String> {
+    convert(json, "json", "json", version)
+}
+
+/// Builds a text component and returns it as raw JSON.
+#[pyfunction]
+#[pyo3(signature = (text, color = None, bold = None, italic = None, underlined = None, version = VERSION_1_16))]
+fn text(
+    text: &str,
+    color: Option<String>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    underlined: Option<bool>,
+    version: u32,
+) -> PyResult<String> {
+    let mut style = ComponentStyle::with_version(version);
+    if let Some(color) = color {
+        let color: ChatColor = serde_json::from_value(serde_json::Value::String(color))
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        style.set_color(Some(color));
+    }
+    if let Some(bold) = bold {
+        style.set_bold(bold);
+    }
+    if let Some(italic) = italic {
+        style.set_italic(italic);
+    }
+    if let Some(underlined) = underlined {
+        style.set_underlined(underlined);
+    }
+    write(&ChatComponent::from_text(text, style), "json")
+}
+
+/// Builds a translation component from a key and raw JSON arguments.
+#[pyfunction]
+#[pyo3(signature = (key, args = Vec::new(), version = VERSION_1_16))]
+fn translate(key: &str, args: Vec<String>, version: u32) -> PyResult<String> {
+    let mut component = ChatComponent::from_key(key, ComponentStyle::with_version(version));
+    if let crate::ComponentType::Translation(translation) = component.get_kind_mut() {
+        for arg in args {
+            translation.add_arg(read(&arg, "json")?);
+        }
+    }
+    component.change_version(version);
+    write(&component, "json")
+}
+
+/// Converts a message from one format into another for the given protocol version.
+#[pyfunction]
+#[pyo3(signature = (input, from_format = "json", to_format = "json", version = VERSION_1_16))]
+fn convert(input: &str, from_format: &str, to_format: &str, version: u32) -> PyResult<String> {
+    let mut component = read(input, from_format)?;
+    component.downgrade(version);
+    write(&component, to_format)
+}
+
+#[pymodule]
+fn mc_chat(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(parse, module)?)?;
+    module.add_function(wrap_pyfunction!(text, module)?)?;
+    module.add_function(wrap_pyfunction!(translate, module)?)?;
+    module.add_function(wrap_pyfunction!(convert, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,256 @@
+//! Rendering components as Slack's mrkdwn, e.g. for chat bridge bots.
+
+use crate::component::{
+    default_key_name, translation_pieces, ChatComponent, ComponentType, TranslationPiece,
+};
+use crate::style::{ClickEvent, ComponentStyle};
+
+/// How a decoration Slack can't display is rendered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlackFallback {
+    /// Like text without the decoration
+    Plain,
+    Bold,
+    Italic,
+    Strikethrough,
+    /// As inline code
+    Code,
+    /// Left out of the output
+    Hide,
+    /// Every character that isn't whitespace is replaced with this one
+    Mask(char),
+}
+
+/// Options for [`ChatComponent::to_slack_mrkdwn()`].
+#[derive(Clone, Debug)]
+pub struct SlackOptions {
+    underlined: SlackFallback,
+    obfuscated: SlackFallback,
+}
+
+impl Default for SlackOptions {
+    /// Underlined and obfuscated text is rendered as plain text.
+    fn default() -> Self {
+        SlackOptions {
+            underlined: SlackFallback::Plain,
+            obfuscated: SlackFallback::Plain,
+        }
+    }
+}
+
+impl SlackOptions {
+    pub fn new() -> Self {
+        SlackOptions::default()
+    }
+
+    pub fn get_underlined(&self) -> SlackFallback {
+        self.underlined
+    }
+
+    pub fn set_underlined(&mut self, underlined: SlackFallback) {
+        self.underlined = underlined;
+    }
+
+    pub fn underlined(mut self, underlined: SlackFallback) -> Self {
+        self.underlined = underlined;
+        self
+    }
+
+    pub fn get_obfuscated(&self) -> SlackFallback {
+        self.obfuscated
+    }
+
+    /// Sets how obfuscated text is rendered, [`SlackFallback::Hide`] or
+    /// [`SlackFallback::Mask`] keep it secret like Minecraft does.
+    pub fn set_obfuscated(&mut self, obfuscated: SlackFallback) {
+        self.obfuscated = obfuscated;
+    }
+
+    pub fn obfuscated(mut self, obfuscated: SlackFallback) -> Self {
+        self.obfuscated = obfuscated;
+        self
+    }
+}
+
+/// The formatting Slack displays for a run of text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct SlackState {
+    bold: bool,
+    italic: bool,
+    strikethrough: bool,
+    code: bool,
+    link: Option<String>,
+}
+
+/// Escapes the characters Slack reads as control sequences. Slack has no way to
+/// escape `*`, `_` and `~`, they are only formatting next to word boundaries anyway.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// The url of `style` if Slack would link it.
+fn link(style: &ComponentStyle) -> Option<String> {
+    match style.get_click_event() {
+        Some(ClickEvent::OpenUrl(url))
+            if url.starts_with("http://") || url.starts_with("https://") =>
+        {
+            Some(
+                url.replace(' ', "%20")
+                    .replace('|', "%7C")
+                    .replace('<', "%3C")
+                    .replace('>', "%3E"),
+            )
+        }
+        _ => None,
+    }
+}
+
+struct SlackWriter<'a> {
+    options: &'a SlackOptions,
+    /// Runs of text with their formatting, adjacent runs are merged
+    runs: Vec<(String, SlackState)>,
+}
+
+impl SlackWriter<'_> {
+    /// Adds `text` in `style`, with the fallbacks for decorations Slack can't display.
+    fn push(&mut self, text: &str, style: &ComponentStyle) {
+        let mut state = SlackState {
+            bold: style.get_bold() == Some(true),
+            italic: style.get_italic() == Some(true),
+            strikethrough: style.get_strikethrough() == Some(true),
+            code: false,
+            link: link(style),
+        };
+        let mut text = text.to_string();
+        let fallbacks = [
+            (style.get_underlined(), self.options.underlined),
+            (style.get_obfuscated(), self.options.obfuscated),
+        ];
+        for (_, fallback) in fallbacks.iter().filter(|(value, _)| *value == Some(true)) {
+            match fallback {
+                SlackFallback::Plain => {}
+                SlackFallback::Bold => state.bold = true,
+                SlackFallback::Italic => state.italic = true,
+                SlackFallback::Strikethrough => state.strikethrough = true,
+                SlackFallback::Code => state.code = true,
+                SlackFallback::Hide => return,
+                SlackFallback::Mask(mask) => {
+                    text = text
+                        .chars()
+                        .map(|c| if c.is_whitespace() { c } else { *mask })
+                        .collect()
+                }
+            }
+        }
+        if text.is_empty() {
+            return;
+        }
+        match self.runs.last_mut() {
+            Some((last, last_state)) if *last_state == state => last.push_str(&text),
+            _ => self.runs.push((text, state)),
+        }
+    }
+
+    fn write(&mut self, component: &ChatComponent, parent: Option<&ComponentStyle>) {
+        let mut style = component.get_style().clone();
+        if let Some(parent) = parent {
+            style.inherit(parent);
+        }
+        match component.get_kind() {
+            ComponentType::Text(text) => self.push(text.get_text(), &style),
+            ComponentType::Translation(translation) => {
+                for piece in translation_pieces(translation.get_key()) {
+                    match piece {
+                        TranslationPiece::Text(text) => self.push(&text, &style),
+                        TranslationPiece::Arg(index) => {
+                            if let Some(arg) = translation.get_args().get(index) {
+                                self.write(arg, Some(&style));
+                            }
+                        }
+                    }
+                }
+            }
+            ComponentType::Score(score) => self.push(score.get_value().unwrap_or_default(), &style),
+            ComponentType::Selector(selector) => self.push(selector.get_selector(), &style),
+            ComponentType::Keybind(keybind) => self.push(
+                default_key_name(keybind.get_keybind()).unwrap_or(keybind.get_keybind()),
+                &style,
+            ),
+            ComponentType::Nbt(_) => {}
+            ComponentType::Custom(custom) => self.push(&custom.plain_text(), &style),
+        }
+        for sibling in component.get_siblings() {
+            self.write(sibling, Some(&style));
+        }
+    }
+
+    fn finish(self) -> String {
+        let mut out = String::new();
+        let mut runs = self.runs.iter().peekable();
+        while let Some((text, state)) = runs.next() {
+            let mut label = render_run(text, state);
+            if let Some(url) = &state.link {
+                // a link is one label, with all runs linking to the same url in it
+                while let Some((text, next)) =
+                    runs.peek().filter(|(_, next)| next.link == state.link)
+                {
+                    label.push_str(&render_run(text, next));
+                    runs.next();
+                }
+                out.push_str(&format!("<{}|{}>", url, label));
+            } else {
+                out.push_str(&label);
+            }
+        }
+        out
+    }
+}
+
+/// Renders one run, keeping surrounding whitespace outside of the markers
+/// since Slack only reads markers next to the text.
+fn render_run(text: &str, state: &SlackState) -> String {
+    let core = text.trim();
+    if core.is_empty() {
+        return escape(text);
+    }
+    let start = text.len() - text.trim_start().len();
+    let end = start + core.len();
+    let mut markers = String::new();
+    for (enabled, marker) in [
+        (state.bold, '*'),
+        (state.italic, '_'),
+        (state.strikethrough, '~'),
+        (state.code, '`'),
+    ] {
+        if enabled {
+            markers.push(marker);
+        }
+    }
+    let closing: String = markers.chars().rev().collect();
+    format!(
+        "{}{}{}{}{}",
+        &text[..start],
+        markers,
+        escape(core),
+        closing,
+        &text[end..]
+    )
+}
+
+impl ChatComponent {
+    /// Renders this component as Slack's mrkdwn: bold, italic and strikethrough text
+    /// get their markers and `open_url` click events become `<url|label>` links.
+    ///
+    /// Colors and other events are dropped, underlined and obfuscated text is rendered
+    /// as set in `options`.
+    pub fn to_slack_mrkdwn(&self, options: &SlackOptions) -> String {
+        let mut writer = SlackWriter {
+            options,
+            runs: vec![],
+        };
+        writer.write(self, None);
+        writer.finish()
+    }
+}
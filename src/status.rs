@@ -0,0 +1,263 @@
+use crate::component::ChatComponent;
+
+#[cfg(feature = "json")]
+use serde::Deserializer;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "json")]
+use serde_json::Value;
+
+/// The `version` of a [`StatusResponse`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StatusVersion {
+    name: String,
+    protocol: i32,
+}
+
+impl StatusVersion {
+    pub fn new<T: Into<String>>(name: T, protocol: i32) -> Self {
+        StatusVersion {
+            name: name.into(),
+            protocol,
+        }
+    }
+
+    /// A version no client matches, so every client shows `text` (in red)
+    /// instead of the player count, e.g. `"Maintenance"`.
+    pub fn text<T: Into<String>>(text: T) -> Self {
+        StatusVersion::new(text, -1)
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_protocol(&self) -> i32 {
+        self.protocol
+    }
+}
+
+/// A player of the sample shown when hovering the player count.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StatusPlayer {
+    name: String,
+    id: String,
+}
+
+impl StatusPlayer {
+    pub fn new<T: Into<String>, U: Into<String>>(name: T, id: U) -> Self {
+        StatusPlayer {
+            name: name.into(),
+            id: id.into(),
+        }
+    }
+
+    /// A sample entry showing `line` (as legacy text) instead of a player,
+    /// servers use these to show custom text when hovering the player count.
+    pub fn line(line: &ChatComponent) -> Self {
+        StatusPlayer::new(
+            line.to_legacy_string(),
+            "00000000-0000-0000-0000-000000000000",
+        )
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// The `players` of a [`StatusResponse`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StatusPlayers {
+    max: i32,
+    online: i32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_serializing_if = "Vec::is_empty", default)
+    )]
+    sample: Vec<StatusPlayer>,
+}
+
+impl StatusPlayers {
+    pub fn new(online: i32, max: i32) -> Self {
+        StatusPlayers {
+            max,
+            online,
+            sample: vec![],
+        }
+    }
+
+    pub fn get_max(&self) -> i32 {
+        self.max
+    }
+
+    pub fn get_online(&self) -> i32 {
+        self.online
+    }
+
+    pub fn get_sample(&self) -> &Vec<StatusPlayer> {
+        &self.sample
+    }
+
+    pub fn get_sample_mut(&mut self) -> &mut Vec<StatusPlayer> {
+        &mut self.sample
+    }
+
+    pub fn player(mut self, player: StatusPlayer) -> Self {
+        self.sample.push(player);
+        self
+    }
+}
+
+/// The response to a server list ping, containing the MOTD (`description`),
+/// player count and version shown in the server list.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct StatusResponse {
+    version: StatusVersion,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    players: Option<StatusPlayers>,
+    #[cfg_attr(feature = "json", serde(deserialize_with = "deserialize_description"))]
+    description: ChatComponent,
+    /// A `data:image/png;base64,` url of a 64x64 image.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    favicon: Option<String>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    enforces_secure_chat: Option<bool>,
+}
+
+/// Reads the description with [`StatusResponse::parse_description()`].
+#[cfg(feature = "json")]
+fn deserialize_description<'de, D>(deserializer: D) -> Result<ChatComponent, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let description = Value::deserialize(deserializer)?;
+    StatusResponse::parse_description(&description, crate::style::default_style_version())
+        .map_err(serde::de::Error::custom)
+}
+
+impl StatusResponse {
+    /// Parses the `description` (MOTD) of a response as sent by any server: a legacy string,
+    /// a component, or a component with legacy codes in its texts. All of them are
+    /// returned as components without legacy codes, see [`ChatComponent::expand_legacy_codes()`].
+    ///
+    /// Deserializing a [`StatusResponse`] reads its description like this.
+    #[cfg(feature = "json")]
+    pub fn parse_description(
+        description: &Value,
+        version: u32,
+    ) -> Result<ChatComponent, serde_json::Error> {
+        if let Value::String(text) = description {
+            return Ok(ChatComponent::from_legacy_text(text, version));
+        }
+        let mut component = ChatComponent::deserialize(description)?;
+        component.change_version(version);
+        component.expand_legacy_codes();
+        Ok(component)
+    }
+
+    pub fn new(version: StatusVersion, description: ChatComponent) -> Self {
+        StatusResponse {
+            version,
+            players: None,
+            description,
+            favicon: None,
+            enforces_secure_chat: None,
+        }
+    }
+
+    pub fn get_version(&self) -> &StatusVersion {
+        &self.version
+    }
+
+    pub fn get_players(&self) -> Option<&StatusPlayers> {
+        self.players.as_ref()
+    }
+
+    pub fn set_players(&mut self, players: Option<StatusPlayers>) {
+        self.players = players;
+    }
+
+    /// Sets the player count, [`None`] shows `???` instead.
+    pub fn players(mut self, players: Option<StatusPlayers>) -> Self {
+        self.set_players(players);
+        self
+    }
+
+    pub fn get_description(&self) -> &ChatComponent {
+        &self.description
+    }
+
+    pub fn get_favicon(&self) -> Option<&str> {
+        self.favicon.as_deref()
+    }
+
+    pub fn set_favicon<T: Into<String>>(&mut self, favicon: Option<T>) {
+        self.favicon = favicon.map(|favicon| favicon.into());
+    }
+
+    pub fn favicon<T: Into<String>>(mut self, favicon: Option<T>) -> Self {
+        self.set_favicon(favicon);
+        self
+    }
+
+    pub fn get_enforces_secure_chat(&self) -> Option<bool> {
+        self.enforces_secure_chat
+    }
+
+    pub fn set_enforces_secure_chat(&mut self, enforces_secure_chat: Option<bool>) {
+        self.enforces_secure_chat = enforces_secure_chat;
+    }
+
+    pub fn enforces_secure_chat(mut self, enforces_secure_chat: Option<bool>) -> Self {
+        self.set_enforces_secure_chat(enforces_secure_chat);
+        self
+    }
+
+    /// Serializes this response as the JSON sent to a client of the given version,
+    /// the description is downgraded for older clients.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self, version: u32) -> String {
+        let mut response = self.clone();
+        response.description.downgrade(version);
+        serde_json::to_string(&response).expect("status responses always serialize to JSON")
+    }
+
+    fn legacy_count(&self) -> (i32, i32) {
+        self.players
+            .as_ref()
+            .map_or((0, 0), |players| (players.online, players.max))
+    }
+
+    /// Builds the kick message answering a legacy ping of 1.4 to 1.6 clients,
+    /// sent UTF-16 encoded like every legacy string.
+    pub fn to_legacy_response(&self) -> String {
+        let (online, max) = self.legacy_count();
+        format!(
+            "§1\0{}\0{}\0{}\0{}\0{}",
+            self.version.protocol,
+            self.version.name,
+            self.description.to_legacy_string(),
+            online,
+            max
+        )
+    }
+
+    /// Builds the kick message answering a ping of Beta 1.8 to 1.3 clients,
+    /// which only show the MOTD and player count.
+    pub fn to_beta_response(&self) -> String {
+        let (online, max) = self.legacy_count();
+        // `§` separates the fields, so the MOTD can't be formatted
+        let motd = self.description.to_plain_text().replace('§', "");
+        format!("{}§{}§{}", motd, online, max)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::component::ChatComponent;
+#[cfg(feature = "serde")]
+use crate::nbt::{parse_snbt, Tag};
+
+/// The entity shown by a `show_entity` [`HoverEvent`](crate::HoverEvent).
+///
+/// Since 1.16 it is sent as an object in `contents` with the name as a nested component,
+/// older versions expect an SNBT string in `value` with the name as JSON text.
+/// Both are read, and styles serialize the one matching their version.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EntityTooltip {
+    kind: String,
+    id: String,
+    name: Option<Box<ChatComponent>>,
+}
+
+impl EntityTooltip {
+    /// `kind` is the entity type (e.g. `minecraft:pig`) and `id` its UUID.
+    pub fn new<T: Into<String>, U: Into<String>>(kind: T, id: U) -> Self {
+        EntityTooltip {
+            kind: kind.into(),
+            id: id.into(),
+            name: None,
+        }
+    }
+
+    pub fn get_kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn get_name(&self) -> Option<&ChatComponent> {
+        self.name.as_deref()
+    }
+
+    pub fn get_name_mut(&mut self) -> Option<&mut ChatComponent> {
+        self.name.as_deref_mut()
+    }
+
+    pub fn set_name(&mut self, name: Option<ChatComponent>) {
+        self.name = name.map(Box::new);
+    }
+
+    pub fn name(mut self, name: Option<ChatComponent>) -> Self {
+        self.set_name(name);
+        self
+    }
+
+    /// Builds the SNBT string used before 1.16, e.g.
+    /// `{type:"minecraft:pig",id:"...",name:"{\"text\":\"Pig\"}"}`.
+    ///
+    /// The name is JSON text, without the `json` feature [`None`] is returned if there is one.
+    #[cfg(feature = "serde")]
+    pub(crate) fn to_legacy_value(&self) -> Option<String> {
+        let mut value = format!(
+            "{{type:{},id:{}",
+            quote_snbt(&self.kind),
+            quote_snbt(&self.id)
+        );
+        #[cfg(feature = "json")]
+        if let Some(name) = &self.name {
+            let name = serde_json::to_string(name).expect("components always serialize to JSON");
+            value.push_str(",name:");
+            value.push_str(&quote_snbt(&name));
+        }
+        #[cfg(not(feature = "json"))]
+        if self.name.is_some() {
+            return None;
+        }
+        value.push('}');
+        Some(value)
+    }
+
+    /// Reads the SNBT string used before 1.16, [`None`] if it isn't one
+    /// or its name isn't JSON text (like before 1.13). Names need the `json` feature.
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_legacy_value(value: &str) -> Option<EntityTooltip> {
+        let entries = match parse_snbt(value).ok()? {
+            Tag::Compound(entries) => entries,
+            _ => return None,
+        };
+        let mut tooltip = EntityTooltip::new("", "");
+        let (mut kind, mut id) = (None, None);
+        for (key, value) in entries {
+            let value = match (key.as_str(), value) {
+                ("type" | "id" | "name", Tag::String(value)) => value,
+                ("type" | "id" | "name", _) => return None,
+                _ => continue,
+            };
+            match key.as_str() {
+                "type" => kind = Some(value),
+                "id" => id = Some(value),
+                #[cfg(feature = "json")]
+                _ => tooltip.set_name(Some(serde_json::from_str(&value).ok()?)),
+                #[cfg(not(feature = "json"))]
+                _ => return None,
+            }
+        }
+        tooltip.kind = kind?;
+        tooltip.id = id?;
+        Some(tooltip)
+    }
+}
+
+/// Always quotes with `"`, single quoted strings are only read since 1.14.
+#[cfg(feature = "serde")]
+fn quote_snbt(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,210 @@
+use crate::style::{ChatColor, ComponentStyle};
+
+/// Every color and decoration that can be expressed with a legacy
+/// formatting code (`§a`, `§l`...), named like [`ChatColor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChatFormatting {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkCyan,
+    DarkRed,
+    Purple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Cyan,
+    Red,
+    Pink,
+    Yellow,
+    White,
+    Obfuscated,
+    Bold,
+    Strikethrough,
+    Underlined,
+    Italic,
+    Reset,
+}
+
+impl ChatFormatting {
+    /// The character that starts a legacy formatting code.
+    pub const SECTION_SIGN: char = '§';
+
+    pub const ALL: [ChatFormatting; 22] = [
+        ChatFormatting::Black,
+        ChatFormatting::DarkBlue,
+        ChatFormatting::DarkGreen,
+        ChatFormatting::DarkCyan,
+        ChatFormatting::DarkRed,
+        ChatFormatting::Purple,
+        ChatFormatting::Gold,
+        ChatFormatting::Gray,
+        ChatFormatting::DarkGray,
+        ChatFormatting::Blue,
+        ChatFormatting::Green,
+        ChatFormatting::Cyan,
+        ChatFormatting::Red,
+        ChatFormatting::Pink,
+        ChatFormatting::Yellow,
+        ChatFormatting::White,
+        ChatFormatting::Obfuscated,
+        ChatFormatting::Bold,
+        ChatFormatting::Strikethrough,
+        ChatFormatting::Underlined,
+        ChatFormatting::Italic,
+        ChatFormatting::Reset,
+    ];
+
+    /// The character following `§` in legacy text.
+    pub fn get_code(&self) -> char {
+        match self {
+            ChatFormatting::Black => '0',
+            ChatFormatting::DarkBlue => '1',
+            ChatFormatting::DarkGreen => '2',
+            ChatFormatting::DarkCyan => '3',
+            ChatFormatting::DarkRed => '4',
+            ChatFormatting::Purple => '5',
+            ChatFormatting::Gold => '6',
+            ChatFormatting::Gray => '7',
+            ChatFormatting::DarkGray => '8',
+            ChatFormatting::Blue => '9',
+            ChatFormatting::Green => 'a',
+            ChatFormatting::Cyan => 'b',
+            ChatFormatting::Red => 'c',
+            ChatFormatting::Pink => 'd',
+            ChatFormatting::Yellow => 'e',
+            ChatFormatting::White => 'f',
+            ChatFormatting::Obfuscated => 'k',
+            ChatFormatting::Bold => 'l',
+            ChatFormatting::Strikethrough => 'm',
+            ChatFormatting::Underlined => 'n',
+            ChatFormatting::Italic => 'o',
+            ChatFormatting::Reset => 'r',
+        }
+    }
+
+    /// Looks up a legacy code character, ignoring its case.
+    pub fn from_code(code: char) -> Option<ChatFormatting> {
+        let code = code.to_ascii_lowercase();
+        ChatFormatting::ALL
+            .iter()
+            .copied()
+            .find(|formatting| formatting.get_code() == code)
+    }
+
+    /// The name used by vanilla, e.g. in JSON colors and `/team modify`.
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ChatFormatting::Black => "black",
+            ChatFormatting::DarkBlue => "dark_blue",
+            ChatFormatting::DarkGreen => "dark_green",
+            ChatFormatting::DarkCyan => "dark_aqua",
+            ChatFormatting::DarkRed => "dark_red",
+            ChatFormatting::Purple => "dark_purple",
+            ChatFormatting::Gold => "gold",
+            ChatFormatting::Gray => "gray",
+            ChatFormatting::DarkGray => "dark_gray",
+            ChatFormatting::Blue => "blue",
+            ChatFormatting::Green => "green",
+            ChatFormatting::Cyan => "aqua",
+            ChatFormatting::Red => "red",
+            ChatFormatting::Pink => "light_purple",
+            ChatFormatting::Yellow => "yellow",
+            ChatFormatting::White => "white",
+            ChatFormatting::Obfuscated => "obfuscated",
+            ChatFormatting::Bold => "bold",
+            ChatFormatting::Strikethrough => "strikethrough",
+            ChatFormatting::Underlined => "underline",
+            ChatFormatting::Italic => "italic",
+            ChatFormatting::Reset => "reset",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ChatFormatting> {
+        ChatFormatting::ALL
+            .iter()
+            .copied()
+            .find(|formatting| formatting.get_name() == name)
+    }
+
+    /// Returns whether this is one of the 16 colors.
+    pub fn is_color(&self) -> bool {
+        !self.is_decoration() && *self != ChatFormatting::Reset
+    }
+
+    /// Returns whether this is a decoration like bold or italic.
+    pub fn is_decoration(&self) -> bool {
+        matches!(
+            self,
+            ChatFormatting::Obfuscated
+                | ChatFormatting::Bold
+                | ChatFormatting::Strikethrough
+                | ChatFormatting::Underlined
+                | ChatFormatting::Italic
+        )
+    }
+
+    /// Returns the color of this formatting, [`ChatColor::Reset`] for [`ChatFormatting::Reset`].
+    pub fn to_color(&self) -> Option<ChatColor> {
+        Some(match self {
+            ChatFormatting::Black => ChatColor::Black,
+            ChatFormatting::DarkBlue => ChatColor::DarkBlue,
+            ChatFormatting::DarkGreen => ChatColor::DarkGreen,
+            ChatFormatting::DarkCyan => ChatColor::DarkCyan,
+            ChatFormatting::DarkRed => ChatColor::DarkRed,
+            ChatFormatting::Purple => ChatColor::Purple,
+            ChatFormatting::Gold => ChatColor::Gold,
+            ChatFormatting::Gray => ChatColor::Gray,
+            ChatFormatting::DarkGray => ChatColor::DarkGray,
+            ChatFormatting::Blue => ChatColor::Blue,
+            ChatFormatting::Green => ChatColor::Green,
+            ChatFormatting::Cyan => ChatColor::Cyan,
+            ChatFormatting::Red => ChatColor::Red,
+            ChatFormatting::Pink => ChatColor::Pink,
+            ChatFormatting::Yellow => ChatColor::Yellow,
+            ChatFormatting::White => ChatColor::White,
+            ChatFormatting::Reset => ChatColor::Reset,
+            _ => return None,
+        })
+    }
+
+    /// Returns the formatting of a color, [`None`] for [`ChatColor::Rgb`] and [`ChatColor::Custom`].
+    pub fn from_color(color: &ChatColor) -> Option<ChatFormatting> {
+        Some(match color {
+            ChatColor::Black => ChatFormatting::Black,
+            ChatColor::DarkBlue => ChatFormatting::DarkBlue,
+            ChatColor::DarkGreen => ChatFormatting::DarkGreen,
+            ChatColor::DarkCyan => ChatFormatting::DarkCyan,
+            ChatColor::DarkRed => ChatFormatting::DarkRed,
+            ChatColor::Purple => ChatFormatting::Purple,
+            ChatColor::Gold => ChatFormatting::Gold,
+            ChatColor::Gray => ChatFormatting::Gray,
+            ChatColor::DarkGray => ChatFormatting::DarkGray,
+            ChatColor::Blue => ChatFormatting::Blue,
+            ChatColor::Green => ChatFormatting::Green,
+            ChatColor::Cyan => ChatFormatting::Cyan,
+            ChatColor::Red => ChatFormatting::Red,
+            ChatColor::Pink => ChatFormatting::Pink,
+            ChatColor::Yellow => ChatFormatting::Yellow,
+            ChatColor::White => ChatFormatting::White,
+            ChatColor::Reset => ChatFormatting::Reset,
+            ChatColor::Rgb(..) | ChatColor::Custom(_) => return None,
+        })
+    }
+
+    /// Applies this formatting to `style` the way legacy text does: colors
+    /// set the color, decorations enable themselves and [`ChatFormatting::Reset`] resets everything.
+    pub fn apply_to(&self, style: &mut ComponentStyle) {
+        match self {
+            ChatFormatting::Obfuscated => style.set_obfuscated(true),
+            ChatFormatting::Bold => style.set_bold(true),
+            ChatFormatting::Strikethrough => style.set_strikethrough(true),
+            ChatFormatting::Underlined => style.set_underlined(true),
+            ChatFormatting::Italic => style.set_italic(true),
+            ChatFormatting::Reset => style.reset(),
+            color => style.set_color(color.to_color()),
+        }
+    }
+}
@@ -1,45 +1,112 @@
 use crate::component::ChatComponent;
+use crate::intern::Interner;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
 
 #[cfg(feature = "serde")]
 use serde::Deserialize;
+#[cfg(feature = "preserve_unknown")]
+use serde_json::{Map, Value};
 #[cfg(feature = "serde")]
 mod serde_support;
 
+#[cfg(feature = "serde")]
+pub(crate) use serde_support::default_style_version;
+
+mod entity;
+mod formatting;
+
+pub use entity::EntityTooltip;
+pub use formatting::ChatFormatting;
+
 /// The version number of the Minecraft protocol for 1.7
 pub const VERSION_1_7: u32 = 4;
 /// The version number of the Minecraft protocol for 1.8
 pub const VERSION_1_8: u32 = 47;
+/// The version number of the Minecraft protocol for 1.12
+pub const VERSION_1_12: u32 = 335;
 /// The version number of the Minecraft protocol for 1.15
 pub const VERSION_1_15: u32 = 573;
 /// The version number of the Minecraft protocol for 1.16
 pub const VERSION_1_16: u32 = 735;
+/// The version number of the Minecraft protocol for 1.21.4
+pub(crate) const VERSION_1_21_4: u32 = 769;
+
+/// The tri-state decorations of a [`ComponentStyle`] packed into a single integer
+/// to keep styles small. Every flag takes two bits: whether it is set and its value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct Decorations(u16);
+
+impl Decorations {
+    const BOLD: u16 = 0;
+    const ITALIC: u16 = 1;
+    const UNDERLINED: u16 = 2;
+    const STRIKETHROUGH: u16 = 3;
+    const OBFUSCATED: u16 = 4;
+
+    fn get(self, flag: u16) -> Option<bool> {
+        if self.0 & (0b01 << (flag * 2)) != 0 {
+            Some(self.0 & (0b10 << (flag * 2)) != 0)
+        } else {
+            None
+        }
+    }
+
+    fn set(&mut self, flag: u16, value: Option<bool>) {
+        self.0 &= !(0b11 << (flag * 2));
+        match value {
+            Some(true) => self.0 |= 0b11 << (flag * 2),
+            Some(false) => self.0 |= 0b01 << (flag * 2),
+            None => {}
+        }
+    }
+
+    /// Takes every flag that isn't set from `parent`.
+    fn inherit(&mut self, parent: Decorations) {
+        // every set flag masks both of its bits in `parent`
+        let set = self.0 & 0b01_01_01_01_01;
+        self.0 |= parent.0 & !(set | set << 1);
+    }
+}
 
 /// The style of a [`ChatComponent`]
-#[derive(Clone, Debug)]
+///
+/// Styles can also be (de)serialized on their own, e.g. for team styles or configs,
+/// using the same fields and version handling as inside a component.
+/// Deserialized styles use the 1.16 version and keep every field they were given,
+/// fields newer than their version are only left out when serializing and by
+/// the getters, see [`ComponentStyle::change_version()`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "serde_support::ComponentStyleData"))]
 pub struct ComponentStyle {
-    #[cfg_attr(
-        feature = "serde",
-        serde(skip, default = "serde_support::default_style_version")
-    )]
     version: u32,
-    bold: Option<bool>,
-    italic: Option<bool>,
-    underlined: Option<bool>,
-    strikethrough: Option<bool>,
-    obfuscated: Option<bool>,
+    decorations: Decorations,
     color: Option<ChatColor>,
     /// This field is ignored for versions older than 1.8
-    insertion: Option<String>,
+    insertion: Option<Box<str>>,
     /// This field is ignored for versions older than 1.16
-    font: Option<String>,
-    #[cfg_attr(feature = "serde", serde(rename = "clickEvent"))]
+    font: Option<Arc<str>>,
+    /// This field is ignored for versions older than 1.21.4
+    shadow_color: Option<ShadowColor>,
     click_event: Option<ClickEvent>,
-    #[cfg_attr(feature = "serde", serde(rename = "hoverEvent"))]
     hover_event: Option<HoverEvent>,
+    /// Fields this crate doesn't know, kept so they are serialized again
+    #[cfg(feature = "preserve_unknown")]
+    unknown: Map<String, Value>,
 }
 
 impl ComponentStyle {
+    /// The font text is rendered in if no font is set.
+    pub const DEFAULT_FONT: &'static str = "minecraft:default";
+    /// The vanilla font of the unicode glyphs, also used by the "Force Unicode Font" option.
+    pub const UNIFORM_FONT: &'static str = "minecraft:uniform";
+    /// The runes of the enchanting table.
+    pub const ALT_FONT: &'static str = "minecraft:alt";
+    /// The runes of illagers.
+    pub const ILLAGERALT_FONT: &'static str = "minecraft:illageralt";
+
     pub fn v1_7() -> Self {
         ComponentStyle::with_version(4)
     }
@@ -59,16 +126,15 @@ impl ComponentStyle {
     pub fn with_version(version: u32) -> Self {
         ComponentStyle {
             version,
-            bold: None,
-            italic: None,
-            underlined: None,
-            strikethrough: None,
-            obfuscated: None,
+            decorations: Decorations::default(),
             color: None,
             insertion: None,
             font: None,
+            shadow_color: None,
             click_event: None,
             hover_event: None,
+            #[cfg(feature = "preserve_unknown")]
+            unknown: Map::new(),
         }
     }
 
@@ -93,7 +159,7 @@ impl ComponentStyle {
     }
 
     pub fn set_bold(&mut self, bold: bool) {
-        self.bold = Some(bold);
+        self.decorations.set(Decorations::BOLD, Some(bold));
     }
 
     pub fn bold(mut self, bold: bool) -> Self {
@@ -102,7 +168,7 @@ impl ComponentStyle {
     }
 
     pub fn set_italic(&mut self, italic: bool) {
-        self.italic = Some(italic);
+        self.decorations.set(Decorations::ITALIC, Some(italic));
     }
 
     pub fn italic(mut self, italic: bool) -> Self {
@@ -111,7 +177,8 @@ impl ComponentStyle {
     }
 
     pub fn set_underlined(&mut self, underlined: bool) {
-        self.underlined = Some(underlined);
+        self.decorations
+            .set(Decorations::UNDERLINED, Some(underlined));
     }
 
     pub fn underlined(mut self, underlined: bool) -> Self {
@@ -120,7 +187,8 @@ impl ComponentStyle {
     }
 
     pub fn set_strikethrough(&mut self, strikethrough: bool) {
-        self.strikethrough = Some(strikethrough);
+        self.decorations
+            .set(Decorations::STRIKETHROUGH, Some(strikethrough));
     }
 
     pub fn strikethrough(mut self, strikethrough: bool) -> Self {
@@ -129,7 +197,8 @@ impl ComponentStyle {
     }
 
     pub fn set_obfuscated(&mut self, obfuscated: bool) {
-        self.obfuscated = Some(obfuscated);
+        self.decorations
+            .set(Decorations::OBFUSCATED, Some(obfuscated));
     }
 
     pub fn obfuscated(mut self, obfuscated: bool) -> Self {
@@ -137,8 +206,14 @@ impl ComponentStyle {
         self
     }
 
+    /// Sets the resource location of the font, like [`ComponentStyle::UNIFORM_FONT`]
+    /// or a font of a resource pack. [`None`] inherits the font of the parent,
+    /// [`ComponentStyle::DEFAULT_FONT`] resets it.
+    ///
+    /// # Warning
+    /// Since **1.16**!
     pub fn set_font<T: Into<String>>(&mut self, font: Option<T>) {
-        self.font = font.map(|font| font.into());
+        self.font = font.map(|font| Arc::from(font.into()));
     }
 
     pub fn font<T: Into<String>>(mut self, font: Option<T>) -> Self {
@@ -146,8 +221,22 @@ impl ComponentStyle {
         self
     }
 
+    /// Sets the color of the shadow behind the text, [`None`] uses the default one
+    /// (the text color at a quarter of the brightness). An alpha of 0 hides the shadow.
+    ///
+    /// # Warning
+    /// Since **1.21.4**!
+    pub fn set_shadow_color(&mut self, shadow_color: Option<ShadowColor>) {
+        self.shadow_color = shadow_color;
+    }
+
+    pub fn shadow_color(mut self, shadow_color: Option<ShadowColor>) -> Self {
+        self.set_shadow_color(shadow_color);
+        self
+    }
+
     pub fn set_insertion<T: Into<String>>(&mut self, insertion: Option<T>) {
-        self.insertion = insertion.map(|insertion| insertion.into());
+        self.insertion = insertion.map(|insertion| insertion.into().into_boxed_str());
     }
 
     pub fn insertion<T: Into<String>>(mut self, insertion: Option<T>) -> Self {
@@ -178,36 +267,44 @@ impl ComponentStyle {
     }
 
     pub fn get_bold(&self) -> Option<bool> {
-        self.bold
+        self.decorations.get(Decorations::BOLD)
     }
 
     pub fn get_italic(&self) -> Option<bool> {
-        self.italic
+        self.decorations.get(Decorations::ITALIC)
     }
 
     pub fn get_underlined(&self) -> Option<bool> {
-        self.underlined
+        self.decorations.get(Decorations::UNDERLINED)
     }
 
     pub fn get_strikethrough(&self) -> Option<bool> {
-        self.strikethrough
+        self.decorations.get(Decorations::STRIKETHROUGH)
     }
 
     pub fn get_obfuscated(&self) -> Option<bool> {
-        self.obfuscated
+        self.decorations.get(Decorations::OBFUSCATED)
     }
 
-    pub fn get_font(&self) -> Option<&String> {
+    pub fn get_font(&self) -> Option<&str> {
         if self.version >= 713 {
-            self.font.as_ref()
+            self.font.as_deref()
         } else {
             None
         }
     }
 
-    pub fn get_insertion(&self) -> Option<&String> {
+    pub fn get_shadow_color(&self) -> Option<ShadowColor> {
+        if self.version >= VERSION_1_21_4 {
+            self.shadow_color
+        } else {
+            None
+        }
+    }
+
+    pub fn get_insertion(&self) -> Option<&str> {
         if self.version >= 5 {
-            self.insertion.as_ref()
+            self.insertion.as_deref()
         } else {
             None
         }
@@ -221,29 +318,138 @@ impl ComponentStyle {
         self.hover_event.as_ref()
     }
 
+    pub fn get_hover_event_mut(&mut self) -> Option<&mut HoverEvent> {
+        self.hover_event.as_mut()
+    }
+
+    pub fn get_version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the fields of a deserialized standalone style this crate doesn't understand,
+    /// e.g. ones added by newer versions. They are written back when serializing.
+    ///
+    /// Unknown fields of a deserialized component are kept by the component instead,
+    /// see [`ChatComponent::get_unknown_fields()`]. Both are only kept with the
+    /// `preserve_unknown` feature.
+    #[cfg(feature = "preserve_unknown")]
+    pub fn get_unknown_fields(&self) -> &Map<String, Value> {
+        &self.unknown
+    }
+
+    #[cfg(feature = "preserve_unknown")]
+    pub fn get_unknown_fields_mut(&mut self) -> &mut Map<String, Value> {
+        &mut self.unknown
+    }
+
+    /// Changes the version of this style, including the text of its [`HoverEvent::ShowText`].
     pub fn change_version(&mut self, to: u32) {
         self.version = to;
+        match &mut self.hover_event {
+            Some(HoverEvent::ShowText(text)) => text.change_version(to),
+            Some(HoverEvent::ShowEntityTooltip(entity)) => {
+                if let Some(name) = entity.get_name_mut() {
+                    name.change_version(to);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub(crate) fn intern(&mut self, interner: &mut Interner) {
+        if let Some(font) = &self.font {
+            self.font = Some(interner.intern(font));
+        }
+    }
+
+    /// Estimates the heap memory (in bytes) held by this style, see [`ChatComponent::deep_size_of()`].
+    pub fn deep_size_of(&self) -> usize {
+        let color = match &self.color {
+            Some(ChatColor::Custom(color)) => color.capacity(),
+            _ => 0,
+        };
+        let click_event = match &self.click_event {
+            Some(ClickEvent::OpenUrl(value))
+            | Some(ClickEvent::RunCommand(value))
+            | Some(ClickEvent::SuggestCommand(value))
+            | Some(ClickEvent::CopyToClipBoard(value)) => value.capacity(),
+            _ => 0,
+        };
+        let hover_event = match &self.hover_event {
+            Some(HoverEvent::ShowText(text)) => {
+                std::mem::size_of::<ChatComponent>() + text.deep_size_of()
+            }
+            Some(HoverEvent::ShowItem(value)) | Some(HoverEvent::ShowEntity(value)) => {
+                value.capacity()
+            }
+            Some(HoverEvent::ShowEntityTooltip(entity)) => {
+                entity.get_kind().len()
+                    + entity.get_id().len()
+                    + entity.get_name().map_or(0, |name| {
+                        std::mem::size_of::<ChatComponent>() + name.deep_size_of()
+                    })
+            }
+            None => 0,
+        };
+        color
+            + self
+                .insertion
+                .as_ref()
+                .map_or(0, |insertion| insertion.len())
+            + self
+                .font
+                .as_ref()
+                .map_or(0, |font| crate::component::shared_str_size(font))
+            + click_event
+            + hover_event
+    }
+
+    /// Fills every field that isn't set in this style with the value of `parent`.
+    /// The result is the style a component is actually rendered with.
+    pub fn inherit(&mut self, parent: &ComponentStyle) {
+        self.decorations.inherit(parent.decorations);
+        if self.color.is_none() {
+            self.color = parent.color.clone();
+        }
+        if self.insertion.is_none() {
+            self.insertion = parent.insertion.clone();
+        }
+        if self.font.is_none() {
+            self.font = parent.font.clone();
+        }
+        if self.shadow_color.is_none() {
+            self.shadow_color = parent.shadow_color;
+        }
+        if self.click_event.is_none() {
+            self.click_event = parent.click_event.clone();
+        }
+        if self.hover_event.is_none() {
+            self.hover_event = parent.hover_event.clone();
+        }
+    }
+
+    /// Returns whether text with this (resolved) style is obfuscated,
+    /// renderers can choose how to display this.
+    pub fn is_obfuscated(&self) -> bool {
+        self.get_obfuscated().unwrap_or(false)
     }
 
     /// Resets all fields to default (being [`None`]).
     pub fn reset(&mut self) {
-        self.bold = None;
-        self.italic = None;
-        self.underlined = None;
-        self.strikethrough = None;
-        self.obfuscated = None;
+        self.decorations = Decorations::default();
         self.color = None;
         self.insertion = None;
         self.font = None;
+        self.shadow_color = None;
         self.click_event = None;
         self.hover_event = None;
+        #[cfg(feature = "preserve_unknown")]
+        self.unknown.clear();
     }
 }
 
 /// The different colors a [`ChatComponent`] can have.
-/// ## TODO
-/// Automatically find nearest value when serializing [`ChatColor::Custom`] for older versions
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ChatColor {
     Black,
     DarkBlue,
@@ -261,7 +467,11 @@ pub enum ChatColor {
     Pink,
     Yellow,
     White,
-    /// This field is ignored for versions older than 1.16.
+    /// Any color of 1.16 and newer, versions older than it get the nearest
+    /// named color instead, see [`ChatColor::to_nearest_legacy()`].
+    Rgb(u8, u8, u8),
+    /// A color written in a form this crate can't read (not `#RRGGBB`),
+    /// kept so it is written again. Versions older than 1.16 leave it out.
     ///
     /// See [`ChatColor::custom()`].
     Custom(String),
@@ -269,13 +479,192 @@ pub enum ChatColor {
 }
 
 impl ChatColor {
+    /// A color as it is written in JSON: `#RRGGBB` (in any case) becomes a
+    /// [`ChatColor::Rgb`], anything else a [`ChatColor::Custom`].
     pub fn custom<T: Into<String>>(color: T) -> ChatColor {
-        ChatColor::Custom(color.into())
+        let color = color.into();
+        match parse_hex(&color) {
+            Some((red, green, blue)) => ChatColor::Rgb(red, green, blue),
+            None => ChatColor::Custom(color),
+        }
+    }
+
+    /// A color in the `#RRGGBB` format, see [`ChatColor::Rgb`].
+    pub fn rgb(red: u8, green: u8, blue: u8) -> ChatColor {
+        ChatColor::Rgb(red, green, blue)
+    }
+
+    /// Returns the red, green and blue values of this color, [`None`] for
+    /// [`ChatColor::Reset`] and [`ChatColor::Custom`].
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        let rgb = match self {
+            ChatColor::Black => 0x000000,
+            ChatColor::DarkBlue => 0x0000AA,
+            ChatColor::DarkGreen => 0x00AA00,
+            ChatColor::DarkCyan => 0x00AAAA,
+            ChatColor::DarkRed => 0xAA0000,
+            ChatColor::Purple => 0xAA00AA,
+            ChatColor::Gold => 0xFFAA00,
+            ChatColor::Gray => 0xAAAAAA,
+            ChatColor::DarkGray => 0x555555,
+            ChatColor::Blue => 0x5555FF,
+            ChatColor::Green => 0x55FF55,
+            ChatColor::Cyan => 0x55FFFF,
+            ChatColor::Red => 0xFF5555,
+            ChatColor::Pink => 0xFF55FF,
+            ChatColor::Yellow => 0xFFFF55,
+            ChatColor::White => 0xFFFFFF,
+            ChatColor::Rgb(red, green, blue) => return Some((*red, *green, *blue)),
+            ChatColor::Custom(_) | ChatColor::Reset => return None,
+        };
+        Some(((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+    }
+
+    /// Returns the one of the 16 colors closest to this one by RGB distance, which
+    /// clients before 1.16 can display. Named colors and [`ChatColor::Reset`] are
+    /// returned as they are, [`None`] for [`ChatColor::Custom`].
+    pub fn to_nearest_legacy(&self) -> Option<ChatColor> {
+        if !matches!(self, ChatColor::Rgb(..) | ChatColor::Custom(_)) {
+            return Some(self.clone());
+        }
+        let (red, green, blue) = self.to_rgb()?;
+        ChatFormatting::ALL
+            .iter()
+            .filter_map(ChatFormatting::to_color)
+            .filter_map(|color| Some((color.to_rgb()?, color)))
+            .min_by_key(|((r, g, b), _)| {
+                let distance = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+                distance(*r, red) + distance(*g, green) + distance(*b, blue)
+            })
+            .map(|(_, color)| color)
+    }
+}
+
+/// Reads a `#RRGGBB` color, ignoring the case of the digits.
+fn parse_hex(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color
+        .strip_prefix('#')
+        .filter(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))?;
+    let rgb = u32::from_str_radix(hex, 16).ok()?;
+    Some(((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+}
+
+/// The color of the shadow behind text, as ARGB with 8 bits per channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ShadowColor(u32);
+
+impl ShadowColor {
+    pub fn new(alpha: u8, red: u8, green: u8, blue: u8) -> Self {
+        ShadowColor(u32::from_be_bytes([alpha, red, green, blue]))
+    }
+
+    /// A color packed like `0xAARRGGBB`, the form Minecraft writes.
+    pub fn from_argb(argb: u32) -> Self {
+        ShadowColor(argb)
+    }
+
+    /// A color from its red, green, blue and alpha channel between 0 and 1,
+    /// in the order of the list form Minecraft reads.
+    pub fn from_floats(rgba: [f32; 4]) -> Self {
+        let [red, green, blue, alpha] =
+            rgba.map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8);
+        ShadowColor::new(alpha, red, green, blue)
+    }
+
+    pub fn get_argb(&self) -> u32 {
+        self.0
+    }
+
+    pub fn get_alpha(&self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    pub fn get_red(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    pub fn get_green(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    pub fn get_blue(&self) -> u8 {
+        self.0 as u8
+    }
+
+    /// The red, green, blue and alpha channel between 0 and 1, see [`ShadowColor::from_floats()`].
+    pub fn to_floats(&self) -> [f32; 4] {
+        [
+            self.get_red(),
+            self.get_green(),
+            self.get_blue(),
+            self.get_alpha(),
+        ]
+        .map(|channel| channel as f32 / 255.0)
+    }
+}
+
+/// The error returned when a string isn't a color, see [`ChatColor::from_str()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColorParseErr(pub String);
+
+impl Display for ColorParseErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} is not a valid color, expected a name, #RRGGBB, #RGB or a legacy code!",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ColorParseErr {}
+
+impl FromStr for ChatColor {
+    type Err = ColorParseErr;
+
+    /// Reads a color the way users write them in configs: a vanilla name like
+    /// `dark_aqua` (in any case), `#RRGGBB`, `#RGB` or a legacy code like `b`,
+    /// optionally after `§` or `&`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let err = || ColorParseErr(input.to_string());
+        let color = input.trim();
+        if let Some(hex) = color.strip_prefix('#') {
+            if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(err());
+            }
+            let channel = |hex: &str| u8::from_str_radix(hex, 16).map_err(|_| err());
+            return match hex.len() {
+                6 => Ok(ChatColor::rgb(
+                    channel(&hex[0..2])?,
+                    channel(&hex[2..4])?,
+                    channel(&hex[4..6])?,
+                )),
+                // every digit is doubled, like in CSS
+                3 => Ok(ChatColor::rgb(
+                    channel(&hex[0..1])? * 0x11,
+                    channel(&hex[1..2])? * 0x11,
+                    channel(&hex[2..3])? * 0x11,
+                )),
+                _ => Err(err()),
+            };
+        }
+        let code = color
+            .strip_prefix(ChatFormatting::SECTION_SIGN)
+            .or_else(|| color.strip_prefix('&'))
+            .unwrap_or(color);
+        let mut chars = code.chars();
+        let formatting = match (chars.next(), chars.next()) {
+            (Some(code), None) => ChatFormatting::from_code(code),
+            _ => ChatFormatting::from_name(&color.to_ascii_lowercase()),
+        };
+        formatting
+            .and_then(|formatting| formatting.to_color())
+            .ok_or_else(err)
     }
 }
 
 /// A ClickEvent useful in a chat message or book.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize))]
 #[cfg_attr(feature = "serde", serde(try_from = "serde_support::ClickEventData"))]
 pub enum ClickEvent {
@@ -312,12 +701,14 @@ impl ClickEvent {
 /// A HoverEvent useful in a chat message or book.
 /// ## TODO
 /// Change 'value' field to 'contents' when serializing for 1.16+,
-/// also add more sophisticated `item` and `entity` data structures
-#[derive(Clone, Debug)]
+/// also add a more sophisticated `item` data structure
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Deserialize))]
 #[cfg_attr(feature = "serde", serde(try_from = "serde_support::HoverEventData"))]
 pub enum HoverEvent {
     ShowText(Box<ChatComponent>),
     ShowItem(String),
+    /// The raw `value` of an entity hover this crate couldn't read, see [`EntityTooltip`].
     ShowEntity(String),
+    ShowEntityTooltip(EntityTooltip),
 }
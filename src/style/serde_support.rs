@@ -1,37 +1,37 @@
 use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 
+#[cfg(feature = "preserve_unknown")]
+use crate::component::deserialize_unknown_fields;
 use crate::component::ChatComponent;
+use serde::de::Error;
 use serde::ser::{SerializeMap, SerializeStruct};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "preserve_unknown")]
+use serde_json::{Map, Value};
 
-use crate::style::{ChatColor, ClickEvent, ComponentStyle, HoverEvent, VERSION_1_16};
+use crate::style::{
+    ChatColor, ChatFormatting, ClickEvent, ComponentStyle, EntityTooltip, HoverEvent, ShadowColor,
+    VERSION_1_16, VERSION_1_8,
+};
 
 impl Serialize for ChatColor {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(match self {
-            ChatColor::Black => "black",
-            ChatColor::DarkBlue => "dark_blue",
-            ChatColor::DarkGreen => "dark_green",
-            ChatColor::DarkCyan => "dark_aqua",
-            ChatColor::DarkRed => "dark_red",
-            ChatColor::Purple => "dark_purple",
-            ChatColor::Gold => "gold",
-            ChatColor::Gray => "gray",
-            ChatColor::DarkGray => "dark_gray",
-            ChatColor::Blue => "blue",
-            ChatColor::Green => "green",
-            ChatColor::Cyan => "aqua",
-            ChatColor::Red => "red",
-            ChatColor::Pink => "light_purple",
-            ChatColor::Yellow => "yellow",
-            ChatColor::White => "white",
-            ChatColor::Custom(color) => color,
-            ChatColor::Reset => "reset",
-        })
+        match self {
+            ChatColor::Rgb(red, green, blue) => {
+                serializer.serialize_str(&format!("#{:02X}{:02X}{:02X}", red, green, blue))
+            }
+            ChatColor::Custom(color) => serializer.serialize_str(color),
+            // every other color has a formatting
+            _ => serializer.serialize_str(
+                ChatFormatting::from_color(self)
+                    .unwrap_or(ChatFormatting::Reset)
+                    .get_name(),
+            ),
+        }
     }
 }
 
@@ -41,26 +41,47 @@ impl<'de> Deserialize<'de> for ChatColor {
         D: Deserializer<'de>,
     {
         let input = String::deserialize(deserializer)?;
-        Ok(match input.as_str() {
-            "black" => ChatColor::Black,
-            "dark_blue" => ChatColor::DarkBlue,
-            "dark_green" => ChatColor::DarkGreen,
-            "dark_aqua" => ChatColor::DarkCyan,
-            "dark_red" => ChatColor::DarkRed,
-            "dark_purple" => ChatColor::Purple,
-            "gold" => ChatColor::Gold,
-            "gray" => ChatColor::Gray,
-            "dark_gray" => ChatColor::DarkGray,
-            "blue" => ChatColor::Blue,
-            "green" => ChatColor::Green,
-            "aqua" => ChatColor::Cyan,
-            "red" => ChatColor::Red,
-            "light_purple" => ChatColor::Pink,
-            "yellow" => ChatColor::Yellow,
-            "white" => ChatColor::White,
-            "reset" => ChatColor::Reset,
-            _ => ChatColor::Custom(input),
-        })
+        Ok(ChatFormatting::from_name(&input)
+            .and_then(|formatting| formatting.to_color())
+            .unwrap_or_else(|| ChatColor::custom(input)))
+    }
+}
+
+impl Serialize for ShadowColor {
+    /// Written as the signed integer Minecraft writes.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i32(self.get_argb() as i32)
+    }
+}
+
+/// The forms of a shadow color: a packed ARGB integer (signed or not) or
+/// the red, green, blue and alpha channel between 0 and 1.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ShadowColorData {
+    Packed(i64),
+    Floats([f32; 4]),
+}
+
+impl<'de> Deserialize<'de> for ShadowColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match ShadowColorData::deserialize(deserializer)? {
+            ShadowColorData::Packed(argb)
+                if (i32::MIN as i64..=u32::MAX as i64).contains(&argb) =>
+            {
+                Ok(ShadowColor::from_argb(argb as u32))
+            }
+            ShadowColorData::Packed(argb) => {
+                Err(D::Error::custom(format!("{} is not a 32 bit color", argb)))
+            }
+            ShadowColorData::Floats(rgba) => Ok(ShadowColor::from_floats(rgba)),
+        }
     }
 }
 
@@ -169,21 +190,64 @@ impl Serialize for HoverEvent {
                 event.serialize_field("action", "show_entity")?;
                 event.serialize_field("value", entity)?;
             }
+            HoverEvent::ShowEntityTooltip(entity) => {
+                event.serialize_field("action", "show_entity")?;
+                event.serialize_field("contents", &EntityTooltipData::from(entity))?;
+            }
         }
         event.end()
     }
 }
 
+/// A hover event with a plain string value, as expected by older versions.
+struct LegacyHoverEvent {
+    action: &'static str,
+    value: String,
+}
+
+impl Serialize for LegacyHoverEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut event = serializer.serialize_struct("hoverEvent", 2)?;
+        event.serialize_field("action", self.action)?;
+        event.serialize_field("value", &self.value)?;
+        event.end()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct EntityTooltipData {
+    #[serde(rename = "type")]
+    kind: String,
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<ChatComponent>,
+}
+
+impl From<&EntityTooltip> for EntityTooltipData {
+    fn from(entity: &EntityTooltip) -> Self {
+        EntityTooltipData {
+            kind: entity.get_kind().to_string(),
+            id: entity.get_id().to_string(),
+            name: entity.get_name().cloned(),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(untagged)]
 enum HoverEventType {
     String(String),
-    Chat(ChatComponent),
+    Entity(Box<EntityTooltipData>),
+    Chat(Box<ChatComponent>),
 }
 
 #[derive(Deserialize)]
 pub(crate) struct HoverEventData {
     action: String,
+    #[serde(alias = "contents")]
     value: HoverEventType,
 }
 
@@ -207,22 +271,29 @@ impl TryFrom<HoverEventData> for HoverEvent {
     type Error = HoverEventDeserializeErr;
 
     fn try_from(data: HoverEventData) -> Result<Self, Self::Error> {
-        if data.action.as_str() == "show_text" {
-            if let HoverEventType::Chat(component) = data.value {
-                Ok(HoverEvent::ShowText(Box::new(component)))
-            } else {
-                Err(HoverEventDeserializeErr::NoValueFound(data.action))
+        match (data.action.as_str(), data.value) {
+            ("show_text", HoverEventType::Chat(component)) => Ok(HoverEvent::ShowText(component)),
+            // plain strings are used by 1.7 clients and still accepted by newer ones
+            ("show_text", HoverEventType::String(text)) => {
+                Ok(HoverEvent::ShowText(Box::new(ChatComponent::from_text(
+                    text,
+                    ComponentStyle::with_version(default_style_version()),
+                ))))
             }
-        } else {
-            if let HoverEventType::String(str) = data.value {
-                match data.action.as_str() {
-                    "show_item" => Ok(HoverEvent::ShowItem(str)),
-                    "show_entity" => Ok(HoverEvent::ShowEntity(str)),
-                    _ => Err(HoverEventDeserializeErr::WrongKey(str)),
+            ("show_item", HoverEventType::String(item)) => Ok(HoverEvent::ShowItem(item)),
+            ("show_entity", HoverEventType::Entity(entity)) => Ok(HoverEvent::ShowEntityTooltip(
+                EntityTooltip::new(entity.kind, entity.id).name(entity.name),
+            )),
+            ("show_entity", HoverEventType::String(entity)) => {
+                if let Some(entity) = EntityTooltip::from_legacy_value(&entity) {
+                    return Ok(HoverEvent::ShowEntityTooltip(entity));
                 }
-            } else {
-                Err(HoverEventDeserializeErr::WrongKey(data.action))
+                Ok(HoverEvent::ShowEntity(entity))
+            }
+            ("show_text", _) | ("show_item", _) | ("show_entity", _) => {
+                Err(HoverEventDeserializeErr::NoValueFound(data.action))
             }
+            _ => Err(HoverEventDeserializeErr::WrongKey(data.action)),
         }
     }
 }
@@ -233,40 +304,40 @@ impl Serialize for ComponentStyle {
         S: Serializer,
     {
         let mut map = serializer.serialize_map(None)?;
-        if self.bold.is_some() {
-            map.serialize_entry("bold", &self.bold)?;
+        if let Some(bold) = self.get_bold() {
+            map.serialize_entry("bold", &bold)?;
         }
-        if self.italic.is_some() {
-            map.serialize_entry("italic", &self.italic)?;
+        if let Some(italic) = self.get_italic() {
+            map.serialize_entry("italic", &italic)?;
         }
-        if self.underlined.is_some() {
-            map.serialize_entry("underlined", &self.underlined)?;
+        if let Some(underlined) = self.get_underlined() {
+            map.serialize_entry("underlined", &underlined)?;
         }
-        if self.strikethrough.is_some() {
-            map.serialize_entry("strikethrough", &self.strikethrough)?;
+        if let Some(strikethrough) = self.get_strikethrough() {
+            map.serialize_entry("strikethrough", &strikethrough)?;
         }
-        if self.obfuscated.is_some() {
-            map.serialize_entry("obfuscated", &self.obfuscated)?;
+        if let Some(obfuscated) = self.get_obfuscated() {
+            map.serialize_entry("obfuscated", &obfuscated)?;
         }
-        if self.color.is_some() {
-            if let Some(ChatColor::Custom(_)) = self.color {
-                if self.version >= 713 {
-                    map.serialize_entry("color", &self.color)?;
-                }
-            } else {
-                map.serialize_entry("color", &self.color)?;
+        if let Some(color) = &self.color {
+            if self.version >= 713 {
+                map.serialize_entry("color", color)?;
+            } else if let Some(color) = color.to_nearest_legacy() {
+                // older clients only know the named colors
+                map.serialize_entry("color", &color)?;
             }
         }
         if self.version >= 5 {
             if self.insertion.is_some() {
                 map.serialize_entry("insertion", &self.insertion)?;
             }
-            if self.version >= 713 {
-                if self.font.is_some() {
-                    map.serialize_entry("font", &self.font)?;
-                }
+            if self.version >= 713 && self.font.is_some() {
+                map.serialize_entry("font", &self.font)?;
             }
         }
+        if let Some(shadow_color) = self.get_shadow_color() {
+            map.serialize_entry("shadow_color", &shadow_color)?;
+        }
         if self.click_event.is_some() {
             if let Some(ClickEvent::CopyToClipBoard(_)) = self.click_event {
                 if self.version >= 558 {
@@ -276,14 +347,93 @@ impl Serialize for ComponentStyle {
                 map.serialize_entry("clickEvent", &self.click_event)?;
             }
         }
-        if self.hover_event.is_some() {
-            map.serialize_entry("hoverEvent", &self.hover_event)?;
+        match &self.hover_event {
+            // 1.7 clients only accept strings as hover text, formatted with § codes
+            Some(HoverEvent::ShowText(text)) if self.version < VERSION_1_8 => {
+                let event = LegacyHoverEvent {
+                    action: "show_text",
+                    value: text.to_legacy_string(),
+                };
+                map.serialize_entry("hoverEvent", &event)?;
+            }
+            // entities are sent as SNBT strings before 1.16
+            Some(HoverEvent::ShowEntityTooltip(entity)) if self.version < VERSION_1_16 => {
+                match entity.to_legacy_value() {
+                    Some(value) => {
+                        let event = LegacyHoverEvent {
+                            action: "show_entity",
+                            value,
+                        };
+                        map.serialize_entry("hoverEvent", &event)?;
+                    }
+                    // the name can't be written without JSON, the newer form keeps it
+                    None => map.serialize_entry("hoverEvent", &self.hover_event)?,
+                }
+            }
+            Some(event) => map.serialize_entry("hoverEvent", event)?,
+            None => {}
+        }
+        #[cfg(feature = "preserve_unknown")]
+        for (key, value) in &self.unknown {
+            map.serialize_entry(key, value)?;
         }
 
         map.end()
     }
 }
 
+#[derive(Deserialize)]
+pub(crate) struct ComponentStyleData {
+    bold: Option<bool>,
+    italic: Option<bool>,
+    underlined: Option<bool>,
+    strikethrough: Option<bool>,
+    obfuscated: Option<bool>,
+    color: Option<ChatColor>,
+    insertion: Option<String>,
+    font: Option<String>,
+    shadow_color: Option<ShadowColor>,
+    #[serde(rename = "clickEvent")]
+    click_event: Option<ClickEvent>,
+    #[serde(rename = "hoverEvent")]
+    hover_event: Option<HoverEvent>,
+    #[cfg(feature = "preserve_unknown")]
+    #[serde(flatten, deserialize_with = "deserialize_unknown_fields")]
+    unknown: Map<String, Value>,
+}
+
+impl From<ComponentStyleData> for ComponentStyle {
+    fn from(data: ComponentStyleData) -> Self {
+        let mut style = ComponentStyle::with_version(default_style_version())
+            .color(data.color)
+            .shadow_color(data.shadow_color)
+            .insertion(data.insertion)
+            .font(data.font)
+            .click_event(data.click_event)
+            .hover_event(data.hover_event);
+        if let Some(bold) = data.bold {
+            style.set_bold(bold);
+        }
+        if let Some(italic) = data.italic {
+            style.set_italic(italic);
+        }
+        if let Some(underlined) = data.underlined {
+            style.set_underlined(underlined);
+        }
+        if let Some(strikethrough) = data.strikethrough {
+            style.set_strikethrough(strikethrough);
+        }
+        if let Some(obfuscated) = data.obfuscated {
+            style.set_obfuscated(obfuscated);
+        }
+        #[cfg(feature = "preserve_unknown")]
+        {
+            style.unknown = data.unknown;
+        }
+        style
+    }
+}
+
 pub(crate) fn default_style_version() -> u32 {
     VERSION_1_16
 }
@@ -0,0 +1,82 @@
+use crate::component::ChatComponent;
+use crate::style::{ChatColor, ComponentStyle};
+
+/// How a team decorates the names of its members in chat and the tab list.
+#[derive(Clone, Debug)]
+pub struct TeamFormat {
+    color: Option<ChatColor>,
+    prefix: Option<ChatComponent>,
+    suffix: Option<ChatComponent>,
+}
+
+impl TeamFormat {
+    pub fn new() -> Self {
+        TeamFormat {
+            color: None,
+            prefix: None,
+            suffix: None,
+        }
+    }
+
+    pub fn get_color(&self) -> Option<&ChatColor> {
+        self.color.as_ref()
+    }
+
+    pub fn set_color(&mut self, color: Option<ChatColor>) {
+        self.color = color;
+    }
+
+    pub fn color(mut self, color: Option<ChatColor>) -> Self {
+        self.set_color(color);
+        self
+    }
+
+    pub fn get_prefix(&self) -> Option<&ChatComponent> {
+        self.prefix.as_ref()
+    }
+
+    pub fn set_prefix(&mut self, prefix: Option<ChatComponent>) {
+        self.prefix = prefix;
+    }
+
+    pub fn prefix(mut self, prefix: Option<ChatComponent>) -> Self {
+        self.set_prefix(prefix);
+        self
+    }
+
+    pub fn get_suffix(&self) -> Option<&ChatComponent> {
+        self.suffix.as_ref()
+    }
+
+    pub fn set_suffix(&mut self, suffix: Option<ChatComponent>) {
+        self.suffix = suffix;
+    }
+
+    pub fn suffix(mut self, suffix: Option<ChatComponent>) -> Self {
+        self.set_suffix(suffix);
+        self
+    }
+
+    /// Decorates `name` the way vanilla does: `prefix`, `name` and `suffix`
+    /// are joined and the team color (unless [`ChatColor::Reset`]) is applied to all of them.
+    pub fn apply_team_format(&self, name: ChatComponent) -> ChatComponent {
+        let mut style = ComponentStyle::with_version(name.get_version());
+        if let Some(color) = &self.color {
+            if *color != ChatColor::Reset {
+                style.set_color(Some(color.clone()));
+            }
+        }
+        let mut formatted = ChatComponent::from_text("", style);
+        let siblings = formatted.get_siblings_mut();
+        siblings.extend(self.prefix.clone());
+        siblings.push(name);
+        siblings.extend(self.suffix.clone());
+        formatted
+    }
+}
+
+impl Default for TeamFormat {
+    fn default() -> Self {
+        TeamFormat::new()
+    }
+}
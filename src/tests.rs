@@ -1,10 +1,10 @@
 #![cfg(test)]
 
-#[cfg(feature = "serde")]
+#[cfg(feature = "json")]
 #[cfg(test)]
 mod serde_support {
     use crate::component::ChatComponent;
-    use crate::style::{ChatColor, ClickEvent, ComponentStyle};
+    use crate::style::{ChatColor, ClickEvent, ComponentStyle, HoverEvent};
 
     use serde_json::json;
 
@@ -39,5 +39,2805 @@ mod serde_support {
         );
 
         println!("test: {:?}", obj);
+
+        // the same with either backend of from_json
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(ChatComponent::from_json(&json).unwrap(), obj);
+    }
+
+    #[test]
+    fn test_1_7_profile() {
+        let mut obj: ChatComponent = serde_json::from_str(r#"{"text":"","hoverEvent":{"action":"show_text","value":"Old hover"},"extra":[{"score":{"name":"Notch","objective":"deaths","value":"3"}},{"selector":"@p"}]}"#).unwrap();
+
+        obj.downgrade(crate::style::VERSION_1_7);
+
+        assert_eq!(
+            serde_json::to_value(&obj).unwrap(),
+            json!({"text":"","hoverEvent":{"action":"show_text","value":"Old hover"},"extra":[{"text":"3"},{"text":"@p"}]})
+        );
+
+        let mut hover = ChatComponent::from_text("Hover ", ComponentStyle::v1_7().bold(true));
+        hover
+            .get_siblings_mut()
+            .push(ChatComponent::from_text("text", ComponentStyle::v1_7()));
+        obj.set_hover_event(Some(HoverEvent::ShowText(Box::new(hover))));
+        let json = serde_json::to_string(&obj).unwrap();
+        assert_eq!(
+            serde_json::to_value(obj.get_style()).unwrap(),
+            json!({"hoverEvent":{"action":"show_text","value":"§lHover text"}})
+        );
+
+        // the 1.7 shape is read back with the same formatting
+        let read = ChatComponent::from_json_1_7(&json).unwrap();
+        assert_eq!(read.get_version(), crate::style::VERSION_1_7);
+        assert_eq!(serde_json::to_string(&read).unwrap(), json);
+        match read.get_hover_event() {
+            Some(HoverEvent::ShowText(text)) => assert_eq!(text.get_bold(), Some(true)),
+            _ => panic!("hover text was not read"),
+        }
+
+        let err = ChatComponent::from_json_1_7(r#"{"text":"a","bold":1}"#).unwrap_err();
+        assert_eq!(err.get_path(), "bold");
+        let err = ChatComponent::from_json_1_7(r#"{"text":"a","extra":[{"selector":"@p"}]}"#)
+            .unwrap_err();
+        assert_eq!(err.get_path(), "extra[0]");
+        let err = ChatComponent::from_json_1_7(
+            r#"{"text":"a","hoverEvent":{"action":"show_text","value":{"text":"b"}}}"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.get_path(), "hoverEvent.value");
+    }
+
+    #[test]
+    #[cfg(feature = "preserve_unknown")]
+    fn test_unknown_fields() {
+        let value = json!({
+            "text": "Hello",
+            "bold": true,
+            "future_field": {"nested": [1, 2]},
+            "extra": [{"translate": "chat.type.text", "with": [], "mod:glow": 3}]
+        });
+        let component: ChatComponent = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(component.get_unknown_fields().len(), 1);
+        assert!(component.get_unknown_fields().contains_key("future_field"));
+        assert!(component.get_style().get_unknown_fields().is_empty());
+        assert_eq!(serde_json::to_value(&component).unwrap(), value);
+
+        let mut stripped = component.clone();
+        stripped.clear_unknown_fields();
+        assert_eq!(
+            serde_json::to_value(&stripped).unwrap(),
+            json!({"text": "Hello", "bold": true, "extra": [{"translate": "chat.type.text", "with": []}]})
+        );
+
+        let value = json!({"color": "red", "future_style": 2});
+        let style: ComponentStyle = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(style.get_unknown_fields().len(), 1);
+        assert_eq!(serde_json::to_value(&style).unwrap(), value);
+    }
+
+    #[test]
+    #[cfg(not(feature = "preserve_unknown"))]
+    fn test_drop_unknown_fields() {
+        let value =
+            json!({"text": "Hello", "future_field": 1, "extra": [{"text": "!", "mod:glow": 3}]});
+        let component: ChatComponent = serde_json::from_value(value).unwrap();
+        assert_eq!(
+            serde_json::to_value(&component).unwrap(),
+            json!({"text": "Hello", "extra": [{"text": "!"}]})
+        );
+    }
+
+    #[test]
+    fn test_primitive_components() {
+        let component: ChatComponent =
+            serde_json::from_value(json!({"text":"a","extra":["b",1,true,2.5]})).unwrap();
+        assert_eq!(component.to_plain_text(), "ab1true2.5");
+        assert!(component
+            .get_siblings()
+            .iter()
+            .all(|sibling| matches!(sibling.get_kind(), crate::ComponentType::Text(_))));
+
+        let component: ChatComponent =
+            serde_json::from_value(json!({"translate":"%s","with":[3]})).unwrap();
+        assert_eq!(component.to_plain_text(), "3");
+        let component: ChatComponent = serde_json::from_str(r#""hi""#).unwrap();
+        assert_eq!(
+            serde_json::to_value(&component).unwrap(),
+            json!({"text":"hi"})
+        );
+        assert!(serde_json::from_str::<ChatComponent>("null").is_err());
+    }
+
+    #[test]
+    fn test_list_components() {
+        let component: ChatComponent =
+            serde_json::from_value(json!([{"text":"a","color":"red"},"b",["c","d"]])).unwrap();
+        assert_eq!(component.get_color(), Some(&ChatColor::Red));
+        assert_eq!(component.get_siblings().len(), 2);
+        assert_eq!(component.get_siblings()[1].get_siblings().len(), 1);
+        assert_eq!(component.to_plain_text(), "abcd");
+
+        let component: ChatComponent =
+            serde_json::from_value(json!({"text":"","extra":[["x","y"]]})).unwrap();
+        assert_eq!(component.to_plain_text(), "xy");
+        assert!(serde_json::from_str::<ChatComponent>("[]").is_err());
+    }
+
+    #[test]
+    fn test_value_conversions() {
+        use crate::ComponentValueErr;
+        use std::convert::TryFrom;
+
+        let component = ChatComponent::try_from(json!({"text":"a","bold":true})).unwrap();
+        assert_eq!(component.get_bold(), Some(true));
+        assert_eq!(
+            serde_json::Value::from(component),
+            json!({"text":"a","bold":true})
+        );
+
+        assert_eq!(
+            ChatComponent::try_from(json!(null)),
+            Err(ComponentValueErr::Null)
+        );
+        assert_eq!(
+            ChatComponent::try_from(json!([])),
+            Err(ComponentValueErr::EmptyList)
+        );
+        assert_eq!(
+            ChatComponent::try_from(json!({"color":"red"})),
+            Err(ComponentValueErr::MissingContent)
+        );
+        assert!(matches!(
+            ChatComponent::try_from(json!({"text":"a","bold":"yes"})),
+            Err(ComponentValueErr::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_json_errors() {
+        let err = ChatComponent::from_json(
+            r#"{"text":"","extra":["a",{"text":"b"},{"text":"c","hoverEvent":{"action":"show_entity","contents":{"type":"minecraft:pig","id":5}}}]}"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.get_path(), "extra[2].hoverEvent.contents.id");
+        assert_eq!(err.get_value(), Some(&json!(5)));
+        assert_eq!(err.get_expected(), "a string");
+
+        let err =
+            ChatComponent::from_json(r#"{"translate":"a","with":[{"text":"b","bold":"yes"}]}"#)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Invalid component at with[0].bold: expected a boolean, found "yes""#
+        );
+
+        let err = ChatComponent::from_json(r#"{"color":"red"}"#).unwrap_err();
+        assert_eq!(err.get_path(), "");
+        let err = ChatComponent::from_json(r#"{"text":"a""#).unwrap_err();
+        assert_eq!(err.get_value(), None);
+    }
+
+    #[test]
+    fn test_write_json() {
+        let component = ChatComponent::from_text("héllo ✓", ComponentStyle::v1_16().bold(true));
+        let expected = serde_json::to_string(&component).unwrap();
+
+        let mut buffer = vec![1];
+        component.write_json(&mut buffer).unwrap();
+        assert_eq!(&buffer[1..], expected.as_bytes());
+
+        let mut out = String::from("> ");
+        component.write_json_fmt(&mut out).unwrap();
+        assert_eq!(out, format!("> {}", expected));
+    }
+
+    #[test]
+    fn test_nbt_component() {
+        use crate::component::{ComponentType, NbtSource};
+
+        let json = r#"{"nbt":"Yaw","storage":"minecraft:data","bold":true}"#;
+        let component: ChatComponent = serde_json::from_str(json).unwrap();
+        match component.get_kind() {
+            ComponentType::Nbt(nbt) => {
+                assert_eq!(nbt.get_nbt(), "Yaw");
+                assert_eq!(nbt.get_source(), &NbtSource::storage("minecraft:data"));
+            }
+            kind => panic!("not an nbt component: {:?}", kind),
+        }
+        assert_eq!(component.get_bold(), Some(true));
+        #[cfg(feature = "preserve_unknown")]
+        assert!(component.get_unknown_fields().is_empty());
+        assert_eq!(
+            serde_json::to_value(&component).unwrap(),
+            serde_json::from_str::<serde_json::Value>(json).unwrap()
+        );
+        assert_eq!(component.to_plain_text(), "");
+        assert!(ChatComponent::from_json(r#"{"nbt":"Yaw"}"#).is_err());
+
+        let component =
+            ChatComponent::from_json(r#"{"nbt":"Items[0].id","block":"12 34 -56"}"#).unwrap();
+        match component.get_kind() {
+            ComponentType::Nbt(nbt) => {
+                assert_eq!(nbt.get_source(), &NbtSource::block_at(12, 34, -56));
+                assert_eq!(nbt.get_source().get_block_position(), Some((12, 34, -56)));
+            }
+            kind => panic!("not an nbt component: {:?}", kind),
+        }
+        assert_eq!(NbtSource::block("~ ~1 ~").get_block_position(), None);
+
+        let json = r#"{"nbt":"Health","entity":"@e[type=pig,limit=1]"}"#;
+        let component = ChatComponent::from_json(json).unwrap();
+        assert_eq!(
+            component.get_kind(),
+            &ComponentType::Nbt(crate::NbtComponent::from_nbt(
+                "Health",
+                NbtSource::entity("@e[type=pig,limit=1]")
+            ))
+        );
+        assert_eq!(serde_json::to_string(&component).unwrap(), json);
+
+        let mut component =
+            ChatComponent::from_json(r#"{"nbt":"Name","storage":"a:b","interpret":true}"#).unwrap();
+        if let ComponentType::Nbt(nbt) = component.get_kind_mut() {
+            assert!(nbt.get_interpret());
+            nbt.set_interpret(false);
+        }
+        assert!(!serde_json::to_string(&component)
+            .unwrap()
+            .contains("interpret"));
+        assert!(ChatComponent::from_json(r#"{"nbt":"a","storage":"a:b","interpret":1}"#).is_err());
+
+        let mut downgraded = component.clone();
+        downgraded.downgrade(crate::VERSION_1_12);
+        assert!(matches!(downgraded.get_kind(), ComponentType::Text(_)));
+
+        let json = r#"{"selector":"@a","separator":{"text":" | ","color":"gray"}}"#;
+        let mut component = ChatComponent::from_json(json).unwrap();
+        match component.get_kind() {
+            ComponentType::Selector(selector) => {
+                assert_eq!(selector.get_separator().unwrap().to_plain_text(), " | ")
+            }
+            kind => panic!("not a selector: {:?}", kind),
+        }
+        assert_eq!(
+            serde_json::to_value(&component).unwrap(),
+            serde_json::from_str::<serde_json::Value>(json).unwrap()
+        );
+        assert!(ChatComponent::from_json(r#"{"nbt":"a","storage":"a:b","separator":[]}"#).is_err());
+        component.downgrade(crate::VERSION_1_16);
+        assert_eq!(
+            serde_json::to_string(&component).unwrap(),
+            r#"{"selector":"@a"}"#
+        );
+    }
+
+    #[test]
+    fn test_translation_fallback() {
+        use crate::component::ComponentType;
+
+        let json = r#"{"translate":"item.mymod.gem","with":[],"fallback":"Gem"}"#;
+        let mut component = ChatComponent::from_json(json).unwrap();
+        match component.get_kind() {
+            ComponentType::Translation(translation) => {
+                assert_eq!(translation.get_fallback(), Some("Gem"))
+            }
+            kind => panic!("not a translation: {:?}", kind),
+        }
+        assert_eq!(
+            serde_json::to_value(&component).unwrap(),
+            serde_json::json!({"translate": "item.mymod.gem", "fallback": "Gem", "with": []})
+        );
+        component.downgrade(crate::VERSION_1_16);
+        assert!(!serde_json::to_string(&component)
+            .unwrap()
+            .contains("fallback"));
+        assert!(ChatComponent::from_json(r#"{"translate":"a","with":[],"fallback":1}"#).is_err());
+    }
+
+    #[test]
+    fn test_primitive_args() {
+        use crate::component::{ComponentType, TranslationComponent};
+
+        let translation = TranslationComponent::from_key("commands.xp")
+            .argument("Steve")
+            .argument(3)
+            .argument(1.5)
+            .argument(true)
+            .argument(ChatComponent::from_text("x", ComponentStyle::v1_16()));
+        let component = ChatComponent::from_component(
+            ComponentType::Translation(translation),
+            ComponentStyle::v1_16(),
+        );
+        let json = serde_json::to_value(&component).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"translate": "commands.xp", "with": ["Steve", 3, 1.5, true, {"text": "x"}]})
+        );
+        let parsed = ChatComponent::from_json(&json.to_string()).unwrap();
+        assert_eq!(parsed, component);
+        assert_eq!(serde_json::to_value(&parsed).unwrap()["with"][1], 3);
+
+        // a styled argument can't be a primitive anymore
+        let mut translation = TranslationComponent::from_key("a").argument(3);
+        translation.get_args_mut()[0].get_style_mut().set_bold(true);
+        assert_eq!(
+            serde_json::to_value(&translation).unwrap()["with"][0],
+            serde_json::json!({"text": "3", "bold": true})
+        );
+    }
+
+    #[test]
+    fn test_packet_encoding() {
+        let component = ChatComponent::from_text("hi", ComponentStyle::v1_16());
+        let mut out = vec![0xFF];
+        component.write_packet_json(&mut out);
+        assert_eq!(out, b"\xFF\x0D{\"text\":\"hi\"}");
+
+        let long = ChatComponent::from_text("a".repeat(200), ComponentStyle::v1_16());
+        let mut out = vec![];
+        long.write_packet_json(&mut out);
+        assert_eq!(&out[..2], &[0xD3, 0x01]);
+        assert_eq!(out.len(), 2 + 211);
+
+        let mut input = &out[..];
+        assert_eq!(ChatComponent::read_packet_json(&mut input).unwrap(), long);
+        assert!(input.is_empty());
+        let mut input = &out[..100];
+        assert_eq!(
+            ChatComponent::read_packet_json(&mut input),
+            Err(crate::PacketErr::Incomplete)
+        );
+        assert_eq!(input.len(), 100);
+        assert_eq!(
+            ChatComponent::read_packet_json(&mut &[0xFF; 6][..]),
+            Err(crate::PacketErr::InvalidLength)
+        );
+
+        #[cfg(feature = "nbt")]
+        {
+            let mut out = vec![];
+            component.write_packet(765, &mut out);
+            assert_eq!(out, component.to_network_nbt());
+            out.push(1);
+            let mut input = &out[..];
+            assert_eq!(
+                ChatComponent::read_packet(765, &mut input).unwrap(),
+                component
+            );
+            assert_eq!(input, &[1]);
+            assert_eq!(
+                ChatComponent::read_packet(765, &mut &out[..3]),
+                Err(crate::PacketErr::Incomplete)
+            );
+            let mut out = vec![];
+            component.write_packet(764, &mut out);
+            assert_eq!(out[0], 13);
+            #[cfg(feature = "bytes")]
+            {
+                let mut buf = bytes::BytesMut::new();
+                component.write_to_buf(764, &mut buf);
+                component.write_to_buf(765, &mut buf);
+                assert_eq!(&buf[..out.len()], &out[..]);
+                assert_eq!(&buf[out.len()..], &component.to_network_nbt()[..]);
+            }
+        }
+    }
+
+    #[cfg(feature = "tokio-codec")]
+    #[test]
+    fn test_chat_codec() {
+        use crate::{ChatCodec, CodecErr, PacketErr};
+        use tokio_util::codec::{Decoder, Encoder};
+
+        let component = ChatComponent::from_text("hi", ComponentStyle::v1_16());
+        for version in [764, 765] {
+            let mut codec = ChatCodec::new(version);
+            let mut buf = bytes::BytesMut::new();
+            codec.encode(&component, &mut buf).unwrap();
+            codec.encode(&component, &mut buf).unwrap();
+            let length = buf.len() / 2;
+
+            let mut partial = buf.split_to(length - 1);
+            assert_eq!(codec.decode(&mut partial).unwrap(), None);
+            partial.unsplit(buf);
+            let mut buf = partial;
+            assert_eq!(codec.decode(&mut buf).unwrap(), Some(component.clone()));
+            assert_eq!(buf.len(), length);
+            assert_eq!(codec.decode(&mut buf).unwrap(), Some(component.clone()));
+            assert!(buf.is_empty());
+        }
+
+        let mut buf = bytes::BytesMut::from(&b"\x03{}}"[..]);
+        assert!(matches!(
+            ChatCodec::new(764).decode(&mut buf),
+            Err(CodecErr::Packet(PacketErr::Json(_)))
+        ));
+    }
+
+    #[test]
+    fn test_borrowed_component() {
+        use crate::component::{BorrowedComponent, BorrowedKind};
+        use std::borrow::Cow;
+
+        let json = br#"{"text":"hi","color":"red","bold":true,"extra":["a\nb",{"translate":"k","with":[1]}]}"#;
+        let component = BorrowedComponent::from_slice(json).unwrap();
+        assert!(matches!(
+            component.get_kind(),
+            BorrowedKind::Text(Cow::Borrowed("hi"))
+        ));
+        assert_eq!(component.get_color(), Some("red"));
+        assert!(matches!(
+            component.get_siblings()[0].get_kind(),
+            BorrowedKind::Text(Cow::Owned(text)) if text == "a\nb"
+        ));
+
+        let owned = component.to_component(crate::VERSION_1_16);
+        assert_eq!(owned.to_plain_text(), "hia\nbk");
+        assert_eq!(owned.get_bold(), Some(true));
+        assert!(BorrowedComponent::from_slice(b"[]").is_err());
+    }
+
+    #[test]
+    fn test_lenient_json() {
+        let component = ChatComponent::from_json_lenient(
+            r#"{"text":"a","bold":"true","italic":1,"color":null,"font":3,"extra":[null,{},{"text":2,"underlined":"no"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(component.get_bold(), Some(true));
+        assert_eq!(component.get_italic(), Some(true));
+        assert_eq!(component.get_color(), None);
+        assert_eq!(component.get_siblings().len(), 2);
+        assert_eq!(component.to_plain_text(), "a2");
+        assert_eq!(component.get_siblings()[1].get_underlined(), None);
+
+        let component =
+            ChatComponent::from_json_lenient(r#"{"translate":"a","with":"b"}"#).unwrap();
+        assert_eq!(component.to_plain_text(), "a");
+        assert!(ChatComponent::from_json_lenient("{}").is_ok());
+        assert!(ChatComponent::from_json(r#"{"text":"a","bold":"true"}"#).is_err());
+    }
+
+    #[test]
+    fn test_compact_json() {
+        let mut component = ChatComponent::from_key("chat.type.text", ComponentStyle::v1_16());
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "b",
+            ComponentStyle::v1_16().bold(true),
+        ));
+        if let crate::ComponentType::Translation(translation) = component.get_kind_mut() {
+            translation.add_arg(ChatComponent::from_text("Steve", ComponentStyle::v1_16()));
+        }
+        let compact: serde_json::Value =
+            serde_json::from_str(&component.to_compact_json()).unwrap();
+        assert_eq!(
+            compact,
+            json!({"translate":"chat.type.text","with":["Steve"],"extra":[{"text":"b","bold":true}]})
+        );
+
+        let plain = ChatComponent::from_key("a", ComponentStyle::v1_16());
+        assert_eq!(plain.to_compact_json(), r#"{"translate":"a"}"#);
+        let plain = ChatComponent::from_text("a\"b", ComponentStyle::v1_16());
+        assert_eq!(plain.to_compact_json(), r#""a\"b""#);
+    }
+
+    #[cfg(feature = "preserve_order")]
+    #[test]
+    fn test_preserve_order() {
+        let json =
+            r#"{"extra":[{"color":"red","text":"b"}],"insertion":"i","italic":true,"text":"a"}"#;
+        let (mut component, order) = ChatComponent::from_json_preserving_order(json).unwrap();
+        assert_eq!(component.to_json_preserving_order(&order), json);
+
+        component.set_bold(true);
+        assert_eq!(
+            component.to_json_preserving_order(&order),
+            r#"{"extra":[{"color":"red","text":"b"}],"insertion":"i","italic":true,"text":"a","bold":true}"#
+        );
+    }
+
+    #[test]
+    fn test_status_response() {
+        let response = crate::StatusResponse::new(
+            crate::StatusVersion::new("1.16.5", 754),
+            ChatComponent::from_text("A server", ComponentStyle::v1_16()),
+        )
+        .players(Some(crate::StatusPlayers::new(1, 20).player(
+            crate::StatusPlayer::new("Notch", "069a79f4-44e9-4726-a5be-fca90e38aaf5"),
+        )));
+        let json: serde_json::Value =
+            serde_json::from_str(&response.to_json(crate::style::VERSION_1_16)).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "version": {"name": "1.16.5", "protocol": 754},
+                "players": {"max": 20, "online": 1, "sample": [{"name": "Notch", "id": "069a79f4-44e9-4726-a5be-fca90e38aaf5"}]},
+                "description": {"text": "A server"}
+            })
+        );
+        assert_eq!(
+            response.to_legacy_response(),
+            "§1\u{0}754\u{0}1.16.5\u{0}A server\u{0}1\u{0}20"
+        );
+    }
+
+    #[test]
+    fn test_parse_description() {
+        let version = crate::style::VERSION_1_16;
+        let motd =
+            crate::StatusResponse::parse_description(&json!("§aA §lserver"), version).unwrap();
+        assert_eq!(motd.get_siblings().len(), 2);
+        assert_eq!(motd.get_siblings()[1].get_bold(), Some(true));
+
+        let motd = crate::StatusResponse::parse_description(
+            &json!({"text":"§cRed","italic":true,"extra":[{"text":"A§lB"}]}),
+            version,
+        )
+        .unwrap();
+        assert_eq!(motd.get_color(), Some(&ChatColor::Red));
+        assert_eq!(motd.get_italic(), Some(true));
+        assert_eq!(motd.to_plain_text(), "RedAB");
+        assert_eq!(
+            motd.get_siblings()[0].get_siblings()[1].get_bold(),
+            Some(true)
+        );
+
+        let response: crate::StatusResponse = serde_json::from_value(json!({
+            "version": {"name": "1.8.9", "protocol": 47},
+            "description": {"text": "§eHi"}
+        }))
+        .unwrap();
+        assert_eq!(
+            response.get_description().get_color(),
+            Some(&ChatColor::Yellow)
+        );
+    }
+
+    #[test]
+    fn test_standalone_style() {
+        let mut style: ComponentStyle = serde_json::from_value(json!({"color":"gold","italic":false,"font":"minecraft:uniform","clickEvent":{"action":"copy_to_clipboard","value":"x"}})).unwrap();
+        assert_eq!(
+            serde_json::to_value(&style).unwrap(),
+            json!({"italic":false,"color":"gold","font":"minecraft:uniform","clickEvent":{"action":"copy_to_clipboard","value":"x"}})
+        );
+
+        style.change_version(crate::style::VERSION_1_8);
+        assert_eq!(
+            serde_json::to_value(&style).unwrap(),
+            json!({"italic":false,"color":"gold"})
+        );
+    }
+
+    #[test]
+    fn test_chat_type_decoration() {
+        let chat_type: crate::ChatType = serde_json::from_value(json!({
+            "chat":{"translation_key":"chat.type.team.text","parameters":["target","sender","content"]},
+            "narration":{"translation_key":"chat.type.text.narrate","parameters":["sender","content"],"style":{"italic":true}}
+        }))
+        .unwrap();
+
+        let content = ChatComponent::from_text("hi", ComponentStyle::v1_16());
+        let sender = ChatComponent::from_text("Steve", ComponentStyle::v1_16());
+        let decorated = chat_type.decorate(content.clone(), sender.clone(), None);
+        assert_eq!(
+            serde_json::to_value(&decorated).unwrap(),
+            json!({"translate":"chat.type.team.text","with":[{"text":""},{"text":"Steve"},{"text":"hi"}]})
+        );
+
+        let narrated = chat_type.decorate_narration(content, sender, None);
+        assert_eq!(
+            serde_json::to_value(&narrated).unwrap(),
+            json!({"translate":"chat.type.text.narrate","with":[{"text":"Steve"},{"text":"hi"}],"italic":true})
+        );
+    }
+
+    #[test]
+    fn test_entity_tooltip() {
+        let mut component: ChatComponent = serde_json::from_value(json!({"text":"Pig","hoverEvent":{"action":"show_entity","contents":{"type":"minecraft:pig","id":"f84c6a79-0a4e-45e0-879b-cd49ebd4c4e2","name":{"text":"Pig","color":"red"}}}})).unwrap();
+        match component.get_hover_event() {
+            Some(HoverEvent::ShowEntityTooltip(entity)) => {
+                assert_eq!(entity.get_kind(), "minecraft:pig");
+                assert!(entity.get_name().is_some());
+            }
+            _ => panic!("expected an entity tooltip"),
+        }
+        assert_eq!(
+            serde_json::to_value(&component).unwrap(),
+            json!({"text":"Pig","hoverEvent":{"action":"show_entity","contents":{"type":"minecraft:pig","id":"f84c6a79-0a4e-45e0-879b-cd49ebd4c4e2","name":{"text":"Pig","color":"red"}}}})
+        );
+
+        component.downgrade(crate::style::VERSION_1_15);
+        let legacy = serde_json::to_string(&component).unwrap();
+        assert_eq!(
+            legacy,
+            r#"{"text":"Pig","hoverEvent":{"action":"show_entity","value":"{type:\"minecraft:pig\",id:\"f84c6a79-0a4e-45e0-879b-cd49ebd4c4e2\",name:\"{\\\"text\\\":\\\"Pig\\\",\\\"color\\\":\\\"red\\\"}\"}"}}"#
+        );
+        let read: ChatComponent = serde_json::from_str(&legacy).unwrap();
+        assert!(matches!(
+            read.get_hover_event(),
+            Some(HoverEvent::ShowEntityTooltip(entity)) if entity.get_name().is_some()
+        ));
+
+        // read by the same SNBT parser as components, so both quotes and escapes work
+        let read: ChatComponent = serde_json::from_value(json!({"text":"Pig","hoverEvent":{"action":"show_entity","value":"{type:'minecraft:pig', id:\"a\\\"b\"}"}})).unwrap();
+        match read.get_hover_event() {
+            Some(HoverEvent::ShowEntityTooltip(entity)) => {
+                assert_eq!(entity.get_kind(), "minecraft:pig");
+                assert_eq!(entity.get_id(), "a\"b");
+                assert!(entity.get_name().is_none());
+            }
+            _ => panic!("expected an entity tooltip"),
+        }
+    }
+}
+
+mod downgrade {
+    use crate::component::{ChatComponent, ComponentType};
+    use crate::style::{ComponentStyle, VERSION_1_12, VERSION_1_8};
+
+    #[test]
+    fn test_keybind_downgrade() {
+        let mut component = ChatComponent::from_text("Press ", ComponentStyle::v1_16());
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_keybind(
+                "key.jump",
+                ComponentStyle::v1_16(),
+            ));
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_keybind(
+                "key.custom",
+                ComponentStyle::v1_16(),
+            ));
+
+        let mut kept = component.clone();
+        kept.downgrade(VERSION_1_12);
+        assert!(matches!(
+            kept.get_siblings()[0].get_kind(),
+            ComponentType::Keybind(_)
+        ));
+
+        component.downgrade(VERSION_1_8);
+        let texts: Vec<_> = component
+            .get_siblings()
+            .iter()
+            .map(|sibling| match sibling.get_kind() {
+                ComponentType::Text(text) => text.get_text(),
+                _ => panic!("keybind was not replaced"),
+            })
+            .collect();
+        assert_eq!(texts, ["Space", "key.custom"]);
+        assert_eq!(component.get_siblings()[0].get_version(), VERSION_1_8);
+    }
+}
+
+mod intern {
+    use crate::component::{ChatComponent, ComponentType};
+    use crate::intern::Interner;
+    use crate::pool::ComponentPool;
+    use crate::style::ComponentStyle;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_intern() {
+        let mut interner = Interner::new();
+        let mut first = ChatComponent::from_key("chat.type.text", ComponentStyle::v1_16());
+        let mut second = first.clone();
+        second.get_siblings_mut().push(ChatComponent::from_key(
+            "chat.type.text",
+            ComponentStyle::v1_16(),
+        ));
+
+        first.intern(&mut interner);
+        second.intern(&mut interner);
+
+        assert_eq!(interner.len(), 1);
+        interner.shrink();
+        assert_eq!(interner.len(), 1);
+        drop((first, second));
+        interner.shrink();
+        assert!(interner.is_empty());
+    }
+
+    #[test]
+    fn test_component_pool() {
+        let mut pool = ComponentPool::new();
+        let joined = || {
+            let mut joined =
+                ChatComponent::from_key("multiplayer.player.joined", ComponentStyle::v1_16());
+            if let ComponentType::Translation(translation) = joined.get_kind_mut() {
+                translation.add_arg(ChatComponent::from_text("Steve", ComponentStyle::v1_16()));
+            }
+            joined
+        };
+
+        let first = pool.insert(joined());
+        let second = pool.insert(joined());
+        assert!(Arc::ptr_eq(&first, &second));
+        let mut bold = joined();
+        bold.set_bold(true);
+        assert_ne!(*first.get_component(), bold);
+        #[cfg(feature = "json")]
+        assert_eq!(
+            first.get_json(),
+            r#"{"translate":"multiplayer.player.joined","with":[{"text":"Steve"}]}"#
+        );
+
+        pool.insert(ChatComponent::from_text("Bye", ComponentStyle::v1_16()));
+        assert_eq!(pool.len(), 2);
+        pool.shrink();
+        assert_eq!(pool.len(), 1);
+        drop((first, second));
+        pool.shrink();
+        assert!(pool.is_empty());
+
+        // equal, but the argument is written as a number only by the first
+        let mut number = ChatComponent::from_key("commands.xp", ComponentStyle::v1_16());
+        let mut text = number.clone();
+        if let ComponentType::Translation(translation) = number.get_kind_mut() {
+            translation.add_arg(3);
+        }
+        if let ComponentType::Translation(translation) = text.get_kind_mut() {
+            translation.add_arg(ChatComponent::from_text("3", ComponentStyle::v1_16()));
+        }
+        assert_eq!(number, text);
+        let number = pool.insert(number);
+        let text = pool.insert(text);
+        assert!(!Arc::ptr_eq(&number, &text));
+        #[cfg(feature = "json")]
+        assert_eq!(
+            number.get_json(),
+            r#"{"translate":"commands.xp","with":[3]}"#
+        );
+        assert_eq!(pool.len(), 2);
+    }
+}
+
+mod size {
+    use crate::component::{ChatComponent, ComponentType};
+    use crate::intern::Interner;
+    use crate::style::{ComponentStyle, HoverEvent};
+    use std::mem::size_of;
+
+    fn text(text: &str) -> ChatComponent {
+        ChatComponent::from_text(text, ComponentStyle::v1_16())
+    }
+
+    #[test]
+    fn test_deep_size_of() {
+        let short = text("a");
+        assert_eq!(text("abcd").deep_size_of(), short.deep_size_of() + 3);
+
+        let mut siblings = short.clone();
+        siblings.get_siblings_mut().push(text("bc"));
+        assert!(siblings.deep_size_of() >= short.deep_size_of() + size_of::<ChatComponent>() + 2);
+
+        let mut hover = short.clone();
+        hover.set_hover_event(Some(HoverEvent::ShowText(Box::new(text("bc")))));
+        assert_eq!(
+            hover.deep_size_of(),
+            short.deep_size_of() + size_of::<ChatComponent>() + 2
+        );
+
+        let mut translation = ChatComponent::from_key("chat.type.text", ComponentStyle::v1_16());
+        let without_args = translation.deep_size_of();
+        if let ComponentType::Translation(translation) = translation.get_kind_mut() {
+            translation.add_arg(text("Steve"));
+        }
+        assert!(translation.deep_size_of() >= without_args + size_of::<ChatComponent>() + 5);
+    }
+
+    #[test]
+    fn test_deep_size_of_interned() {
+        let mut component = ChatComponent::from_key("chat.type.text", ComponentStyle::v1_16());
+        component.get_siblings_mut().push(ChatComponent::from_key(
+            "chat.type.text",
+            ComponentStyle::v1_16(),
+        ));
+        let size = component.deep_size_of();
+
+        // shared keys are counted for every use, so interning doesn't change the estimate
+        component.intern(&mut Interner::new());
+        assert_eq!(component.deep_size_of(), size);
+    }
+}
+
+mod path {
+    use crate::component::{ChatComponent, ComponentType, TranslationComponent};
+    use crate::style::{ClickEvent, ComponentStyle};
+
+    #[test]
+    fn test_find_all() {
+        let link = ChatComponent::from_text(
+            "here",
+            ComponentStyle::v1_16().click_event(Some(ClickEvent::url("https://banned.example"))),
+        );
+        let mut component = ChatComponent::from_component(
+            ComponentType::Translation(
+                TranslationComponent::from_key("chat.type.text")
+                    .argument(ChatComponent::from_text("Steve", ComponentStyle::v1_16())),
+            ),
+            ComponentStyle::v1_16(),
+        );
+        component.get_siblings_mut().push(link.clone());
+        if let ComponentType::Translation(translation) = component.get_kind_mut() {
+            translation.get_args_mut()[0].get_siblings_mut().push(link);
+        }
+
+        let found: Vec<_> = component
+            .find_all(|c| matches!(c.get_click_event(), Some(ClickEvent::OpenUrl(url)) if url.contains("banned")))
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(
+            found
+                .iter()
+                .map(|path| path.to_string())
+                .collect::<Vec<_>>(),
+            ["with[0].extra[0]", "extra[0]"]
+        );
+
+        for path in &found {
+            component.get_path_mut(path).unwrap().set_click_event(None);
+        }
+        assert!(component
+            .find_all(|c| c.get_click_event().is_some())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_separator_path() {
+        use crate::component::SelectorComponent;
+
+        let separator = ChatComponent::from_text(" | ", ComponentStyle::v1_16());
+        let mut component = ChatComponent::from_component(
+            ComponentType::Selector(
+                SelectorComponent::from_selector("@a").separator(Some(separator)),
+            ),
+            ComponentStyle::v1_16(),
+        );
+        let found: Vec<_> = component
+            .find_all(|c| c.to_plain_text() == " | ")
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].to_string(), "separator");
+        assert_eq!(
+            found[0].get_segments(),
+            [crate::component::PathSegment::Separator]
+        );
+
+        component
+            .get_path_mut(&found[0])
+            .unwrap()
+            .get_style_mut()
+            .set_bold(true);
+        assert_eq!(
+            component
+                .get_path(&found[0])
+                .unwrap()
+                .get_style()
+                .get_bold(),
+            Some(true)
+        );
+    }
+}
+
+mod replace {
+    use crate::component::ChatComponent;
+    use crate::style::{ChatColor, ComponentStyle, HoverEvent};
+
+    #[test]
+    fn test_replace_with_component() {
+        let mut message = ChatComponent::from_text(
+            "I found a diamond and another diamond!",
+            ComponentStyle::v1_16().color(Some(ChatColor::Gray)),
+        );
+
+        let mut replaced = 0;
+        message.replace_text_with("diamond", |name| {
+            replaced += 1;
+            ChatComponent::from_text(
+                format!("[{}]", name),
+                ComponentStyle::v1_16()
+                    .color(Some(ChatColor::Cyan))
+                    .hover_event(Some(HoverEvent::ShowItem(
+                        "{id:\"minecraft:diamond\"}".to_string(),
+                    ))),
+            )
+        });
+        message.replace_text("another", "one more");
+
+        assert_eq!(replaced, 2);
+        let mut plain = String::new();
+        message.append_plain_text(&mut plain);
+        assert_eq!(plain, "I found a [diamond] and one more [diamond]!");
+        assert_eq!(message.get_siblings().len(), 4);
+        assert!(message.get_siblings()[0].get_hover_event().is_some());
+        assert_eq!(message.get_siblings()[1].get_color(), None);
+    }
+}
+
+mod score {
+    use crate::component::{ChatComponent, ComponentType, ScoreComponent};
+    use crate::style::ComponentStyle;
+
+    #[test]
+    fn test_resolve_reader() {
+        let mut component = ChatComponent::from_text("Kills: ", ComponentStyle::v1_16());
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_component(
+                ComponentType::Score(ScoreComponent::reader("kills")),
+                ComponentStyle::v1_16(),
+            ));
+
+        component.resolve_reader("Notch");
+
+        match component.get_siblings()[0].get_kind() {
+            ComponentType::Score(score) => {
+                assert!(!score.is_reader());
+                assert_eq!(score.get_name(), "Notch");
+            }
+            _ => panic!("score was replaced"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_vanilla_score() {
+        let json = r#"{"text":"Kills: ","extra":[{"score":{"name":"*","objective":"kills"}}]}"#;
+        let mut component = ChatComponent::from_json(json).unwrap();
+        match component.get_siblings()[0].get_kind() {
+            ComponentType::Score(score) => {
+                assert!(score.is_reader());
+                assert_eq!(score.get_objective(), "kills");
+                assert_eq!(score.get_value(), None);
+            }
+            _ => panic!("score was not read"),
+        }
+        assert_eq!(serde_json::to_string(&component).unwrap(), json);
+
+        component.resolve_reader("Notch");
+        assert_eq!(
+            serde_json::to_string(&component).unwrap(),
+            r#"{"text":"Kills: ","extra":[{"score":{"name":"Notch","objective":"kills"}}]}"#
+        );
+        // the flat fields are no score, at most a component of an unknown kind
+        let flat = ChatComponent::from_json(r#"{"name":"*","objective":"kills"}"#);
+        assert!(!matches!(
+            flat.as_ref().map(ChatComponent::get_kind),
+            Ok(ComponentType::Score(_))
+        ));
+    }
+}
+
+mod inspect {
+    use crate::component::ChatComponent;
+    use crate::style::{ChatColor, ClickEvent, ComponentStyle, HoverEvent};
+
+    #[test]
+    fn test_inspect() {
+        let red = || ComponentStyle::v1_16().color(Some(ChatColor::Red));
+        let hover = ChatComponent::from_text("ää", red());
+        let mut component = ChatComponent::from_key(
+            "chat.type.text",
+            ComponentStyle::v1_16()
+                .hover_event(Some(HoverEvent::ShowText(Box::new(hover))))
+                .click_event(Some(ClickEvent::suggest_command("/msg Steve "))),
+        );
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_text("abc", red()));
+        component.get_siblings_mut()[0]
+            .get_siblings_mut()
+            .push(ChatComponent::from_text("x", ComponentStyle::v1_16()));
+
+        let stats = component.inspect();
+        assert_eq!(stats.components, 4);
+        assert_eq!(stats.texts, 3);
+        assert_eq!(stats.translations, 1);
+        assert_eq!(stats.click_events, 1);
+        assert_eq!(stats.hover_events, 1);
+        assert_eq!(stats.colors.get(&ChatColor::Red), Some(&2));
+        assert_eq!(stats.colors.len(), 1);
+        assert_eq!(stats.total_text_length, 6);
+        // "ää" is 4 bytes but only 2 characters, so it comes after "abc"
+        assert_eq!(stats.largest_texts, ["abc", "ää", "x"]);
+    }
+}
+
+#[cfg(feature = "json")]
+mod selector {
+    use crate::component::{
+        ChatComponent, ComponentType, ScoreComponent, Selector, SelectorComponent,
+    };
+    use crate::style::ComponentStyle;
+
+    #[test]
+    fn test_selector_builder() {
+        assert_eq!(Selector::executor().to_string(), "@s");
+        let selector = Selector::all_entities()
+            .entity_type("minecraft:zombie", false)
+            .distance(None, Some(10.5))
+            .tag("boss fight", true)
+            .score("kills", Some(1), None)
+            .score("deaths", Some(0), Some(0))
+            .limit(1);
+        assert_eq!(
+            selector.to_string(),
+            r#"@e[type=minecraft:zombie,distance=..10.5,tag=!"boss fight",limit=1,scores={kills=1..,deaths=0}]"#
+        );
+        assert_eq!(
+            Selector::all_players().level(Some(5), Some(10)).to_string(),
+            "@a[level=5..10]"
+        );
+
+        let component = ChatComponent::from_component(
+            ComponentType::Selector(SelectorComponent::from_selector(Selector::nearest_player())),
+            ComponentStyle::v1_16(),
+        );
+        assert_eq!(
+            serde_json::to_string(&component).unwrap(),
+            r#"{"selector":"@p"}"#
+        );
+        let score = ScoreComponent::from_score(Selector::random_player(), "kills");
+        assert_eq!(score.get_name(), "@r");
+    }
+}
+
+mod keybind {
+    use crate::component::{
+        default_key_name, ChatComponent, ComponentType, Keybind, UnknownKeybindErr,
+    };
+    use crate::style::ComponentStyle;
+
+    #[test]
+    fn test_keybind() {
+        assert_eq!(Keybind::Jump.to_string(), "key.jump");
+        assert_eq!("key.hotbar.3".parse(), Ok(Keybind::Hotbar3));
+        assert_eq!(
+            "key.jmup".parse::<Keybind>(),
+            Err(UnknownKeybindErr("key.jmup".to_string()))
+        );
+        assert_eq!(Keybind::Sneak.default_key_name(), "Left Shift");
+        assert_eq!(default_key_name("key.playerlist"), Some("Tab"));
+
+        let component = ChatComponent::from_keybind(Keybind::SwapOffhand, ComponentStyle::v1_16());
+        match component.get_kind() {
+            ComponentType::Keybind(keybind) => {
+                assert_eq!(keybind.get_keybind(), "key.swapOffhand");
+                assert_eq!(keybind.get_vanilla_keybind(), Some(Keybind::SwapOffhand));
+            }
+            kind => panic!("not a keybind: {:?}", kind),
+        }
+        let modded = ChatComponent::from_keybind("key.mymod.dash", ComponentStyle::v1_16());
+        match modded.get_kind() {
+            ComponentType::Keybind(keybind) => assert_eq!(keybind.get_vanilla_keybind(), None),
+            kind => panic!("not a keybind: {:?}", kind),
+        }
+    }
+}
+
+mod style {
+    use crate::component::ChatComponent;
+    use crate::style::{ChatColor, ChatFormatting, ComponentStyle};
+
+    #[test]
+    fn test_resolved_styles() {
+        let mut component = ChatComponent::from_text(
+            "magic",
+            ComponentStyle::v1_16()
+                .obfuscated(true)
+                .color(Some(ChatColor::Red)),
+        );
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_text("still", ComponentStyle::v1_16()));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "plain",
+            ComponentStyle::v1_16().obfuscated(false),
+        ));
+
+        let resolved = component.resolved_styles();
+        let obfuscated: Vec<_> = resolved
+            .iter()
+            .map(|(_, style)| style.is_obfuscated())
+            .collect();
+        assert_eq!(obfuscated, [true, true, false]);
+        assert!(matches!(resolved[2].1.get_color(), Some(ChatColor::Red)));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_text_colors() {
+        let style = ComponentStyle::v1_16().color(Some(ChatColor::rgb(0x12, 0xAB, 0xEF)));
+        assert_eq!(
+            serde_json::to_string(&style).unwrap(),
+            r##"{"color":"#12ABEF"}"##
+        );
+        let style = ComponentStyle::v1_16().color(Some(ChatColor::Gold));
+        assert_eq!(
+            serde_json::to_string(&style).unwrap(),
+            r#"{"color":"gold"}"#
+        );
+
+        // hex colors are read in any case
+        let parsed: ComponentStyle = serde_json::from_str(r##"{"color":"#12abef"}"##).unwrap();
+        assert_eq!(parsed.get_color(), Some(&ChatColor::Rgb(0x12, 0xAB, 0xEF)));
+        assert_eq!(ChatColor::custom("#12abef"), ChatColor::custom("#12ABEF"));
+        assert_eq!(
+            ChatColor::custom("mymod:rainbow"),
+            ChatColor::Custom("mymod:rainbow".to_string())
+        );
+        assert_eq!(ChatColor::custom("mymod:rainbow").to_rgb(), None);
+        let parsed: ComponentStyle = serde_json::from_str(r#"{"color":"light_purple"}"#).unwrap();
+        assert_eq!(parsed.get_color(), Some(&ChatColor::Pink));
+
+        // before 1.16 there are only the named colors
+        let style = ComponentStyle::v1_15().color(Some(ChatColor::rgb(1, 2, 3)));
+        assert_eq!(
+            serde_json::to_string(&style).unwrap(),
+            r#"{"color":"black"}"#
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_font() {
+        let mut style = ComponentStyle::v1_16().font(Some("mypack:icons"));
+        assert_eq!(style.get_font(), Some("mypack:icons"));
+        assert_eq!(
+            serde_json::to_string(&style).unwrap(),
+            r#"{"font":"mypack:icons"}"#
+        );
+        style.set_font(Some(ComponentStyle::UNIFORM_FONT));
+        assert_eq!(
+            serde_json::to_string(&style).unwrap(),
+            r#"{"font":"minecraft:uniform"}"#
+        );
+        style.set_font(None::<String>);
+        assert_eq!(serde_json::to_string(&style).unwrap(), "{}");
+
+        let old = ComponentStyle::v1_15().font(Some(ComponentStyle::ALT_FONT));
+        assert_eq!(old.get_font(), None);
+        assert_eq!(serde_json::to_string(&old).unwrap(), "{}");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_shadow_color() {
+        use crate::style::{ShadowColor, VERSION_1_21_4};
+
+        let color = ShadowColor::new(0x80, 0xFF, 0x00, 0x00);
+        assert_eq!(color.get_argb(), 0x80FF0000);
+        assert_eq!(ShadowColor::from_floats(color.to_floats()), color);
+        assert_eq!(
+            ShadowColor::from_floats([0.0, 0.0, 1.0, 1.0]),
+            ShadowColor::from_argb(0xFF0000FF)
+        );
+
+        let style = ComponentStyle::with_version(769).shadow_color(Some(color));
+        assert_eq!(
+            serde_json::to_string(&style).unwrap(),
+            r#"{"shadow_color":-2130771968}"#
+        );
+        let old = ComponentStyle::v1_16().shadow_color(Some(color));
+        assert_eq!(old.get_shadow_color(), None);
+        assert_eq!(serde_json::to_string(&old).unwrap(), "{}");
+
+        // both forms are read, and written again
+        for json in [
+            r#"{"text":"a","shadow_color":-2130771968}"#,
+            r#"{"text":"a","shadow_color":2164195328}"#,
+            r#"{"text":"a","shadow_color":[1.0,0.0,0.0,0.502]}"#,
+        ] {
+            let mut component = ChatComponent::from_json(json).unwrap();
+            // the parsed value is kept, but only written for 1.21.4 and newer
+            assert_eq!(component.get_style().get_shadow_color(), None);
+            assert_eq!(
+                serde_json::to_string(&component).unwrap(),
+                r#"{"text":"a"}"#
+            );
+            component.change_version(VERSION_1_21_4);
+            assert_eq!(component.get_style().get_shadow_color(), Some(color));
+            assert_eq!(
+                serde_json::to_string(&component).unwrap(),
+                r#"{"text":"a","shadow_color":-2130771968}"#
+            );
+        }
+
+        // the version stays the same throughout a parsed tree
+        let component =
+            ChatComponent::from_json(r#"{"text":"a","extra":[{"text":"b","shadow_color":0}]}"#)
+                .unwrap();
+        assert_eq!(
+            component.get_version(),
+            component.get_siblings()[0].get_version()
+        );
+        let err = ChatComponent::from_json(r#"{"text":"a","shadow_color":[1.0]}"#).unwrap_err();
+        assert_eq!(err.get_path(), "shadow_color");
+    }
+
+    #[test]
+    fn test_parse_color() {
+        use crate::style::ColorParseErr;
+
+        assert_eq!("dark_aqua".parse(), Ok(ChatColor::DarkCyan));
+        assert_eq!(" Light_Purple ".parse(), Ok(ChatColor::Pink));
+        assert_eq!("#12abEF".parse(), Ok(ChatColor::rgb(0x12, 0xAB, 0xEF)));
+        assert_eq!("#F80".parse(), Ok(ChatColor::rgb(0xFF, 0x88, 0x00)));
+        assert_eq!("a".parse(), Ok(ChatColor::Green));
+        assert_eq!("&6".parse(), Ok(ChatColor::Gold));
+        assert_eq!("§C".parse(), Ok(ChatColor::Red));
+        for invalid in ["l", "bold", "#12345", "#GGGGGG", "", "pink"] {
+            assert_eq!(
+                invalid.parse::<ChatColor>(),
+                Err(ColorParseErr(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_nearest_legacy_color() {
+        assert_eq!(
+            ChatColor::rgb(0xF0, 0x50, 0x50).to_nearest_legacy(),
+            Some(ChatColor::Red)
+        );
+        assert_eq!(
+            ChatColor::rgb(0x10, 0x10, 0xB0).to_nearest_legacy(),
+            Some(ChatColor::DarkBlue)
+        );
+        assert_eq!(ChatColor::Gold.to_nearest_legacy(), Some(ChatColor::Gold));
+        assert_eq!(ChatColor::custom("not a color").to_nearest_legacy(), None);
+
+        let mut component = ChatComponent::from_text(
+            "hi",
+            ComponentStyle::v1_16().color(Some(ChatColor::rgb(0xFE, 0xFE, 0x50))),
+        );
+        component.downgrade(crate::VERSION_1_15);
+        assert_eq!(component.get_style().get_color(), Some(&ChatColor::Yellow));
+    }
+
+    #[test]
+    fn test_chat_formatting_codes() {
+        assert_eq!(ChatFormatting::from_code('r'), Some(ChatFormatting::Reset));
+        assert_eq!(ChatFormatting::from_code('R'), Some(ChatFormatting::Reset));
+        assert_eq!(ChatFormatting::from_code('A'), Some(ChatFormatting::Green));
+        assert_eq!(ChatFormatting::from_code('L'), Some(ChatFormatting::Bold));
+        assert_eq!(ChatFormatting::from_code('g'), None);
+        assert_eq!(ChatFormatting::from_code('x'), None);
+        assert_eq!(ChatFormatting::from_code('§'), None);
+        for formatting in ChatFormatting::ALL.iter() {
+            assert_eq!(
+                ChatFormatting::from_code(formatting.get_code()),
+                Some(*formatting)
+            );
+        }
+        assert_eq!(ChatFormatting::Reset.get_code(), 'r');
+        assert_eq!(ChatFormatting::Green.get_code(), 'a');
+
+        assert_eq!(
+            ChatFormatting::from_color(&ChatColor::Reset),
+            Some(ChatFormatting::Reset)
+        );
+        assert_eq!(
+            ChatFormatting::from_color(&ChatColor::Pink),
+            Some(ChatFormatting::Pink)
+        );
+        assert_eq!(
+            ChatFormatting::from_color(&ChatColor::rgb(255, 85, 85)),
+            None
+        );
+        assert_eq!(ChatFormatting::from_color(&ChatColor::custom("#abc")), None);
+    }
+}
+
+mod presets {
+    use crate::component::{ChatComponent, ComponentType};
+    use crate::presets::{self, AdvancementFrame};
+    use crate::style::{ChatColor, ClickEvent, ComponentStyle, HoverEvent};
+
+    fn text(text: &str) -> ChatComponent {
+        ChatComponent::from_text(text, ComponentStyle::v1_16())
+    }
+
+    fn plain(component: &ChatComponent) -> String {
+        let mut plain = String::new();
+        component.append_plain_text(&mut plain);
+        plain
+    }
+
+    #[test]
+    fn test_format_list() {
+        let list = |names: &[&str]| {
+            let list = presets::format_list(
+                names.iter().map(|name| text(name)),
+                text(", "),
+                text(" and "),
+            );
+            let mut plain = String::new();
+            list.append_plain_text(&mut plain);
+            plain
+        };
+        assert_eq!(list(&[]), "");
+        assert_eq!(list(&["A"]), "A");
+        assert_eq!(list(&["A", "B"]), "A and B");
+        assert_eq!(list(&["A", "B", "C"]), "A, B and C");
+    }
+
+    #[test]
+    fn test_confirm_prompt() {
+        let prompt = presets::confirm_prompt(text("Teleport?"), "/tpaccept", "/tpdeny");
+        assert_eq!(plain(&prompt), "Teleport? [Accept] [Decline]");
+
+        let buttons: Vec<_> = prompt
+            .get_siblings()
+            .iter()
+            .filter(|sibling| sibling.get_click_event().is_some())
+            .collect();
+        assert_eq!(buttons.len(), 2);
+        let expected = [
+            ("/tpaccept", "Click to accept", ChatColor::Green),
+            ("/tpdeny", "Click to decline", ChatColor::Red),
+        ];
+        for (button, (command, hint, color)) in buttons.iter().zip(expected.iter()) {
+            assert_eq!(
+                button.get_click_event(),
+                Some(&ClickEvent::run_command(*command))
+            );
+            assert_eq!(button.get_color(), Some(color));
+            match button.get_hover_event() {
+                Some(HoverEvent::ShowText(hover)) => assert_eq!(plain(hover), *hint),
+                _ => panic!("button has no hover text"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_lore() {
+        let italic = ChatComponent::from_text("Cursed", ComponentStyle::v1_16().italic(true));
+        let gold = ChatComponent::from_text(
+            "Legendary",
+            ComponentStyle::v1_16().color(Some(ChatColor::Gold)),
+        );
+        let lines = presets::lore(vec![text("Plain"), italic, gold]);
+
+        assert_eq!(lines[0].get_italic(), Some(false));
+        assert_eq!(lines[0].get_color(), Some(&ChatColor::White));
+        assert_eq!(lines[1].get_italic(), Some(true));
+        assert_eq!(lines[1].get_color(), Some(&ChatColor::White));
+        assert_eq!(lines[2].get_italic(), Some(false));
+        assert_eq!(lines[2].get_color(), Some(&ChatColor::Gold));
+
+        // children keep inheriting from the line
+        let mut line = text("Sharpness ");
+        line.get_siblings_mut().push(text("V"));
+        let line = presets::lore_line(line);
+        assert_eq!(line.get_siblings()[0].get_italic(), None);
+        assert_eq!(line.get_siblings()[0].get_color(), None);
+    }
+
+    #[test]
+    fn test_advancement() {
+        let announcement = presets::advancement(
+            text("Steve"),
+            text("Stone Age"),
+            text("Mine Stone with your new Pickaxe"),
+            AdvancementFrame::Challenge,
+        );
+        let translation = match announcement.get_kind() {
+            ComponentType::Translation(translation) => translation,
+            _ => panic!("announcement is not a translation"),
+        };
+        assert_eq!(translation.get_key(), "chat.type.advancement.challenge");
+        let args = translation.get_args();
+        assert_eq!(args.len(), 2);
+        assert_eq!(plain(&args[0]), "Steve");
+        assert_eq!(plain(&args[1]), "[Stone Age]");
+        assert_eq!(args[1].get_color(), Some(&ChatColor::Purple));
+
+        let hover = match args[1].get_hover_event() {
+            Some(HoverEvent::ShowText(hover)) => hover,
+            _ => panic!("advancement has no hover text"),
+        };
+        assert_eq!(plain(hover), "Stone Age\nMine Stone with your new Pickaxe");
+        assert_eq!(hover.get_color(), Some(&ChatColor::Purple));
+    }
+}
+
+mod team {
+    use crate::component::ChatComponent;
+    use crate::style::{ChatColor, ComponentStyle};
+    use crate::TeamFormat;
+
+    fn text(text: &str) -> ChatComponent {
+        ChatComponent::from_text(text, ComponentStyle::v1_16())
+    }
+
+    fn plain(component: &ChatComponent) -> String {
+        let mut plain = String::new();
+        component.append_plain_text(&mut plain);
+        plain
+    }
+
+    #[test]
+    fn test_apply_team_format() {
+        let team = TeamFormat::new()
+            .color(Some(ChatColor::Red))
+            .prefix(Some(text("[Red] ")))
+            .suffix(Some(text(" ♥")));
+        let name = team.apply_team_format(text("Steve"));
+        assert_eq!(plain(&name), "[Red] Steve ♥");
+        assert_eq!(name.get_color(), Some(&ChatColor::Red));
+        let siblings: Vec<String> = name.get_siblings().iter().map(plain).collect();
+        assert_eq!(siblings, ["[Red] ", "Steve", " ♥"]);
+
+        let reset = TeamFormat::new().color(Some(ChatColor::Reset));
+        let name = reset.apply_team_format(text("Alex"));
+        assert_eq!(plain(&name), "Alex");
+        assert_eq!(name.get_color(), None);
+    }
+}
+
+mod chat_type {
+    use crate::component::{ChatComponent, ComponentType};
+    use crate::style::ComponentStyle;
+    use crate::ChatType;
+
+    fn text(text: &str) -> ChatComponent {
+        ChatComponent::from_text(text, ComponentStyle::v1_16())
+    }
+
+    fn args(message: &ChatComponent) -> Vec<String> {
+        let translation = match message.get_kind() {
+            ComponentType::Translation(translation) => translation,
+            _ => panic!("message is not a translation"),
+        };
+        assert_eq!(translation.get_key(), "chat.type.text");
+        let plain = |arg: &ChatComponent| {
+            let mut plain = String::new();
+            arg.append_plain_text(&mut plain);
+            plain
+        };
+        translation.get_args().iter().map(plain).collect()
+    }
+
+    #[test]
+    fn test_render_player_chat() {
+        let chat = ChatType::chat();
+        let message = chat.render_player_chat("hello", None, text("Steve"), None);
+        assert_eq!(args(&message), ["Steve", "hello"]);
+
+        let decorated = ChatComponent::from_text("HELLO", ComponentStyle::v1_16().bold(true));
+        let message = chat.render_player_chat("hello", Some(decorated), text("Steve"), None);
+        assert_eq!(args(&message), ["Steve", "HELLO"]);
+        if let ComponentType::Translation(translation) = message.get_kind() {
+            assert_eq!(translation.get_args()[1].get_bold(), Some(true));
+        }
+    }
+}
+
+mod lint {
+    use crate::component::{ChatComponent, LintKind};
+    use crate::style::{ChatColor, ClickEvent, ComponentStyle};
+
+    #[test]
+    fn test_lint() {
+        let mut component =
+            ChatComponent::from_text("", ComponentStyle::v1_16().color(Some(ChatColor::Red)));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "§aGreen",
+            ComponentStyle::v1_16()
+                .color(Some(ChatColor::Gold))
+                .click_event(Some(ClickEvent::url("example.com"))),
+        ));
+        component.get_siblings_mut().push(ChatComponent::from_key(
+            "Hello there",
+            ComponentStyle::v1_16().color(Some(ChatColor::Gold)),
+        ));
+
+        let warnings: Vec<_> = component
+            .lint()
+            .into_iter()
+            .map(|warning| (warning.get_path().to_string(), warning.get_kind().clone()))
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![
+                ("$".to_string(), LintKind::OverriddenStyle("color")),
+                ("extra[0]".to_string(), LintKind::LegacyCode),
+                (
+                    "extra[0]".to_string(),
+                    LintKind::UrlWithoutProtocol("example.com".to_string())
+                ),
+                (
+                    "extra[1]".to_string(),
+                    LintKind::RawTranslationKey("Hello there".to_string())
+                ),
+            ]
+        );
+    }
+}
+
+mod narration {
+    use std::collections::HashMap;
+
+    use crate::component::{ChatComponent, ComponentType, TranslationComponent};
+    use crate::style::{ClickEvent, ComponentStyle};
+
+    #[test]
+    fn test_narration_string() {
+        let mut translations = HashMap::new();
+        translations.insert("chat.type.text".to_string(), "<%2$s> %1$s".to_string());
+
+        let mut content = ChatComponent::from_text("see ", ComponentStyle::v1_16());
+        content.get_siblings_mut().push(ChatComponent::from_text(
+            "this",
+            ComponentStyle::v1_16().click_event(Some(ClickEvent::url("https://example.com"))),
+        ));
+        content.get_siblings_mut().push(ChatComponent::from_text(
+            "#$%!",
+            ComponentStyle::v1_16().obfuscated(true),
+        ));
+        let message = ChatComponent::from_component(
+            ComponentType::Translation(
+                TranslationComponent::from_key("chat.type.text")
+                    .argument(content)
+                    .argument(ChatComponent::from_text("Steve", ComponentStyle::v1_16())),
+            ),
+            ComponentStyle::v1_16(),
+        );
+
+        assert_eq!(
+            message.to_narration_string(&translations),
+            "<Steve> see this (link: https://example.com)"
+        );
+    }
+
+    #[test]
+    fn test_plain_text() {
+        let mut component = ChatComponent::from_component(
+            ComponentType::Translation(
+                TranslationComponent::from_key("%s pressed %s")
+                    .argument(ChatComponent::from_text("Steve", ComponentStyle::v1_16()))
+                    .argument(ChatComponent::from_keybind(
+                        "key.jump",
+                        ComponentStyle::v1_16(),
+                    )),
+            ),
+            ComponentStyle::v1_16(),
+        );
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_selector("@p", ComponentStyle::v1_16()));
+        assert_eq!(component.to_plain_text(), "Steve pressed Space@p");
+    }
+
+    #[test]
+    fn test_styled_spans() {
+        let mut component = ChatComponent::from_component(
+            ComponentType::Translation(TranslationComponent::from_key("<%s> hi").argument(
+                ChatComponent::from_text("Steve", ComponentStyle::v1_16().italic(true)),
+            )),
+            ComponentStyle::v1_16().bold(true),
+        );
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_text("", ComponentStyle::v1_16()));
+        let spans: Vec<_> = component
+            .to_styled_spans()
+            .into_iter()
+            .map(|(text, style)| (text, style.get_bold(), style.get_italic()))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                ("<".to_string(), Some(true), None),
+                ("Steve".to_string(), Some(true), Some(true)),
+                ("> hi".to_string(), Some(true), None),
+            ]
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn test_crossterm_spans() {
+        use crossterm::style::{Attribute, Color};
+
+        let component = ChatComponent::from_text(
+            "hi",
+            ComponentStyle::v1_16()
+                .bold(true)
+                .color(Some(crate::ChatColor::Red)),
+        );
+        let spans = component.to_crossterm();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].content(), "hi");
+        let style = spans[0].style();
+        assert_eq!(
+            style.foreground_color,
+            Some(Color::Rgb {
+                r: 0xFF,
+                g: 0x55,
+                b: 0x55
+            })
+        );
+        assert!(style.attributes.has(Attribute::Bold));
+        assert!(!style.attributes.has(Attribute::Italic));
+    }
+
+    #[cfg(feature = "colored")]
+    #[test]
+    fn test_colored_spans() {
+        let component = ChatComponent::from_text(
+            "hi",
+            ComponentStyle::v1_16()
+                .italic(true)
+                .color(Some(crate::ChatColor::rgb(1, 2, 3))),
+        );
+        let spans = component.to_colored();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(&*spans[0], "hi");
+        assert_eq!(
+            spans[0].fgcolor,
+            Some(colored::Color::TrueColor { r: 1, g: 2, b: 3 })
+        );
+        assert!(spans[0].style.contains(colored::Styles::Italic));
+        assert!(!spans[0].style.contains(colored::Styles::Bold));
+    }
+}
+
+#[cfg(feature = "json")]
+mod commands {
+    use crate::commands::{parse_command, ComponentCommand, TitleAction};
+    use crate::component::ComponentType;
+
+    #[test]
+    fn test_parse_command() {
+        let (command, component) =
+            parse_command(r#"execute as @a run tellraw @a[name="A B",tag=x] {"text":"hi there"}"#)
+                .unwrap();
+        assert_eq!(
+            command,
+            ComponentCommand::Tellraw {
+                targets: r#"@a[name="A B",tag=x]"#.to_string()
+            }
+        );
+        assert!(
+            matches!(component.get_kind(), ComponentType::Text(text) if text.get_text() == "hi there")
+        );
+
+        // only the first `run` outside of the component starts the command
+        let (command, component) = parse_command(
+            r#"execute as @a at @s run execute if entity @s[tag=run] run tellraw @s {"text":"I run fast"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            command,
+            ComponentCommand::Tellraw {
+                targets: "@s".to_string()
+            }
+        );
+        assert_eq!(component.to_plain_text(), "I run fast");
+
+        let (command, _) = parse_command(r#"/title Steve actionbar {"text":"x"}"#).unwrap();
+        assert_eq!(
+            command,
+            ComponentCommand::Title {
+                targets: "Steve".to_string(),
+                action: TitleAction::Actionbar
+            }
+        );
+
+        let (command, _) =
+            parse_command(r#"bossbar set minecraft:raid name {"text":"Raid"}"#).unwrap();
+        assert_eq!(
+            command,
+            ComponentCommand::Bossbar {
+                id: "minecraft:raid".to_string()
+            }
+        );
+
+        let component = crate::ChatComponent::from_text("d\"q", crate::ComponentStyle::v1_16());
+        let command = component.to_tellraw_command("@a", crate::VERSION_1_16);
+        assert_eq!(command, r#"tellraw @a {"text":"d\"q"}"#);
+        assert!(parse_command(&command).is_ok());
+
+        assert!(parse_command("title @a times 10 70 20").is_err());
+        assert!(parse_command("tellraw @a").is_err());
+
+        let (command, component) =
+            parse_command(r#"titleraw @p actionbar {"rawtext":[{"text":"§lhi"}]}"#).unwrap();
+        assert_eq!(command.get_targets(), Some("@p"));
+        assert_eq!(component.get_bold(), Some(true));
+
+        #[cfg(feature = "nbt")]
+        {
+            let (command, component) =
+                parse_command("tellraw @a[tag=x] {text:'hi',bold:1b}").unwrap();
+            assert_eq!(command.get_targets(), Some("@a[tag=x]"));
+            assert_eq!(component.get_bold(), Some(true));
+        }
+    }
+
+    #[test]
+    fn test_build_command() {
+        let component = crate::ChatComponent::from_text("hi", crate::ComponentStyle::v1_16());
+        assert_eq!(
+            component.to_titleraw_command("@s", TitleAction::Subtitle),
+            r#"titleraw @s subtitle {"rawtext":[{"text":"hi"}]}"#
+        );
+        assert_eq!(
+            component.to_title_command("@a", TitleAction::Title, crate::VERSION_1_16),
+            r#"title @a title {"text":"hi"}"#
+        );
+        // 1.21.5 reads SNBT, which doesn't need the `nbt` feature to be written
+        assert_eq!(
+            component.to_tellraw_command("@a", 770),
+            r#"tellraw @a "hi""#
+        );
+        let red = crate::ChatComponent::from_text(
+            "hi",
+            crate::ComponentStyle::v1_16().color(Some(crate::ChatColor::Red)),
+        );
+        let command = red.to_tellraw_command("@a", 770);
+        assert!(command.starts_with("tellraw @a {"), "{}", command);
+        assert!(command.contains(r#"color:"red""#), "{}", command);
+    }
+}
+
+mod legacy {
+    use crate::component::{ChatComponent, ComponentType};
+    use crate::legacy::{LegacyOptions, LegacyParser, UnknownCodes};
+    use crate::style::{ChatColor, ComponentStyle, VERSION_1_16};
+
+    fn text(component: &ChatComponent) -> &str {
+        match component.get_kind() {
+            ComponentType::Text(text) => text.get_text(),
+            _ => panic!("not a text component"),
+        }
+    }
+
+    #[test]
+    fn test_streaming_parser() {
+        let mut parser = LegacyParser::new(VERSION_1_16);
+        let mut components = parser.feed("§aHello §");
+        components.extend(parser.feed("k§lmagic§"));
+        components.extend(parser.feed("c world"));
+        components.extend(parser.finish());
+
+        let texts: Vec<_> = components.iter().map(text).collect();
+        assert_eq!(texts, ["Hello ", "magic", " world"]);
+        assert_eq!(components[0].get_color(), Some(&ChatColor::Green));
+        assert!(components[1].is_obfuscated());
+        assert_eq!(components[1].get_bold(), Some(true));
+        // colors reset decorations
+        assert!(!components[2].is_obfuscated());
+        assert_eq!(components[2].get_bold(), None);
+        assert_eq!(components[2].get_color(), Some(&ChatColor::Red));
+    }
+
+    #[test]
+    fn test_to_legacy_string() {
+        let style = ComponentStyle::v1_16;
+        let mut component =
+            ChatComponent::from_text("Hello ", style().color(Some(ChatColor::Green)));
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_text("bold", style().bold(true)));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            " red",
+            style().color(Some(ChatColor::Red)),
+        ));
+        let mut translation = ChatComponent::from_key("%s!", style().italic(true));
+        if let ComponentType::Translation(translation) = translation.get_kind_mut() {
+            translation.add_arg(ChatComponent::from_text("hi", style().bold(true)));
+        }
+        component.get_siblings_mut().push(translation);
+
+        assert_eq!(
+            component.to_legacy_string(),
+            "§aHello §lbold§c red§a§o§lhi§a§o!"
+        );
+    }
+
+    #[test]
+    fn test_from_legacy_text() {
+        let component = ChatComponent::from_legacy_text("§aHello §lbold§r plain", VERSION_1_16);
+        let texts: Vec<_> = component.get_siblings().iter().map(text).collect();
+        assert_eq!(texts, ["Hello ", "bold", " plain"]);
+        assert_eq!(component.to_legacy_string(), "§aHello §lbold§r plain");
+
+        let single = ChatComponent::from_legacy_text("§cRed", VERSION_1_16);
+        assert_eq!(text(&single), "Red");
+        assert_eq!(single.get_color(), Some(&ChatColor::Red));
+    }
+
+    #[test]
+    fn test_obfuscated_runs() {
+        // runs of §k and the colors ending them are written back the same way
+        for legacy in [
+            "§kab§r cd",
+            "§c§kab§ccd",
+            "§kab §lcd§r§kef",
+            "§7[§e§k*§7] done",
+        ] {
+            let component = ChatComponent::from_legacy_text(legacy, VERSION_1_16);
+            assert_eq!(component.to_legacy_string(), legacy);
+        }
+        // a color code resets §k, so it is written again after one
+        let mut component = ChatComponent::from_text("a", ComponentStyle::v1_16().obfuscated(true));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "b",
+            ComponentStyle::v1_16().color(Some(ChatColor::Red)),
+        ));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "c",
+            ComponentStyle::v1_16().obfuscated(false),
+        ));
+        let legacy = component.to_legacy_string();
+        assert_eq!(legacy, "§ka§c§kb§rc");
+
+        let parsed = ChatComponent::from_legacy_text(&legacy, VERSION_1_16);
+        let obfuscated: Vec<_> = parsed
+            .get_siblings()
+            .iter()
+            .map(|run| (text(run), run.is_obfuscated()))
+            .collect();
+        assert_eq!(obfuscated, [("a", true), ("b", true), ("c", false)]);
+        // §k without text after it doesn't change anything
+        let empty = ChatComponent::from_legacy_text("a§k§r b", VERSION_1_16);
+        assert_eq!(empty.to_legacy_string(), "a b");
+    }
+
+    #[test]
+    fn test_legacy_hex_syntaxes() {
+        let options = LegacyOptions::ampersand();
+        let inline = ChatComponent::from_legacy_text_with("&#ff00aaPink", VERSION_1_16, &options);
+        let bungee =
+            ChatComponent::from_legacy_text_with("&x&F&f&0&0&A&aPink", VERSION_1_16, &options);
+        assert_eq!(inline, bungee);
+        assert_eq!(inline.get_color(), Some(&ChatColor::rgb(0xFF, 0x00, 0xAA)));
+    }
+
+    #[test]
+    fn test_legacy_options() {
+        let options = LegacyOptions::ampersand();
+        let component = ChatComponent::from_legacy_text_with(
+            "&aGreen &#FF8800orange&z",
+            VERSION_1_16,
+            &options,
+        );
+        let texts: Vec<_> = component.get_siblings().iter().map(text).collect();
+        assert_eq!(texts, ["Green ", "orange"]);
+        assert_eq!(
+            component.get_siblings()[1].get_color(),
+            Some(&ChatColor::custom("#FF8800"))
+        );
+        assert_eq!(
+            component.to_legacy_string_with(&options),
+            "&aGreen &#FF8800orange"
+        );
+        assert_eq!(component.to_legacy_string(), "§aGreen §6orange");
+
+        let options = options.hex(false).unknown_codes(UnknownCodes::Keep);
+        let component =
+            ChatComponent::from_legacy_text_with("&#FF8800 & co&", VERSION_1_16, &options);
+        assert_eq!(text(&component), "&#FF8800 & co&");
+    }
+
+    #[test]
+    fn test_bungee_hex() {
+        let options = LegacyOptions::new().hex(true);
+        let component = ChatComponent::from_legacy_text_with(
+            "§x§f§f§8§8§0§0orange §x§fnot hex",
+            VERSION_1_16,
+            &options,
+        );
+        let texts: Vec<_> = component.get_siblings().iter().map(text).collect();
+        assert_eq!(texts, ["orange ", "not hex"]);
+        assert_eq!(
+            component.get_siblings()[0].get_color(),
+            Some(&ChatColor::rgb(0xFF, 0x88, 0x00))
+        );
+        assert_eq!(
+            component.to_legacy_string_with(&options),
+            "§x§f§f§8§8§0§0orange §fnot hex"
+        );
+    }
+}
+
+#[cfg(feature = "json")]
+#[cfg(feature = "minimessage")]
+mod minimessage {
+    use crate::component::{ChatComponent, ComponentType};
+    use crate::style::{ChatColor, ClickEvent, ComponentStyle, HoverEvent, VERSION_1_16};
+
+    #[test]
+    fn test_parse_minimessage() {
+        let component = ChatComponent::from_minimessage(
+            "<red>Hello <b>world</b>!</red> <click:open_url:'https://x.y/z'>link <unknown> \\<b></click>",
+            VERSION_1_16,
+        );
+        let resolved: Vec<_> = component
+            .resolved_styles()
+            .into_iter()
+            .filter_map(|(node, style)| match node.get_kind() {
+                ComponentType::Text(text) if !text.get_text().is_empty() => {
+                    Some((text.get_text().to_string(), style))
+                }
+                _ => None,
+            })
+            .collect();
+        let texts: Vec<_> = resolved.iter().map(|(text, _)| text.as_str()).collect();
+        assert_eq!(texts, ["Hello ", "world", "!", " ", "link <unknown> <b>"]);
+        assert_eq!(resolved[1].1.get_color(), Some(&ChatColor::Red));
+        assert_eq!(resolved[1].1.get_bold(), Some(true));
+        assert_eq!(resolved[2].1.get_bold(), None);
+        assert_eq!(resolved[3].1.get_color(), None);
+        assert_eq!(
+            resolved[4].1.get_click_event(),
+            Some(&ClickEvent::url("https://x.y/z"))
+        );
+
+        let hover = ChatComponent::from_minimessage(
+            "<hover:show_text:'<green>tip'><gradient:red:blue>abc",
+            VERSION_1_16,
+        );
+        match hover.get_hover_event() {
+            Some(HoverEvent::ShowText(text)) => {
+                assert_eq!(text.get_color(), Some(&ChatColor::Green))
+            }
+            _ => panic!("expected a hover text"),
+        }
+        let mut colors = vec![];
+        for (node, style) in hover.resolved_styles() {
+            if matches!(node.get_kind(), ComponentType::Text(text) if !text.get_text().is_empty()) {
+                colors.push(style.get_color().cloned());
+            }
+        }
+        assert_eq!(
+            colors,
+            [
+                Some(ChatColor::rgb(0xFF, 0x55, 0x55)),
+                Some(ChatColor::rgb(0xAA, 0x55, 0xAA)),
+                Some(ChatColor::rgb(0x55, 0x55, 0xFF)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_minimessage() {
+        let input = "<red>Hello <bold>world</bold>!</red> <click:run_command:'/say hi'><!italic>click <lang:chat.type.text:'<#ff8800>Steve':hi>";
+        let component = ChatComponent::from_minimessage(input, VERSION_1_16);
+        assert_eq!(component.to_minimessage(), input);
+
+        // styles repeating what's inherited get no tags
+        let mut red =
+            ChatComponent::from_text("a", ComponentStyle::v1_16().color(Some(ChatColor::Red)));
+        red.get_siblings_mut().push(ChatComponent::from_text(
+            "<b",
+            ComponentStyle::v1_16().color(Some(ChatColor::Red)),
+        ));
+        assert_eq!(red.to_minimessage(), "<red>a\\<b");
+    }
+}
+
+mod ansi {
+    use crate::ansi::ColorDepth;
+    use crate::component::ChatComponent;
+    use crate::style::{ChatColor, ComponentStyle};
+
+    #[test]
+    fn test_to_ansi() {
+        let mut component =
+            ChatComponent::from_text("Hi ", ComponentStyle::v1_16().color(Some(ChatColor::Red)));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "there",
+            ComponentStyle::v1_16().bold(true),
+        ));
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_text("!", ComponentStyle::v1_16()));
+        assert_eq!(
+            component.to_ansi(),
+            "\x1b[38;2;255;85;85mHi \x1b[0m\x1b[1;38;2;255;85;85mthere\x1b[0m\x1b[38;2;255;85;85m!\x1b[0m"
+        );
+        assert_eq!(
+            ChatComponent::from_text("plain", ComponentStyle::v1_16()).to_ansi(),
+            "plain"
+        );
+        let orange = ChatComponent::from_text(
+            "o",
+            ComponentStyle::v1_16().color(Some(ChatColor::rgb(0xFF, 0x88, 0x00))),
+        );
+        assert_eq!(
+            orange.to_ansi_with(ColorDepth::Ansi256),
+            "\x1b[38;5;208mo\x1b[0m"
+        );
+        assert_eq!(orange.to_ansi_with(ColorDepth::Ansi16), "\x1b[33mo\x1b[0m");
+    }
+
+    #[test]
+    fn test_display_ansi() {
+        use crate::ansi::colors_enabled;
+
+        assert!(colors_enabled(None, true));
+        assert!(colors_enabled(Some(""), true));
+        assert!(!colors_enabled(Some("1"), true));
+        assert!(!colors_enabled(None, false));
+
+        let component =
+            ChatComponent::from_text("hi", ComponentStyle::v1_16().color(Some(ChatColor::Red)));
+        let shown = format!("{}", component.display_ansi().depth(ColorDepth::Ansi16));
+        assert!(shown == "hi" || shown == component.to_ansi_with(ColorDepth::Ansi16));
+    }
+}
+
+#[cfg(feature = "json")]
+mod custom {
+    use serde_json::{json, Map, Value};
+
+    use crate::component::{
+        register_custom_component, ChatComponent, ComponentType, CustomComponent,
+    };
+
+    #[derive(Clone, Debug)]
+    struct Emoji(String);
+
+    impl CustomComponent for Emoji {
+        fn clone_box(&self) -> Box<dyn CustomComponent> {
+            Box::new(self.clone())
+        }
+
+        fn plain_text(&self) -> String {
+            format!(":{}:", self.0)
+        }
+
+        fn to_json(&self) -> Map<String, Value> {
+            let mut fields = Map::new();
+            fields.insert("emoji".to_string(), Value::String(self.0.clone()));
+            fields
+        }
+    }
+
+    fn parse_emoji(fields: &Map<String, Value>) -> Option<Box<dyn CustomComponent>> {
+        let name = fields.get("emoji")?.as_str()?;
+        Some(Box::new(Emoji(name.to_string())))
+    }
+
+    #[test]
+    fn test_custom_component() {
+        register_custom_component(parse_emoji);
+
+        let value = json!({"text":"I like ","extra":[{"emoji":"cake","bold":true}]});
+        let component: ChatComponent = serde_json::from_value(value.clone()).unwrap();
+
+        let emoji = &component.get_siblings()[0];
+        assert!(matches!(emoji.get_kind(), ComponentType::Custom(_)));
+        assert_eq!(emoji.get_bold(), Some(true));
+        assert_eq!(serde_json::to_value(&component).unwrap(), value);
+
+        let mut plain = String::new();
+        component.append_plain_text(&mut plain);
+        assert_eq!(plain, "I like :cake:");
+    }
+
+    #[test]
+    fn test_unknown_component() {
+        let value =
+            json!({"text":"","extra":[{"sprite":"item/diamond","atlas":"items","color":"aqua"}]});
+        let component = ChatComponent::from_json(&value.to_string()).unwrap();
+
+        let unknown = &component.get_siblings()[0];
+        assert!(matches!(unknown.get_kind(), ComponentType::Custom(_)));
+        assert!(unknown.get_color().is_some());
+        #[cfg(feature = "preserve_unknown")]
+        assert!(unknown.get_unknown_fields().is_empty());
+        assert_eq!(serde_json::to_value(&component).unwrap(), value);
+        assert_eq!(component.to_plain_text(), "");
+
+        // broken built-in kinds are still errors
+        assert!(ChatComponent::from_json(r#"{"text":5,"sprite":"a"}"#).is_err());
+        assert!(ChatComponent::from_json(r#"{"color":"red"}"#).is_err());
+    }
+}
+
+mod book {
+    use crate::book::{split_pages, BookErr, WrittenBook, PAGE_LINES};
+    use crate::component::ChatComponent;
+    use crate::style::ComponentStyle;
+
+    #[test]
+    fn test_split_pages() {
+        let lines = vec!["line"; 20].join("\n");
+        let text = ChatComponent::from_text(lines, ComponentStyle::v1_16());
+        let pages = split_pages(&text);
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].to_plain_text().lines().count(), PAGE_LINES);
+        assert_eq!(pages[1].to_plain_text().lines().count(), 6);
+
+        // narrow characters reach the character limit before the line limit
+        let dots = ChatComponent::from_text("i".repeat(1500), ComponentStyle::v1_16());
+        let pages = split_pages(&dots);
+        assert!(pages.len() > 1);
+        assert!(pages
+            .iter()
+            .all(|page| page.to_plain_text().chars().count() <= 1023));
+        assert_eq!(
+            pages
+                .iter()
+                .map(|page| page.to_plain_text().replace('\n', ""))
+                .collect::<String>(),
+            "i".repeat(1500)
+        );
+
+        assert_eq!(
+            WrittenBook::new("t".repeat(33), "Steve"),
+            Err(BookErr::TitleTooLong(33))
+        );
+        let book = WrittenBook::new("Guide", "Steve").unwrap().text(&text);
+        assert_eq!(book.get_pages().len(), 2);
+        #[cfg(feature = "nbt")]
+        {
+            let book = WrittenBook::new("Guide", "Steve")
+                .unwrap()
+                .page(ChatComponent::from_text("hi", ComponentStyle::v1_16()));
+            assert_eq!(
+                book.to_snbt(crate::VERSION_1_16),
+                r#"{title:"Guide",author:"Steve",pages:['{"text":"hi"}'],resolved:1b}"#
+            );
+            assert_eq!(
+                book.to_snbt(770),
+                r#"{title:"Guide",author:"Steve",pages:["hi"],resolved:1b}"#
+            );
+        }
+    }
+}
+
+mod layout {
+    use crate::component::ChatComponent;
+    use crate::layout::{self, Alignment};
+    use crate::style::{ClickEvent, ComponentStyle};
+
+    #[test]
+    fn test_align() {
+        let mut component = ChatComponent::from_text("Hi\n", ComponentStyle::v1_16());
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "Hey",
+            ComponentStyle::v1_16().bold(true),
+        ));
+        assert_eq!(layout::width(&component), 21);
+
+        let lines = layout::split_lines(&layout::align_lines(&component, 40, Alignment::Right));
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|line| layout::width(line) == 40));
+
+        let centered = layout::align(&lines[0], 60, Alignment::Center);
+        assert_eq!(layout::width(&centered), 50);
+
+        for pixels in [12, 37, layout::CHAT_WIDTH] {
+            let separator = layout::separator(pixels, None, crate::style::VERSION_1_16);
+            assert_eq!(layout::width(&separator), pixels);
+        }
+        let separator = layout::separator_of('=', 100, None, crate::style::VERSION_1_16);
+        assert_eq!(layout::width(&separator), 100);
+
+        let message_box = layout::MessageBox::new(component)
+            .title(Some(ChatComponent::from_text(
+                "Info",
+                ComponentStyle::v1_16(),
+            )))
+            .width(200)
+            .build();
+        let lines = layout::split_lines(&message_box);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(layout::width(&lines[0]), 200);
+        assert_eq!(layout::width(&lines[3]), 200);
+    }
+
+    #[test]
+    fn test_wrap() {
+        let mut component = ChatComponent::from_text("aaaa bbbb ", ComponentStyle::v1_16());
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "cc",
+            ComponentStyle::v1_16().bold(true),
+        ));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "cc dddddddddd",
+            ComponentStyle::v1_16(),
+        ));
+
+        let lines: Vec<String> = layout::wrap_lines(&component, 40)
+            .iter()
+            .map(|line| {
+                let mut text = String::new();
+                line.append_plain_text(&mut text);
+                text
+            })
+            .collect();
+        assert_eq!(lines, vec!["aaaa", "bbbb", "cccc", "dddddd", "dddd"]);
+    }
+
+    #[test]
+    fn test_split_message() {
+        let style = ComponentStyle::v1_16().click_event(Some(ClickEvent::suggest_command("/help")));
+        let component = ChatComponent::from_text("word ".repeat(60), style);
+
+        let messages = layout::split_message(&component, crate::style::VERSION_1_16);
+        assert_eq!(messages.len(), 2);
+        let messages = layout::split_message(&component, crate::style::VERSION_1_8);
+        assert_eq!(messages.len(), 3);
+        for message in &messages {
+            let mut text = String::new();
+            message.append_plain_text(&mut text);
+            assert!(text.len() <= 100 && text.starts_with("word") && text.ends_with("word"));
+            assert!(message
+                .get_siblings()
+                .iter()
+                .all(|part| part.get_click_event().is_some()));
+        }
+    }
+}
+
+mod effects {
+    use crate::component::{ChatComponent, ComponentType};
+    use crate::effects::{self, Easing};
+    use crate::style::{ChatColor, ComponentStyle};
+
+    #[test]
+    fn test_gradient() {
+        let colors = [ChatColor::Red, ChatColor::rgb(0, 0, 0xFF)];
+        let gradient = effects::gradient("abc", &colors, ComponentStyle::v1_16().bold(true));
+        let colors: Vec<_> = gradient
+            .get_siblings()
+            .iter()
+            .map(|c| c.get_color().cloned())
+            .collect();
+        assert_eq!(
+            colors,
+            vec![
+                Some(ChatColor::custom("#FF5555")),
+                Some(ChatColor::custom("#802BAA")),
+                Some(ChatColor::custom("#0000FF")),
+            ]
+        );
+        assert_eq!(gradient.get_bold(), Some(true));
+
+        let frames = effects::gradient_frames(
+            "abcd",
+            &[ChatColor::White, ChatColor::Black],
+            ComponentStyle::v1_16(),
+            4,
+            Easing::Linear,
+        );
+        assert_eq!(frames.len(), 4);
+        // every frame moves the gradient by one character
+        assert_eq!(
+            frames[1].get_siblings()[1].get_color(),
+            frames[0].get_siblings()[2].get_color()
+        );
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_style_graphemes() {
+        let mut component = ChatComponent::from_text("e\u{301}👍🏽", ComponentStyle::v1_16());
+        let mut units = vec![];
+        component.style_chars(|_, c| {
+            units.push(c.to_string());
+            None
+        });
+        assert_eq!(units, vec!["e\u{301}", "👍🏽"]);
+    }
+
+    #[test]
+    fn test_style_chars() {
+        let mut component = ChatComponent::from_text("Hey ", ComponentStyle::v1_16());
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_text("you", ComponentStyle::v1_16()));
+        component.style_chars(|i, c| {
+            (i % 2 == 0 && c != " ").then(|| ComponentStyle::v1_16().bold(true))
+        });
+
+        let parts: Vec<_> = component
+            .resolved_styles()
+            .into_iter()
+            .filter_map(|(component, style)| match component.get_kind() {
+                ComponentType::Text(text) if !text.get_text().is_empty() => {
+                    Some((text.get_text().to_string(), style.get_bold().is_some()))
+                }
+                _ => None,
+            })
+            .collect();
+        let expected = [
+            ("H", true),
+            ("e", false),
+            ("y", true),
+            (" ", false),
+            ("y", true),
+            ("o", false),
+            ("u", true),
+        ];
+        assert_eq!(
+            parts,
+            expected
+                .iter()
+                .map(|(text, bold)| (text.to_string(), *bold))
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+mod bbcode {
+    use crate::component::ChatComponent;
+    use crate::style::{ChatColor, ComponentStyle};
+
+    #[test]
+    fn test_to_bbcode() {
+        let mut component = ChatComponent::from_text(
+            "Hello ",
+            ComponentStyle::v1_16().color(Some(ChatColor::Red)),
+        );
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "bold",
+            ComponentStyle::v1_16().bold(true),
+        ));
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_text(" world", ComponentStyle::v1_16()));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "!",
+            ComponentStyle::v1_16().color(Some(ChatColor::Reset)),
+        ));
+        assert_eq!(
+            component.to_bbcode(),
+            "[color=#ff5555]Hello [b]bold[/b] world[/color]!"
+        );
+    }
+}
+
+mod irc {
+    use crate::component::ChatComponent;
+    use crate::style::{ChatColor, ComponentStyle};
+
+    #[test]
+    fn test_to_irc() {
+        let mut component =
+            ChatComponent::from_text("Hi ", ComponentStyle::v1_16().color(Some(ChatColor::Red)));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "there",
+            ComponentStyle::v1_16().bold(true),
+        ));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "1",
+            ComponentStyle::v1_16().color(Some(ChatColor::Reset)),
+        ));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            ",5",
+            ComponentStyle::v1_16().color(Some(ChatColor::rgb(0, 0, 0xB0))),
+        ));
+        assert_eq!(
+            component.to_irc(),
+            "\x0304Hi \x02there\x0f1\x0302\x02\x02,5"
+        );
+    }
+}
+
+#[cfg(feature = "json")]
+mod bedrock {
+    use crate::component::{ChatComponent, ComponentType, TranslationComponent};
+    use crate::style::{ChatColor, ComponentStyle};
+
+    #[test]
+    fn test_to_rawtext() {
+        let mut component = ChatComponent::from_text(
+            "Hello ",
+            ComponentStyle::v1_16().color(Some(ChatColor::Green)),
+        );
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "world",
+            ComponentStyle::v1_16().bold(true).underlined(true),
+        ));
+        let translation = TranslationComponent::from_key("commands.give.success")
+            .argument(ChatComponent::from_selector("@p", ComponentStyle::v1_16()))
+            .argument(ChatComponent::from_text(
+                "5",
+                ComponentStyle::v1_16().color(Some(ChatColor::Red)),
+            ));
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_component(
+                ComponentType::Translation(translation),
+                ComponentStyle::v1_16(),
+            ));
+        assert_eq!(
+            component.to_rawtext(),
+            r#"{"rawtext":[{"text":"§aHello §lworld§a"},{"translate":"commands.give.success","with":{"rawtext":[{"selector":"@p"},{"text":"§c5§a"}]}}]}"#
+        );
+    }
+
+    #[test]
+    fn test_from_rawtext() {
+        let component = ChatComponent::from_rawtext(
+            r#"{"rawtext":[{"text":"§aHi "},{"selector":"@s"},{"translate":"a.b","with":["§lx","y"]}]}"#,
+            735,
+        )
+        .unwrap();
+        let parts = component.get_siblings();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[1].get_style().get_color(), Some(&ChatColor::Green));
+        match parts[2].get_kind() {
+            ComponentType::Translation(translation) => {
+                let args = translation.get_args();
+                assert_eq!(args[0].get_style().get_bold(), Some(true));
+                assert_eq!(args[1].get_style().get_color(), Some(&ChatColor::Green));
+            }
+            _ => panic!("expected a translation"),
+        }
+        assert_eq!(
+            ChatComponent::from_rawtext(&component.to_rawtext(), 735)
+                .unwrap()
+                .to_rawtext(),
+            component.to_rawtext()
+        );
+        assert!(ChatComponent::from_rawtext(r#"{"text":"java"}"#, 735).is_err());
+    }
+
+    #[test]
+    fn test_java_bedrock_translation() {
+        use crate::bedrock::BedrockKeyMap;
+        use crate::style::{ClickEvent, ComponentStyle as Style};
+
+        let keys = BedrockKeyMap::new();
+        let stone = TranslationComponent::from_key("block.minecraft.stone");
+        let mut component = ChatComponent::from_component(
+            ComponentType::Translation(
+                TranslationComponent::from_key("advancements.%s.%%").argument(
+                    ChatComponent::from_component(
+                        ComponentType::Translation(stone),
+                        Style::v1_16(),
+                    ),
+                ),
+            ),
+            Style::v1_16()
+                .color(Some(ChatColor::custom("#FF5555")))
+                .underlined(true)
+                .click_event(Some(ClickEvent::url("https://example.com"))),
+        );
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_keybind("key.jump", Style::v1_16()));
+
+        let bedrock = component.to_bedrock(&keys);
+        assert_eq!(bedrock.get_color(), Some(&ChatColor::Red));
+        assert_eq!(bedrock.get_underlined(), None);
+        assert!(bedrock.get_click_event().is_none());
+        assert_eq!(
+            bedrock.to_plain_text(),
+            "advancements.tile.stone.name.%Space"
+        );
+        assert!(matches!(
+            bedrock.get_siblings()[1].get_kind(),
+            ComponentType::Translation(translation) if translation.get_key() == "tile.stone.name"
+        ));
+
+        let java = bedrock.to_java(&keys);
+        assert!(matches!(
+            java.get_siblings()[1].get_kind(),
+            ComponentType::Translation(translation) if translation.get_key() == "block.minecraft.stone"
+        ));
+        assert_eq!(
+            keys.get_java("tile.grass.name"),
+            "block.minecraft.grass_block"
+        );
+        assert_eq!(
+            keys.get_java("commands.kill.success"),
+            "commands.kill.success"
+        );
+    }
+}
+
+#[cfg(feature = "nbt")]
+mod nbt {
+    use crate::component::ChatComponent;
+    use crate::nbt::NbtErr;
+    use crate::style::{ChatColor, ClickEvent, ComponentStyle};
+
+    #[test]
+    fn test_network_nbt() {
+        let plain = ChatComponent::from_text("hi", ComponentStyle::v1_16());
+        assert_eq!(plain.to_network_nbt(), vec![8, 0, 2, b'h', b'i']);
+
+        let mut component = ChatComponent::from_text(
+            "a\0b😀",
+            ComponentStyle::v1_16()
+                .color(Some(ChatColor::Red))
+                .bold(true),
+        );
+        component
+            .get_siblings_mut()
+            .push(ChatComponent::from_text("!", ComponentStyle::v1_16()));
+        let mut bytes = component.to_network_nbt();
+        bytes.push(42);
+        let mut input = bytes.as_slice();
+        assert_eq!(ChatComponent::from_network_nbt(&mut input), Ok(component));
+        assert_eq!(input, &[42]);
+
+        let mut truncated = &bytes[..bytes.len() - 4];
+        assert_eq!(
+            ChatComponent::from_network_nbt(&mut truncated),
+            Err(NbtErr::UnexpectedEnd)
+        );
+        // a list of a string and a compound, with the string wrapped in a compound
+        let list = [
+            9, 10, 0, 0, 0, 2, 8, 0, 0, 0, 1, b'a', 0, 8, 0, 4, b't', b'e', b'x', b't', 0, 1, b'b',
+            0,
+        ];
+        let parsed = ChatComponent::from_network_nbt(&mut &list[..]).unwrap();
+        assert_eq!(parsed.to_plain_text(), "ab");
+    }
+
+    #[test]
+    fn test_to_snbt() {
+        let plain = ChatComponent::from_text("say \"hi\"", ComponentStyle::v1_16());
+        assert_eq!(plain.to_snbt(), r#"'say "hi"'"#);
+
+        let mut component = ChatComponent::from_text(
+            "Click\n",
+            ComponentStyle::v1_16()
+                .color(Some(ChatColor::Red))
+                .bold(true)
+                .click_event(Some(ClickEvent::url("https://example.com"))),
+        );
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "it's",
+            ComponentStyle::v1_16().click_event(Some(ClickEvent::page(2u32))),
+        ));
+        let snbt = component.to_snbt();
+        for part in [
+            r#"text:"Click\n""#,
+            "bold:1b",
+            r#"color:"red""#,
+            r#"click_event:{action:"open_url",url:"https://example.com"}"#,
+            r#"extra:[{"#,
+            r#"text:"it's""#,
+            r#"click_event:{action:"change_page",page:2}"#,
+        ] {
+            assert!(snbt.contains(part), "{} is missing {}", snbt, part);
+        }
+    }
+
+    #[test]
+    fn test_from_snbt() {
+        let component = ChatComponent::from_snbt(
+            r#"{text:'say "hi"',bold:true,color:"red",extra:["\u00e9",{text:"x",click_event:{action:"run_command",command:"/help"}}]}"#,
+        )
+        .unwrap();
+        assert_eq!(component.to_plain_text(), "say \"hi\"éx");
+        assert_eq!(component.get_style().get_bold(), Some(true));
+        assert_eq!(
+            component.get_siblings()[1].get_style().get_click_event(),
+            Some(&ClickEvent::run_command("/help"))
+        );
+        assert_eq!(
+            ChatComponent::from_snbt(&component.to_snbt()),
+            Ok(component)
+        );
+        assert_eq!(
+            ChatComponent::from_snbt("['a', {text: b, italic: 1b}]")
+                .unwrap()
+                .to_plain_text(),
+            "ab"
+        );
+        assert!(matches!(
+            ChatComponent::from_snbt("{text:\"a\""),
+            Err(NbtErr::InvalidSnbt(9, _))
+        ));
+    }
+
+    #[test]
+    fn test_sign() {
+        use crate::nbt::{Sign, SignErr, SignText};
+
+        let line = ChatComponent::from_text("Shop", ComponentStyle::v1_16().bold(true));
+        let front = SignText::new(vec![line]).unwrap().glowing(true);
+        let sign = Sign::new(front.clone());
+        let old = sign.to_snbt(crate::VERSION_1_16);
+        assert!(old.starts_with("{Text1:'{"));
+        assert!(old.contains(r#""bold":true"#));
+        assert!(old.ends_with(r#"Text4:'{"text":""}',Color:"black"}"#));
+        let modern = sign.waxed(true).to_snbt(770);
+        assert!(modern.starts_with(r#"{front_text:{messages:[{"#));
+        assert!(modern.contains(r#"bold:1b"#));
+        assert!(modern.contains(r#"has_glowing_text:1b},back_text:{messages:["","","",""]"#));
+        assert!(modern.ends_with("is_waxed:1b}"));
+
+        let wide = ChatComponent::from_text("W".repeat(20), ComponentStyle::v1_16());
+        assert!(matches!(
+            SignText::new(vec![wide]),
+            Err(SignErr::LineTooWide(0, _))
+        ));
+        let lines = vec![ChatComponent::from_text("", ComponentStyle::v1_16()); 5];
+        assert_eq!(SignText::new(lines), Err(SignErr::TooManyLines(5)));
+    }
+}
+
+mod discord {
+    use crate::component::ChatComponent;
+    use crate::style::{ClickEvent, ComponentStyle};
+
+    #[test]
+    fn test_discord_markdown() {
+        let mut component = ChatComponent::from_text("Hello ", ComponentStyle::v1_16().bold(true));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "world",
+            ComponentStyle::v1_16().italic(true),
+        ));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            " *wiki*",
+            ComponentStyle::v1_16()
+                .bold(false)
+                .click_event(Some(ClickEvent::url("https://minecraft.wiki"))),
+        ));
+        let markdown = component.to_discord_markdown();
+        assert_eq!(
+            markdown,
+            r"**Hello *world***[ \*wiki\*](https://minecraft.wiki)"
+        );
+        let parsed = ChatComponent::from_discord_markdown(&markdown, 735);
+        assert_eq!(parsed.to_plain_text(), "Hello world *wiki*");
+        assert_eq!(parsed.to_discord_markdown(), markdown);
+
+        let parsed = ChatComponent::from_discord_markdown("~~old~~ snake_case **open", 735);
+        let styles: Vec<_> = parsed
+            .get_siblings()
+            .iter()
+            .map(|part| (part.to_plain_text(), part.get_style().get_strikethrough()))
+            .collect();
+        assert_eq!(
+            styles,
+            vec![
+                ("old".to_string(), Some(true)),
+                (" snake_case **open".to_string(), None)
+            ]
+        );
+    }
+}
+
+mod slack {
+    use crate::component::{ChatComponent, TranslationComponent};
+    use crate::slack::{SlackFallback, SlackOptions};
+    use crate::style::{ClickEvent, ComponentStyle};
+
+    #[test]
+    fn test_slack_mrkdwn() {
+        let mut component = ChatComponent::from_text("Hello ", ComponentStyle::v1_16().bold(true));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "world ",
+            ComponentStyle::v1_16().italic(true),
+        ));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "<wiki>",
+            ComponentStyle::v1_16()
+                .bold(false)
+                .click_event(Some(ClickEvent::url("https://minecraft.wiki/w/A|B"))),
+        ));
+        assert_eq!(
+            component.to_slack_mrkdwn(&SlackOptions::new()),
+            "*Hello* *_world_* <https://minecraft.wiki/w/A%7CB|&lt;wiki&gt;>"
+        );
+
+        let mut translation = TranslationComponent::from_key("%s said %s");
+        translation.add_arg(ChatComponent::from_text("Steve", ComponentStyle::v1_16()));
+        translation.add_arg(ChatComponent::from_text(
+            "secret",
+            ComponentStyle::v1_16().obfuscated(true),
+        ));
+        let component = ChatComponent::from_component(
+            crate::ComponentType::Translation(translation),
+            ComponentStyle::v1_16().underlined(true),
+        );
+        assert_eq!(
+            component.to_slack_mrkdwn(&SlackOptions::new()),
+            "Steve said secret"
+        );
+        let options = SlackOptions::new()
+            .underlined(SlackFallback::Italic)
+            .obfuscated(SlackFallback::Mask('?'));
+        assert_eq!(component.to_slack_mrkdwn(&options), "_Steve said ??????_");
+        let options = options.obfuscated(SlackFallback::Hide);
+        assert_eq!(component.to_slack_mrkdwn(&options), "_Steve said_ ");
+    }
+}
+
+mod html {
+    use crate::component::ChatComponent;
+    use crate::html::{HtmlBackground, HtmlStyleMode, HtmlTheme};
+    use crate::style::{ChatColor, ClickEvent, ComponentStyle};
+
+    #[test]
+    fn test_html_theme() {
+        let mut component = ChatComponent::from_text(
+            "<b>",
+            ComponentStyle::v1_16()
+                .color(Some(ChatColor::Black))
+                .bold(true),
+        );
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "secret",
+            ComponentStyle::v1_16().obfuscated(true),
+        ));
+
+        assert_eq!(
+            component.to_html(&HtmlTheme::new()),
+            r#"<span style="font-weight:bold;color:#000000">&lt;b&gt;</span><span style="font-weight:bold;color:#000000">secret</span>"#
+        );
+        let theme = HtmlTheme::new()
+            .background(Some(HtmlBackground::Dark))
+            .obfuscated(Some(|text| format!("<i class=\"magic\">{}</i>", text)));
+        assert_eq!(
+            component.to_html(&theme),
+            r#"<span style="font-weight:bold;color:#666666">&lt;b&gt;</span><span style="font-weight:bold;color:#666666"><i class="magic">secret</i></span>"#
+        );
+        let theme = HtmlTheme::new().style_mode(HtmlStyleMode::Classes);
+        assert_eq!(
+            component.to_html(&theme),
+            r#"<span class="mc-bold mc-black">&lt;b&gt;</span><span class="mc-bold mc-obfuscated mc-black">secret</span>"#
+        );
+        let link = ChatComponent::from_text(
+            "site",
+            ComponentStyle::v1_16()
+                .click_event(Some(ClickEvent::url("https://example.com/?a=1&b=2"))),
+        );
+        assert_eq!(
+            link.to_html(&HtmlTheme::new()),
+            r#"<a href="https://example.com/?a=1&amp;b=2" rel="nofollow">site</a>"#
+        );
+        let script = ChatComponent::from_text(
+            "x",
+            ComponentStyle::v1_16().click_event(Some(ClickEvent::url("javascript:alert(1)"))),
+        );
+        assert_eq!(script.to_html(&HtmlTheme::new()), "x");
+        let stylesheet = HtmlTheme::new().stylesheet();
+        assert!(stylesheet.contains(".mc-bold{font-weight:bold}"));
+        assert!(stylesheet.contains(".mc-red{color:#ff5555}"));
+        assert!(stylesheet.contains(".mc-black{color:#000000}"));
+        let stylesheet = HtmlTheme::new()
+            .background(Some(HtmlBackground::Dark))
+            .stylesheet();
+        assert!(stylesheet.contains(".mc-black{color:#666666}"));
+    }
+
+    #[test]
+    fn test_matrix_html() {
+        let mut component = ChatComponent::from_text(
+            "<Steve>\n",
+            ComponentStyle::v1_16()
+                .color(Some(ChatColor::Red))
+                .bold(true),
+        );
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "secret",
+            ComponentStyle::v1_16().bold(false).obfuscated(true),
+        ));
+        component.get_siblings_mut().push(ChatComponent::from_text(
+            "wiki",
+            ComponentStyle::v1_16()
+                .color(Some(ChatColor::custom("#123456")))
+                .click_event(Some(ClickEvent::url("https://minecraft.wiki"))),
+        ));
+        assert_eq!(
+            component.to_matrix_html(),
+            concat!(
+                r##"<span data-mx-color="#ff5555"><b>&lt;Steve&gt;<br></b></span>"##,
+                r##"<span data-mx-color="#ff5555"><span data-mx-spoiler>secret</span></span>"##,
+                r##"<a href="https://minecraft.wiki"><span data-mx-color="#123456"><b>wiki</b></span></a>"##
+            )
+        );
     }
 }
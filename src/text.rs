@@ -0,0 +1,21 @@
+//! Splitting text into the units per-character operations work on.
+//!
+//! With the `unicode-segmentation` feature these are extended grapheme clusters,
+//! so emoji and characters with combining marks stay in one piece.
+//! Otherwise every `char` is its own unit.
+
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Iterates over the units of `text`.
+#[cfg(feature = "unicode-segmentation")]
+pub(crate) fn units(text: &str) -> impl Iterator<Item = &str> {
+    text.graphemes(true)
+}
+
+/// Iterates over the units of `text`.
+#[cfg(not(feature = "unicode-segmentation"))]
+pub(crate) fn units(text: &str) -> impl Iterator<Item = &str> {
+    text.char_indices()
+        .map(move |(i, c)| &text[i..i + c.len_utf8()])
+}